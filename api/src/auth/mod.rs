@@ -38,7 +38,11 @@ pub async fn get_current_user() -> Result<Option<types::User>, ServerFnError> {
 }
 
 #[server(endpoint = "auth/login")]
-pub async fn login(username: String, code: String) -> Result<types::User, ServerFnError> {
+pub async fn login(
+    username: String,
+    code: String,
+    device: Option<String>,
+) -> Result<types::User, ServerFnError> {
     let mut conn = db::connect();
 
     let user: db::models::User = db::schema::users::table
@@ -74,7 +78,7 @@ pub async fn login(username: String, code: String) -> Result<types::User, Server
         ));
     };
 
-    if let Err(()) = session::set_current_user(&user.username).await {
+    if let Err(()) = session::set_current_user(&user.username, device).await {
         tracing::error!("Failed to create session for user: {}", user.username);
         return Err(ServerFnError::<NoCustomError>::ServerError(
             "Failed to create session".to_string(),
@@ -147,7 +151,7 @@ pub async fn register(
         username: user.username,
     };
 
-    if let Err(()) = session::set_current_user(&user.username).await {
+    if let Err(()) = session::set_current_user(&user.username, None).await {
         tracing::error!("Failed to create session for new user");
     }
 
@@ -195,3 +199,65 @@ pub async fn logout() -> Result<(), ServerFnError> {
 
     Ok(())
 }
+
+/// Every device the user is logged in on.
+#[data::cfg_server("auth/list_sessions")]
+pub async fn list_sessions() -> Result<Vec<types::Session>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let current = session::current_token().await;
+
+    let mut conn = db::connect();
+
+    let sessions: Vec<db::models::Session> = db::schema::sessions::table
+        .filter(db::schema::sessions::user_id.eq(&user.username))
+        .order(db::schema::sessions::id)
+        .select(db::models::Session::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load sessions for user ({}): {e}", user.username);
+            ServerFnError::<NoCustomError>::ServerError("Failed to load sessions".to_string())
+        })?;
+
+    Ok(sessions
+        .into_iter()
+        .map(|s| types::Session {
+            id: s.id,
+            current: current.as_deref() == Some(s.token.as_str()),
+            name: s.name,
+        })
+        .collect())
+}
+
+/// Logs a device out. Revoking the current session logs this device out too.
+#[data::cfg_server("auth/revoke_session")]
+pub async fn revoke_session(id: types::SessionId) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    let session: db::models::Session = db::schema::sessions::table
+        .find(id)
+        .filter(db::schema::sessions::user_id.eq(&user.username))
+        .select(db::models::Session::as_select())
+        .first(&mut conn)
+        .map_err(|e| {
+            tracing::info!("Failed to find session ({id}): {e}");
+            ServerFnError::<NoCustomError>::Request("Session not found".to_string())
+        })?;
+
+    if session::current_token().await.as_deref() == Some(session.token.as_str()) {
+        return logout().await;
+    }
+
+    diesel::delete(db::schema::sessions::table.find(id))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to revoke session ({id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to revoke session".to_string())
+        })?;
+
+    tracing::info!("Revoked session ({id}) for user: {}", user.username);
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
 
 pub const SESSION_COOKIE_NAME: &str = "session_token";
+/// Longest session name kept, as user agents can run long.
+pub const MAX_SESSION_NAME: usize = 100;
 
 pub enum Auth {
     User(crate::User),
@@ -83,7 +85,36 @@ impl FromServerContext for crate::User {
 }
 
 #[cfg(feature = "server")]
-pub async fn set_current_user(user: &types::UserId) -> Result<(), ()> {
+impl crate::User {
+    /// The signed in user, for server functions that return their own error type.
+    pub(crate) async fn extract_as<E>() -> Result<Self, ServerFnError<E>> {
+        extract()
+            .await
+            .map_err(|e: ServerFnError| ServerFnError::Request(e.to_string()))
+    }
+}
+
+/// Names a new session after the device name given at login, falling back to the user agent.
+#[cfg(feature = "server")]
+fn session_name(device: Option<String>) -> Option<String> {
+    let name = device
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty())
+        .or_else(|| {
+            server_context()
+                .request_parts()
+                .headers
+                .get(http::header::USER_AGENT)
+                .and_then(|ua| ua.to_str().ok())
+                .map(|ua| ua.trim().to_string())
+                .filter(|ua| !ua.is_empty())
+        })?;
+
+    Some(name.chars().take(MAX_SESSION_NAME).collect())
+}
+
+#[cfg(feature = "server")]
+pub async fn set_current_user(user: &types::UserId, device: Option<String>) -> Result<(), ()> {
     use diesel::prelude::*;
 
     let token = nanoid::nanoid!(25);
@@ -93,7 +124,7 @@ pub async fn set_current_user(user: &types::UserId) -> Result<(), ()> {
     let new_session = crate::db::models::NewSession {
         user_id: user.clone(),
         token,
-        name: None,
+        name: session_name(device),
     };
 
     diesel::insert_into(crate::db::schema::sessions::table)
@@ -108,25 +139,32 @@ pub async fn set_current_user(user: &types::UserId) -> Result<(), ()> {
     Ok(())
 }
 
+/// The token of the session making the request, if it sent one.
 #[cfg(feature = "server")]
-pub async fn clear_current_user() -> Result<(), ()> {
+pub(crate) async fn current_token() -> Option<String> {
     use axum_extra::extract::cookie::CookieJar;
-    use diesel::prelude::*;
-    let mut conn = crate::db::connect();
 
     let headers: http::HeaderMap = extract()
         .await
-        .map_err(|e| tracing::error!("Failed to get header map when removing session: {e}"))?;
+        .map_err(|e| tracing::error!("Failed to get header map when reading session: {e}"))
+        .ok()?;
 
     let jar = CookieJar::from_headers(&headers);
 
-    let auth = jar.get(SESSION_COOKIE_NAME).ok_or_else(|| {
+    jar.get(SESSION_COOKIE_NAME).map(|c| c.value().to_string())
+}
+
+#[cfg(feature = "server")]
+pub async fn clear_current_user() -> Result<(), ()> {
+    use diesel::prelude::*;
+    let mut conn = crate::db::connect();
+
+    let token = current_token().await.ok_or_else(|| {
         tracing::info!("No session cookie found when removing session");
     })?;
 
     diesel::delete(
-        crate::db::schema::sessions::table
-            .filter(crate::db::schema::sessions::token.eq(auth.value())),
+        crate::db::schema::sessions::table.filter(crate::db::schema::sessions::token.eq(token)),
     )
     .execute(&mut conn)
     .map_err(|e| {
@@ -0,0 +1,107 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+/// Spends a full XP track on a new special ability or action dot, recording it in the
+/// character's advancement history.
+#[data::cfg_server("character/advance")]
+pub async fn advance(
+    character_id: types::CharacterId,
+    advance: types::Advance,
+) -> Result<types::Character, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let mut character = super::load_character(&mut conn, character_id)
+        .map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    if character.user_id != user.username {
+        return Err(ServerFnError::Request("Character not found".to_string()));
+    }
+
+    character
+        .advance(&advance)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::update(character_xp::table.find(character_id))
+            .set(&db::models::CharacterXp::with_xp(
+                character_id,
+                &character.xp,
+            ))
+            .execute(conn)?;
+
+        match &advance {
+            types::Advance::Ability(name) => {
+                diesel::insert_into(character_abilities::table)
+                    .values(&db::models::NewCharacterAbility {
+                        character_id,
+                        name: name.clone(),
+                    })
+                    .execute(conn)?;
+            }
+            types::Advance::Action(_) => {
+                diesel::update(character_dots::table.find(character_id))
+                    .set(&db::models::CharacterDots::new(
+                        character_id,
+                        &character.dots,
+                    ))
+                    .execute(conn)?;
+            }
+        }
+
+        diesel::insert_into(character_advancements::table)
+            .values(&db::models::NewCharacterAdvancement::new(
+                character_id,
+                &advance,
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to advance character ({character_id}): {e}");
+        ServerFnError::ServerError("Failed to advance character".to_string())
+    })?;
+
+    Ok(character)
+}
+
+/// Every advance the character has taken, oldest first.
+#[data::cfg_server("character/get_advancements")]
+pub async fn get_advancements(
+    character_id: types::CharacterId,
+) -> Result<Vec<types::Advance>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    let character = super::load_character(&mut conn, character_id)?;
+
+    if !crate::crew::is_in_crew(character.crew_id, &user.username) {
+        return Err(ServerFnError::Request("Character not found".to_string()));
+    }
+
+    character_advancements::table
+        .filter(character_advancements::character_id.eq(character_id))
+        .order(character_advancements::id.asc())
+        .select(db::models::CharacterAdvancement::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load advancements for character ({character_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load advancements".to_string())
+        })?
+        .into_iter()
+        .map(|a| {
+            types::Advance::try_from(a).map_err(|e| {
+                tracing::error!("Corrupt advancement for character ({character_id}): {e}");
+                ServerFnError::ServerError("Corrupt character data".to_string())
+            })
+        })
+        .collect()
+}
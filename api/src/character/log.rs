@@ -0,0 +1,51 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+#[cfg(feature = "server")]
+pub(crate) fn write_log(
+    conn: &mut diesel::SqliteConnection,
+    character_id: types::CharacterId,
+    entry: String,
+) -> QueryResult<usize> {
+    diesel::insert_into(character_log::table)
+        .values(&db::models::NewCharacterLogEntry {
+            character_id,
+            entry,
+        })
+        .execute(conn)
+}
+
+/// The character's log, newest first.
+#[data::cfg_server("character/get_log")]
+pub async fn get_log(
+    character_id: types::CharacterId,
+) -> Result<Vec<types::CharacterLogEntry>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    let character = super::load_character(&mut conn, character_id)?;
+
+    if !crate::crew::is_in_crew(character.crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Character not found".to_string(),
+        ));
+    }
+
+    let entries: Vec<db::models::CharacterLogEntry> = character_log::table
+        .filter(character_log::character_id.eq(character_id))
+        .order(character_log::id.desc())
+        .select(db::models::CharacterLogEntry::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load log for character ({character_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load log".to_string())
+        })?;
+
+    Ok(entries.into_iter().map(Into::into).collect())
+}
@@ -1,6 +1,14 @@
+mod advancement;
+mod log;
+mod stress;
 mod updates;
+mod vice;
+pub use advancement::*;
 use dioxus::prelude::server_fn::error::NoCustomError;
+pub use log::*;
+pub use stress::*;
 pub use updates::*;
+pub use vice::*;
 
 #[cfg(feature = "server")]
 use diesel::prelude::*;
@@ -16,24 +24,34 @@ pub async fn get(id: types::CharacterId) -> Result<types::Character, ServerFnErr
 
     let mut conn = db::connect();
 
+    let character = load_character(&mut conn, id)?;
+
+    if !crate::crew::is_in_crew(character.crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Character not found".to_string(),
+        ));
+    }
+
+    Ok(character)
+}
+
+#[cfg(feature = "server")]
+pub(crate) fn load_character(
+    conn: &mut diesel::SqliteConnection,
+    id: types::CharacterId,
+) -> Result<types::Character, ServerFnError> {
     let character: db::models::Character = characters::table
         .find(id)
         .select(db::models::Character::as_select())
-        .first(&mut conn)
+        .first(conn)
         .map_err(|e| {
             dioxus::logger::tracing::info!("Failed to find character: {e}");
             ServerFnError::<NoCustomError>::Request("Character not found".to_string())
         })?;
 
-    if !crate::crew::is_in_crew(character.crew_id, &user.username) {
-        return Err(ServerFnError::<NoCustomError>::Request(
-            "Character not found".to_string(),
-        ));
-    }
-
     let harm = db::models::CharacterHarm::belonging_to(&character)
         .select(db::models::CharacterHarm::as_select())
-        .load(&mut conn)
+        .load(conn)
         .map_err(|e| {
             tracing::error!("Failed to get harm for character ({}): {e}", character.id);
             ServerFnError::<NoCustomError>::ServerError("Corrupt character data".to_string())
@@ -54,7 +72,7 @@ pub async fn get(id: types::CharacterId) -> Result<types::Character, ServerFnErr
 
     let abilities = db::models::CharacterAbility::belonging_to(&character)
         .select(db::models::CharacterAbility::as_select())
-        .load(&mut conn)
+        .load(conn)
         .map_err(|e| {
             tracing::error!(
                 "Failed to get abilities for charracter ({}): {e}",
@@ -65,8 +83,10 @@ pub async fn get(id: types::CharacterId) -> Result<types::Character, ServerFnErr
         .into_iter();
 
     let contacts = db::models::CharacterContact::belonging_to(&character)
-        .select(db::models::CharacterContact::as_select())
-        .load(&mut conn)
+        .inner_join(npcs::table)
+        .order(character_contacts::id)
+        .select((npcs::name, character_contacts::friend))
+        .load::<db::models::ContactName>(conn)
         .map_err(|e| {
             tracing::error!(
                 "Failed to get contacts for charracter ({}): {e}",
@@ -78,7 +98,7 @@ pub async fn get(id: types::CharacterId) -> Result<types::Character, ServerFnErr
 
     let class_items = db::models::CharacterClassItem::belonging_to(&character)
         .select(db::models::CharacterClassItem::as_select())
-        .load(&mut conn)
+        .load(conn)
         .map_err(|e| {
             tracing::error!(
                 "Failed to get class items for charracter ({}): {e}",
@@ -90,7 +110,7 @@ pub async fn get(id: types::CharacterId) -> Result<types::Character, ServerFnErr
 
     let xp = db::models::CharacterXp::belonging_to(&character)
         .select(db::models::CharacterXp::as_select())
-        .first(&mut conn)
+        .first(conn)
         .map_err(|e| {
             tracing::error!("Failed to get xp for character ({}): {e}", character.id);
             ServerFnError::<NoCustomError>::ServerError("Corrupt character data".to_string())
@@ -98,7 +118,7 @@ pub async fn get(id: types::CharacterId) -> Result<types::Character, ServerFnErr
 
     let dots = db::models::CharacterDots::belonging_to(&character)
         .select(db::models::CharacterDots::as_select())
-        .first(&mut conn)
+        .first(conn)
         .map_err(|e| {
             tracing::error!("Failed to get dots for character ({}): {e}", character.id);
             ServerFnError::<NoCustomError>::ServerError("Corrupt character data".to_string())
@@ -120,62 +140,73 @@ pub async fn get(id: types::CharacterId) -> Result<types::Character, ServerFnErr
 
 #[data::cfg_server("character/create")]
 pub async fn create(
-    character: db::models::NewCharacter,
-) -> Result<types::Character, ServerFnError> {
-    let user: crate::User = extract().await?;
+    character: types::NewCharacter,
+) -> Result<types::Character, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
 
     if !crate::crew::is_in_crew(character.crew_id, &user.username) {
-        return Err(ServerFnError::<NoCustomError>::Request(
+        return Err(ServerFnError::Request(
             "Cannot create character in crew you are not a member of".to_string(),
         ));
     }
 
+    character
+        .validate()
+        .map_err(ServerFnError::WrappedServerError)?;
+
     let mut conn = db::connect();
 
-    let character = diesel::insert_into(db::schema::characters::table)
-        .values(&character)
-        .returning(db::models::Character::as_returning())
-        .get_result::<db::models::Character>(&mut conn)
+    let id = conn
+        .transaction::<_, diesel::result::Error, _>(|conn| {
+            let id = diesel::insert_into(characters::table)
+                .values(&db::models::NewCharacter::new(user.username, &character))
+                .returning(characters::id)
+                .get_result::<types::CharacterId>(conn)?;
+
+            diesel::insert_into(character_harm::table)
+                .values(&db::models::CharacterHarm::new(id))
+                .execute(conn)?;
+
+            diesel::insert_into(character_xp::table)
+                .values(&db::models::CharacterXp::new(id))
+                .execute(conn)?;
+
+            diesel::insert_into(character_dots::table)
+                .values(&db::models::CharacterDots::new(id, &character.dots))
+                .execute(conn)?;
+
+            diesel::insert_into(character_abilities::table)
+                .values(&db::models::NewCharacterAbility {
+                    character_id: id,
+                    name: character.ability.clone(),
+                })
+                .execute(conn)?;
+
+            let friend =
+                crate::crew::find_or_create_npc(conn, character.crew_id, &character.friend)?;
+            let rival = crate::crew::find_or_create_npc(conn, character.crew_id, &character.rival)?;
+
+            diesel::insert_into(character_contacts::table)
+                .values(&vec![
+                    db::models::NewCharacterContact {
+                        character_id: id,
+                        npc_id: friend,
+                        friend: true,
+                    },
+                    db::models::NewCharacterContact {
+                        character_id: id,
+                        npc_id: rival,
+                        friend: false,
+                    },
+                ])
+                .execute(conn)?;
+
+            Ok(id)
+        })
         .map_err(|e| {
             tracing::error!("Failed to insert new character: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to create character".to_string())
-        })?;
-
-    let harm = db::models::CharacterHarm::new(character.id);
-    diesel::insert_into(db::schema::character_harm::table)
-        .values(&harm)
-        .execute(&mut conn)
-        .map_err(|e| {
-            tracing::error!("Failed to insert new character harm: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to create character".to_string())
-        })?;
-
-    let xp = db::models::CharacterXp::new(character.id);
-    diesel::insert_into(db::schema::character_xp::table)
-        .values(&xp)
-        .execute(&mut conn)
-        .map_err(|e| {
-            tracing::error!("Failed to insert new character xp: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to create character".to_string())
+            ServerFnError::ServerError("Failed to create character".to_string())
         })?;
 
-    let dots = db::models::CharacterDots::new(character.id);
-    diesel::insert_into(db::schema::character_dots::table)
-        .values(&dots)
-        .execute(&mut conn)
-        .map_err(|e| {
-            tracing::error!("Failed to insert new character dots: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to create character".to_string())
-        })?;
-
-    Ok(db::models::IntoCharacter {
-        character,
-        harm,
-        abilities: std::iter::empty(),
-        class_items: std::iter::empty(),
-        contacts: std::iter::empty(),
-        xp,
-        dots,
-    }
-    .into())
+    load_character(&mut conn, id).map_err(|e| ServerFnError::ServerError(e.to_string()))
 }
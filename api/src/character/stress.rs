@@ -0,0 +1,117 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::*;
+
+use crate::db;
+
+#[cfg(feature = "server")]
+fn write_stress(
+    conn: &mut diesel::SqliteConnection,
+    character: &types::Character,
+) -> QueryResult<usize> {
+    use db::schema::characters::dsl;
+
+    diesel::update(dsl::characters.find(character.id))
+        .set((
+            dsl::stress.eq(character.stress as i32),
+            dsl::trauma.eq(character.trauma.bits() as i32),
+            dsl::retired.eq(character.retired),
+        ))
+        .execute(conn)
+}
+
+/// Marks stress on a character, handling overflow into trauma. Returns
+/// [`types::StressGain::TraumaNeeded`] without changing anything if the stress overflows and
+/// no trauma was chosen.
+#[data::cfg_server("character/gain_stress")]
+pub async fn gain_stress(
+    id: types::CharacterId,
+    amount: u8,
+    trauma: Option<types::Trauma>,
+) -> Result<types::StressGain, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let mut character =
+        super::load_character(&mut conn, id).map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    if character.user_id != user.username && !crate::crew::is_dm(character.crew_id, &user.username)
+    {
+        return Err(ServerFnError::Request("Character not found".to_string()));
+    }
+
+    let gain = character
+        .gain_stress(amount, trauma)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    if gain == types::StressGain::TraumaNeeded {
+        return Ok(gain);
+    }
+
+    write_stress(&mut conn, &character).map_err(|e| {
+        tracing::error!("Failed to apply stress to character ({id}): {e}");
+        ServerFnError::ServerError("Failed to update character".to_string())
+    })?;
+
+    Ok(gain)
+}
+
+/// Marks the stress for a push, assist, flashback or resistance roll, and records it against
+/// the crew's current score. Only the GM sets the cost of a flashback.
+#[data::cfg_server("character/spend_stress")]
+pub async fn spend_stress(
+    id: types::CharacterId,
+    spend: types::StressSpend,
+    note: String,
+    trauma: Option<types::Trauma>,
+) -> Result<types::StressGain, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let mut character =
+        super::load_character(&mut conn, id).map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    let is_dm = crate::crew::is_dm(character.crew_id, &user.username);
+
+    if character.user_id != user.username && !is_dm {
+        return Err(ServerFnError::Request("Character not found".to_string()));
+    }
+
+    if matches!(spend, types::StressSpend::Flashback { .. }) && !is_dm {
+        return Err(ServerFnError::WrappedServerError(
+            "Only the GM can set the cost of a flashback".to_string(),
+        ));
+    }
+
+    let gain = character
+        .gain_stress(spend.stress(), trauma)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    if gain == types::StressGain::TraumaNeeded {
+        return Ok(gain);
+    }
+
+    let score_id = crate::score::load_active_score(&mut conn, character.crew_id)
+        .map_err(|e| ServerFnError::ServerError(e.to_string()))?
+        .map(|score| score.id);
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        write_stress(conn, &character)?;
+
+        diesel::insert_into(db::schema::stress_log::table)
+            .values(&db::models::NewStressLogEntry::new(
+                id, score_id, spend, &note,
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to spend stress for character ({id}): {e}");
+        ServerFnError::ServerError("Failed to update character".to_string())
+    })?;
+
+    Ok(gain)
+}
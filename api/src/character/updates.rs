@@ -17,6 +17,27 @@ fn is_own_character(char_id: types::CharacterId, username: &str) -> bool {
         .is_ok()
 }
 
+/// Loads the character, checks it belongs to the user, and validates the change against it.
+#[cfg(feature = "server")]
+fn check_change(
+    id: types::CharacterId,
+    username: &str,
+    change: &types::CharacterChange,
+) -> Result<(), ServerFnError<types::ValidationErrors>> {
+    let mut conn = db::connect();
+
+    let character = super::load_character(&mut conn, id)
+        .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
+
+    if character.user_id != username {
+        return Err(ServerFnError::Request("Character not found".to_string()));
+    }
+
+    character
+        .validate_change(change)
+        .map_err(ServerFnError::WrappedServerError)
+}
+
 #[data::cfg_server("character/set_traits")]
 pub async fn set_traits(
     id: types::CharacterId,
@@ -81,14 +102,19 @@ pub async fn set_stress_truama_healing_armor(
     trauma: u8,
     healing: u8,
     armor: u8,
-) -> Result<(), ServerFnError> {
-    let user: crate::User = extract().await?;
-
-    if !is_own_character(id, &user.username) {
-        return Err(ServerFnError::<NoCustomError>::Request(
-            "Character not found".to_string(),
-        ));
-    }
+) -> Result<(), ServerFnError<types::ValidationErrors>> {
+    let user = crate::User::extract_as().await?;
+
+    check_change(
+        id,
+        &user.username,
+        &types::CharacterChange::StressTraumaHealingArmor {
+            stress,
+            trauma: types::TraumaFlags::from_bits_retain(trauma),
+            healing,
+            armor: types::ArmorFlags::from_bits_retain(armor),
+        },
+    )?;
     use db::schema::characters::dsl;
 
     let mut conn = db::connect();
@@ -103,12 +129,32 @@ pub async fn set_stress_truama_healing_armor(
         .execute(&mut conn)
         .map_err(|e| {
             tracing::error!("Failed to update character stress/trauma: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to update character".to_string())
+            ServerFnError::ServerError("Failed to update character".to_string())
         })?;
 
     Ok(())
 }
 
+/// Writes every harm slot, shared by the sheet and recovery.
+#[cfg(feature = "server")]
+pub(crate) fn write_harm(
+    conn: &mut diesel::SqliteConnection,
+    id: types::CharacterId,
+    harm: &types::Harm,
+) -> QueryResult<usize> {
+    use db::schema::character_harm::dsl;
+
+    diesel::update(dsl::character_harm.find(id))
+        .set((
+            dsl::harm_1_1.eq(&harm.0[0]),
+            dsl::harm_1_2.eq(&harm.0[1]),
+            dsl::harm_2_1.eq(&harm.1[0]),
+            dsl::harm_2_2.eq(&harm.1[1]),
+            dsl::harm_3.eq(&harm.2),
+        ))
+        .execute(conn)
+}
+
 #[data::cfg_server("character/set_harm")]
 pub async fn set_harm(id: types::CharacterId, harm: types::Harm) -> Result<(), ServerFnError> {
     let user: crate::User = extract().await?;
@@ -118,23 +164,13 @@ pub async fn set_harm(id: types::CharacterId, harm: types::Harm) -> Result<(), S
             "Character not found".to_string(),
         ));
     }
-    use db::schema::character_harm::dsl;
 
     let mut conn = db::connect();
 
-    diesel::update(dsl::character_harm.find(id))
-        .set((
-            dsl::harm_1_1.eq(&harm.0[0]),
-            dsl::harm_1_2.eq(&harm.0[1]),
-            dsl::harm_2_1.eq(&harm.1[0]),
-            dsl::harm_2_2.eq(&harm.1[1]),
-            dsl::harm_3.eq(harm.2),
-        ))
-        .execute(&mut conn)
-        .map_err(|e| {
-            tracing::error!("Failed to update character harm: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to update character".to_string())
-        })?;
+    write_harm(&mut conn, id, &harm).map_err(|e| {
+        tracing::error!("Failed to update character harm: {e}");
+        ServerFnError::<NoCustomError>::ServerError("Failed to update character".to_string())
+    })?;
 
     Ok(())
 }
@@ -235,23 +271,30 @@ pub async fn add_contact(
             "Character not found".to_string(),
         ));
     }
-    use db::schema::character_contacts;
+    use db::schema::{character_contacts, characters};
 
     let mut conn = db::connect();
 
-    let new_character_contact = db::models::NewCharacterContact {
-        character_id,
-        name,
-        friend,
-    };
-
-    diesel::insert_into(character_contacts::table)
-        .values(&new_character_contact)
-        .execute(&mut conn)
-        .map_err(|e| {
-            tracing::error!("Failed to add contact to character: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to add contact".to_string())
-        })?;
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        let crew_id = characters::table
+            .find(character_id)
+            .select(characters::crew_id)
+            .first(conn)?;
+
+        let new_character_contact = db::models::NewCharacterContact {
+            character_id,
+            npc_id: crate::crew::find_or_create_npc(conn, crew_id, &name)?,
+            friend,
+        };
+
+        diesel::insert_into(character_contacts::table)
+            .values(&new_character_contact)
+            .execute(conn)
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to add contact to character: {e}");
+        ServerFnError::<NoCustomError>::ServerError("Failed to add contact".to_string())
+    })?;
 
     Ok(())
 }
@@ -269,13 +312,13 @@ pub async fn remove_contact(
             "Character not found".to_string(),
         ));
     }
-    use db::schema::character_contacts::dsl;
+    use db::schema::{character_contacts::dsl, npcs};
 
     let mut conn = db::connect();
 
     diesel::delete(
         dsl::character_contacts
-            .filter(dsl::name.eq(name))
+            .filter(dsl::npc_id.eq_any(npcs::table.filter(npcs::name.eq(name)).select(npcs::id)))
             .filter(dsl::character_id.eq(character_id))
             .filter(dsl::friend.eq(friend)),
     )
@@ -292,18 +335,26 @@ pub async fn remove_contact(
 pub async fn add_class_item(
     character_id: types::CharacterId,
     name: String,
-) -> Result<(), ServerFnError> {
-    let user: crate::User = extract().await?;
+) -> Result<(), ServerFnError<types::ValidationErrors>> {
+    let user = crate::User::extract_as().await?;
 
-    if !is_own_character(character_id, &user.username) {
-        return Err(ServerFnError::<NoCustomError>::Request(
-            "Character not found".to_string(),
-        ));
-    }
     use db::schema::character_class_items;
 
     let mut conn = db::connect();
 
+    let character = super::load_character(&mut conn, character_id)
+        .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
+
+    if character.user_id != user.username {
+        return Err(ServerFnError::Request("Character not found".to_string()));
+    }
+
+    let mut class_items = character.class_items.clone();
+    class_items.push(name.clone());
+    character
+        .validate_change(&types::CharacterChange::ClassItems(class_items))
+        .map_err(ServerFnError::WrappedServerError)?;
+
     let new_character_class_item = db::models::NewCharacterClassItem { character_id, name };
 
     diesel::insert_into(character_class_items::table)
@@ -311,7 +362,7 @@ pub async fn add_class_item(
         .execute(&mut conn)
         .map_err(|e| {
             tracing::error!("Failed to add class item to character: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to add class item".to_string())
+            ServerFnError::ServerError("Failed to add class item".to_string())
         })?;
 
     Ok(())
@@ -352,14 +403,14 @@ pub async fn set_coin_stash(
     character_id: types::CharacterId,
     coin: u8,
     stash: u8,
-) -> Result<(), ServerFnError> {
-    let user: crate::User = extract().await?;
+) -> Result<(), ServerFnError<types::ValidationErrors>> {
+    let user = crate::User::extract_as().await?;
 
-    if !is_own_character(character_id, &user.username) {
-        return Err(ServerFnError::<NoCustomError>::Request(
-            "Character not found".to_string(),
-        ));
-    }
+    check_change(
+        character_id,
+        &user.username,
+        &types::CharacterChange::CoinStash { coin, stash },
+    )?;
     use db::schema::characters::dsl;
 
     let mut conn = db::connect();
@@ -369,37 +420,48 @@ pub async fn set_coin_stash(
         .execute(&mut conn)
         .map_err(|e| {
             tracing::error!("Failed to update character coin stash: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to update character".to_string())
+            ServerFnError::ServerError("Failed to update character".to_string())
         })?;
 
     Ok(())
 }
 
+/// Writes a character's XP tracks, capped at each track's maximum. Every XP change goes
+/// through here, so it can run inside a caller's transaction.
+#[cfg(feature = "server")]
+pub(crate) fn write_xp(
+    conn: &mut diesel::SqliteConnection,
+    character_id: types::CharacterId,
+    xp: &types::XP,
+) -> QueryResult<types::XP> {
+    let xp = xp.capped();
+
+    diesel::update(db::schema::character_xp::table.find(character_id))
+        .set(&db::models::CharacterXp::with_xp(character_id, &xp))
+        .execute(conn)?;
+
+    Ok(xp)
+}
+
 #[data::cfg_server("character/set_xp")]
-pub async fn set_xp(character_id: types::CharacterId, xp: types::XP) -> Result<(), ServerFnError> {
-    let user: crate::User = extract().await?;
+pub async fn set_xp(
+    character_id: types::CharacterId,
+    xp: types::XP,
+) -> Result<(), ServerFnError<types::ValidationErrors>> {
+    let user = crate::User::extract_as().await?;
 
-    if !is_own_character(character_id, &user.username) {
-        return Err(ServerFnError::<NoCustomError>::Request(
-            "Character not found".to_string(),
-        ));
-    }
-    use db::schema::character_xp::dsl;
+    check_change(
+        character_id,
+        &user.username,
+        &types::CharacterChange::Xp(xp.clone()),
+    )?;
 
     let mut conn = db::connect();
 
-    diesel::update(dsl::character_xp.find(character_id))
-        .set((
-            dsl::playbook.eq(xp.playbook as i32),
-            dsl::insight.eq(xp.insight as i32),
-            dsl::prowess.eq(xp.prowess as i32),
-            dsl::resolve.eq(xp.resolve as i32),
-        ))
-        .execute(&mut conn)
-        .map_err(|e| {
-            tracing::error!("Failed to update character xp: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to update character".to_string())
-        })?;
+    write_xp(&mut conn, character_id, &xp).map_err(|e| {
+        tracing::error!("Failed to update character xp: {e}");
+        ServerFnError::ServerError("Failed to update character".to_string())
+    })?;
 
     Ok(())
 }
@@ -408,37 +470,24 @@ pub async fn set_xp(character_id: types::CharacterId, xp: types::XP) -> Result<(
 pub async fn set_dots(
     character_id: types::CharacterId,
     dots: types::Dots,
-) -> Result<(), ServerFnError> {
-    let user: crate::User = extract().await?;
+) -> Result<(), ServerFnError<types::ValidationErrors>> {
+    let user = crate::User::extract_as().await?;
 
-    if !is_own_character(character_id, &user.username) {
-        return Err(ServerFnError::<NoCustomError>::Request(
-            "Character not found".to_string(),
-        ));
-    }
+    check_change(
+        character_id,
+        &user.username,
+        &types::CharacterChange::Dots(dots.clone()),
+    )?;
     use db::schema::character_dots::dsl;
 
     let mut conn = db::connect();
 
     diesel::update(dsl::character_dots.find(character_id))
-        .set((
-            dsl::hunt.eq(dots.hunt as i32),
-            dsl::study.eq(dots.study as i32),
-            dsl::survey.eq(dots.survey as i32),
-            dsl::tinker.eq(dots.tinker as i32),
-            dsl::finesse.eq(dots.finesse as i32),
-            dsl::prowl.eq(dots.prowl as i32),
-            dsl::skirmish.eq(dots.skirmish as i32),
-            dsl::wreck.eq(dots.wreck as i32),
-            dsl::attune.eq(dots.attune as i32),
-            dsl::command.eq(dots.command as i32),
-            dsl::consort.eq(dots.consort as i32),
-            dsl::sway.eq(dots.sway as i32),
-        ))
+        .set(&db::models::CharacterDots::new(character_id, &dots))
         .execute(&mut conn)
         .map_err(|e| {
             tracing::error!("Failed to update character dots: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to update character".to_string())
+            ServerFnError::ServerError("Failed to update character".to_string())
         })?;
 
     Ok(())
@@ -448,14 +497,14 @@ pub async fn set_dots(
 pub async fn set_load(
     character_id: types::CharacterId,
     load: Option<types::Load>,
-) -> Result<(), ServerFnError> {
-    let user: crate::User = extract().await?;
+) -> Result<(), ServerFnError<types::ValidationErrors>> {
+    let user = crate::User::extract_as().await?;
 
-    if !is_own_character(character_id, &user.username) {
-        return Err(ServerFnError::<NoCustomError>::Request(
-            "Character not found".to_string(),
-        ));
-    }
+    check_change(
+        character_id,
+        &user.username,
+        &types::CharacterChange::Load(load),
+    )?;
     use db::schema::characters::dsl;
 
     let mut conn = db::connect();
@@ -471,21 +520,24 @@ pub async fn set_load(
         .execute(&mut conn)
         .map_err(|e| {
             tracing::error!("Failed to update character load: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to update character".to_string())
+            ServerFnError::ServerError("Failed to update character".to_string())
         })?;
 
     Ok(())
 }
 
 #[data::cfg_server("character/set_items")]
-pub async fn set_items(character_id: types::CharacterId, items: u16) -> Result<(), ServerFnError> {
-    let user: crate::User = extract().await?;
+pub async fn set_items(
+    character_id: types::CharacterId,
+    items: u16,
+) -> Result<(), ServerFnError<types::ValidationErrors>> {
+    let user = crate::User::extract_as().await?;
 
-    if !is_own_character(character_id, &user.username) {
-        return Err(ServerFnError::<NoCustomError>::Request(
-            "Character not found".to_string(),
-        ));
-    }
+    check_change(
+        character_id,
+        &user.username,
+        &types::CharacterChange::Items(types::Items::from_bits_retain(items)),
+    )?;
     use db::schema::characters::dsl;
 
     let mut conn = db::connect();
@@ -495,7 +547,7 @@ pub async fn set_items(character_id: types::CharacterId, items: u16) -> Result<(
         .execute(&mut conn)
         .map_err(|e| {
             tracing::error!("Failed to update character items: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to update character".to_string())
+            ServerFnError::ServerError("Failed to update character".to_string())
         })?;
 
     Ok(())
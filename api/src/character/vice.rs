@@ -0,0 +1,68 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::*;
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+/// Resolves an overindulgence with the chosen consequence, recording it in the character's
+/// log.
+#[data::cfg_server("character/overindulge")]
+pub async fn overindulge(
+    character_id: types::CharacterId,
+    consequence: types::Overindulgence,
+) -> Result<(), ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let mut character = super::load_character(&mut conn, character_id)
+        .map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    if character.user_id != user.username {
+        return Err(ServerFnError::Request("Character not found".to_string()));
+    }
+
+    character
+        .resolve_overindulgence()
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::update(characters::table.find(character_id))
+            .set(characters::pending_overindulgence.eq(false))
+            .execute(conn)?;
+
+        if consequence == types::Overindulgence::Brag {
+            let heat: i32 = crews::table
+                .find(character.crew_id)
+                .select(crews::heat)
+                .first(conn)?;
+
+            let heat =
+                (heat + types::Overindulgence::BRAG_HEAT as i32).min(types::Crew::MAX_HEAT as i32);
+
+            diesel::update(crews::table.find(character.crew_id))
+                .set(crews::heat.eq(heat))
+                .execute(conn)?;
+        }
+
+        super::write_log(
+            conn,
+            character_id,
+            format!(
+                "Overindulged in {}: {consequence}. {}",
+                character.vice,
+                consequence.description()
+            ),
+        )?;
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to resolve overindulgence for character ({character_id}): {e}");
+        ServerFnError::ServerError("Failed to resolve overindulgence".to_string())
+    })?;
+
+    Ok(())
+}
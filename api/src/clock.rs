@@ -0,0 +1,194 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+/// Crew clocks are visible to and editable by the whole crew, character clocks are visible to
+/// the crew but only editable by the character's player. Faction clocks are the GM's alone.
+#[cfg(feature = "server")]
+fn can_access(owner: types::ClockOwner, username: &str, edit: bool) -> bool {
+    match owner {
+        types::ClockOwner::Crew(crew_id) => crate::crew::is_in_crew(crew_id, username),
+        types::ClockOwner::Faction(crew_id, faction) => {
+            types::factions::Faction::find(faction).is_some()
+                && crate::crew::is_dm(crew_id, username)
+        }
+        types::ClockOwner::Character(character_id) => {
+            let mut conn = db::connect();
+
+            let Ok(character) = characters::table
+                .find(character_id)
+                .select(db::models::Character::as_select())
+                .first(&mut conn)
+            else {
+                return false;
+            };
+
+            if edit {
+                character.user_id == username
+            } else {
+                crate::crew::is_in_crew(character.crew_id, username)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+pub(crate) fn find_clock(
+    conn: &mut diesel::SqliteConnection,
+    id: types::ClockId,
+    username: &str,
+) -> Result<db::models::Clock, ServerFnError> {
+    let clock: db::models::Clock = clocks::table
+        .find(id)
+        .select(db::models::Clock::as_select())
+        .first(conn)
+        .map_err(|e| {
+            tracing::info!("Failed to find clock: {e}");
+            ServerFnError::<NoCustomError>::Request("Clock not found".to_string())
+        })?;
+
+    if !clock
+        .owner()
+        .is_some_and(|owner| can_access(owner, username, true))
+    {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Clock not found".to_string(),
+        ));
+    }
+
+    Ok(clock)
+}
+
+#[cfg(feature = "server")]
+pub(crate) fn into_clock(clock: db::models::Clock) -> Result<types::Clock, ServerFnError> {
+    clock.try_into().map_err(|e| {
+        tracing::error!("{e}");
+        ServerFnError::<NoCustomError>::ServerError("Corrupt clock data".to_string())
+    })
+}
+
+#[data::cfg_server("clock/get")]
+pub async fn get_clocks(owner: types::ClockOwner) -> Result<Vec<types::Clock>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !can_access(owner, &user.username, false) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Clocks not found".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let query = clocks::table
+        .select(db::models::Clock::as_select())
+        .order(clocks::id)
+        .into_boxed();
+
+    let query = match owner {
+        types::ClockOwner::Crew(crew_id) => query
+            .filter(clocks::crew_id.eq(crew_id))
+            .filter(clocks::faction.is_null()),
+        types::ClockOwner::Character(character_id) => {
+            query.filter(clocks::character_id.eq(character_id))
+        }
+        types::ClockOwner::Faction(crew_id, faction) => query
+            .filter(clocks::crew_id.eq(crew_id))
+            .filter(clocks::faction.eq(types::factions::Faction::find(faction).map(|f| f.name))),
+    };
+
+    let clocks: Vec<db::models::Clock> = query.load(&mut conn).map_err(|e| {
+        tracing::error!("Failed to load clocks: {e}");
+        ServerFnError::<NoCustomError>::ServerError("Failed to load clocks".to_string())
+    })?;
+
+    clocks.into_iter().map(into_clock).collect()
+}
+
+#[data::cfg_server("clock/create")]
+pub async fn create(
+    owner: types::ClockOwner,
+    name: String,
+    size: types::ClockSize,
+) -> Result<types::Clock, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !can_access(owner, &user.username, true) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Cannot create a clock here".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let clock = diesel::insert_into(clocks::table)
+        .values(&db::models::NewClock::new(owner, name, size))
+        .returning(db::models::Clock::as_returning())
+        .get_result(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to insert new clock: {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to create clock".to_string())
+        })?;
+
+    into_clock(clock)
+}
+
+#[data::cfg_server("clock/tick")]
+pub async fn tick(id: types::ClockId, ticks: i8) -> Result<types::Clock, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    let mut clock = into_clock(find_clock(&mut conn, id, &user.username)?)?;
+    clock.tick(ticks);
+
+    diesel::update(clocks::table.find(id))
+        .set(clocks::filled.eq(clock.filled as i32))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to tick clock: {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to update clock".to_string())
+        })?;
+
+    Ok(clock)
+}
+
+#[data::cfg_server("clock/rename")]
+pub async fn rename(id: types::ClockId, name: String) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    find_clock(&mut conn, id, &user.username)?;
+
+    diesel::update(clocks::table.find(id))
+        .set(clocks::name.eq(name))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to rename clock: {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to update clock".to_string())
+        })?;
+
+    Ok(())
+}
+
+#[data::cfg_server("clock/delete")]
+pub async fn delete(id: types::ClockId) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    find_clock(&mut conn, id, &user.username)?;
+
+    diesel::delete(clocks::table.find(id))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to delete clock: {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to delete clock".to_string())
+        })?;
+
+    Ok(())
+}
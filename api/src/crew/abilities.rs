@@ -0,0 +1,222 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+#[cfg(feature = "server")]
+fn load_specialty(
+    conn: &mut diesel::SqliteConnection,
+    crew_id: types::CrewId,
+) -> Result<types::CrewSpecialty, ServerFnError<String>> {
+    crews::table
+        .find(crew_id)
+        .select(crews::specialty)
+        .first(conn)
+        .map_err(|e| {
+            tracing::error!("Failed to find crew ({crew_id}): {e}");
+            ServerFnError::Request("Crew not found".to_string())
+        })
+}
+
+#[cfg(feature = "server")]
+pub(super) fn load_abilities(
+    conn: &mut diesel::SqliteConnection,
+    crew_id: types::CrewId,
+) -> QueryResult<Vec<String>> {
+    crew_abilities::table
+        .filter(crew_abilities::crew_id.eq(crew_id))
+        .order(crew_abilities::id.asc())
+        .select(crew_abilities::name)
+        .load(conn)
+}
+
+/// The names of the crew's special abilities, in the order they were taken.
+#[data::cfg_server("crew/get_abilities")]
+pub async fn get_crew_abilities(crew_id: types::CrewId) -> Result<Vec<String>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    load_abilities(&mut conn, crew_id).map_err(|e| {
+        tracing::error!("Failed to load abilities for crew ({crew_id}): {e}");
+        ServerFnError::<NoCustomError>::ServerError("Failed to load abilities".to_string())
+    })
+}
+
+#[data::cfg_server("crew/add_ability")]
+pub async fn add_crew_ability(
+    crew_id: types::CrewId,
+    name: String,
+) -> Result<(), ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let specialty = load_specialty(&mut conn, crew_id)?;
+    let abilities = load_abilities(&mut conn, crew_id).map_err(|e| {
+        tracing::error!("Failed to load abilities for crew ({crew_id}): {e}");
+        ServerFnError::ServerError("Failed to add ability".to_string())
+    })?;
+
+    specialty
+        .check_ability(&name, &abilities)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    diesel::insert_into(crew_abilities::table)
+        .values(&db::models::NewCrewAbility { crew_id, name })
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to add ability to crew ({crew_id}): {e}");
+            ServerFnError::ServerError("Failed to add ability".to_string())
+        })?;
+
+    Ok(())
+}
+
+/// Gives up one of the crew's special abilities. Veteran goes one at a time.
+#[data::cfg_server("crew/remove_ability")]
+pub async fn remove_crew_ability(
+    crew_id: types::CrewId,
+    name: String,
+) -> Result<(), ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let specialty = load_specialty(&mut conn, crew_id)?;
+    let abilities = load_abilities(&mut conn, crew_id).map_err(|e| {
+        tracing::error!("Failed to load abilities for crew ({crew_id}): {e}");
+        ServerFnError::ServerError("Failed to remove ability".to_string())
+    })?;
+
+    specialty
+        .check_removal(&name, &abilities)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    let latest: Option<i32> = crew_abilities::table
+        .filter(crew_abilities::crew_id.eq(crew_id))
+        .filter(crew_abilities::name.eq(&name))
+        .order(crew_abilities::id.desc())
+        .select(crew_abilities::id)
+        .first(&mut conn)
+        .optional()
+        .map_err(|e| {
+            tracing::error!("Failed to find ability ({name}) of crew ({crew_id}): {e}");
+            ServerFnError::ServerError("Failed to remove ability".to_string())
+        })?;
+
+    if let Some(id) = latest {
+        diesel::delete(crew_abilities::table.find(id))
+            .execute(&mut conn)
+            .map_err(|e| {
+                tracing::error!("Failed to remove ability from crew ({crew_id}): {e}");
+                ServerFnError::ServerError("Failed to remove ability".to_string())
+            })?;
+    }
+
+    Ok(())
+}
+
+/// The boxes ticked on each of the crew's upgrades.
+#[data::cfg_server("crew/get_upgrades")]
+pub async fn get_crew_upgrades(
+    crew_id: types::CrewId,
+) -> Result<Vec<types::CrewUpgradeMark>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let upgrades: Vec<db::models::CrewUpgrade> = crew_upgrades::table
+        .filter(crew_upgrades::crew_id.eq(crew_id))
+        .select(db::models::CrewUpgrade::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load upgrades for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load upgrades".to_string())
+        })?;
+
+    Ok(upgrades.into_iter().map(Into::into).collect())
+}
+
+/// Ticks `boxes` boxes of an upgrade, removing it at zero.
+#[data::cfg_server("crew/set_upgrade")]
+pub async fn set_crew_upgrade(
+    crew_id: types::CrewId,
+    name: String,
+    boxes: u8,
+) -> Result<(), ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let specialty = load_specialty(&mut conn, crew_id)?;
+
+    let Some(upgrade) = specialty.find_upgrade(&name) else {
+        return Err(ServerFnError::WrappedServerError(format!(
+            "{specialty} can't take {name}"
+        )));
+    };
+
+    if boxes > upgrade.boxes {
+        return Err(ServerFnError::WrappedServerError(format!(
+            "{name} only has {} boxes",
+            upgrade.boxes
+        )));
+    }
+
+    let res = if boxes == 0 {
+        diesel::delete(crew_upgrades::table.find((crew_id, &name))).execute(&mut conn)
+    } else {
+        let upgrade = db::models::CrewUpgrade {
+            crew_id,
+            name: name.clone(),
+            boxes: boxes as i32,
+        };
+        diesel::insert_into(crew_upgrades::table)
+            .values(&upgrade)
+            .on_conflict((crew_upgrades::crew_id, crew_upgrades::name))
+            .do_update()
+            .set(crew_upgrades::boxes.eq(boxes as i32))
+            .execute(&mut conn)
+    };
+
+    res.map_err(|e| {
+        tracing::error!("Failed to set upgrade ({name}) for crew ({crew_id}): {e}");
+        ServerFnError::ServerError("Failed to update upgrade".to_string())
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+#[cfg(feature = "server")]
+fn load_crew(
+    conn: &mut diesel::SqliteConnection,
+    crew_id: types::CrewId,
+) -> Result<types::Crew, ServerFnError<String>> {
+    crews::table
+        .find(crew_id)
+        .select(db::models::Crew::as_select())
+        .first(conn)
+        .map(Into::into)
+        .map_err(|e| {
+            tracing::error!("Failed to find crew ({crew_id}): {e}");
+            ServerFnError::Request("Crew not found".to_string())
+        })
+}
+
+/// Marks the crew's end of session XP.
+#[data::cfg_server("crew/mark_xp")]
+pub async fn mark_crew_xp(
+    crew_id: types::CrewId,
+    answers: types::CrewXpAnswers,
+) -> Result<types::Crew, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_dm(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Only the GM can mark crew XP".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let mut crew = load_crew(&mut conn, crew_id)
+        .map_err(|e| ServerFnError::<NoCustomError>::Request(e.to_string()))?;
+    crew.mark_xp(answers.marks());
+
+    diesel::update(crews::table.find(crew_id))
+        .set(crews::xp.eq(crew.xp as i32))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to mark XP for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to mark XP".to_string())
+        })?;
+
+    Ok(crew)
+}
+
+/// Spends the crew's full XP track on a special ability or upgrade boxes.
+#[data::cfg_server("crew/advance")]
+pub async fn advance_crew(
+    crew_id: types::CrewId,
+    advance: types::CrewAdvance,
+) -> Result<types::Crew, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let mut crew = load_crew(&mut conn, crew_id)?;
+
+    let abilities = super::load_abilities(&mut conn, crew_id).map_err(|e| {
+        tracing::error!("Failed to load abilities for crew ({crew_id}): {e}");
+        ServerFnError::ServerError("Failed to advance crew".to_string())
+    })?;
+
+    let upgrades: Vec<types::CrewUpgradeMark> = crew_upgrades::table
+        .filter(crew_upgrades::crew_id.eq(crew_id))
+        .select(db::models::CrewUpgrade::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load upgrades for crew ({crew_id}): {e}");
+            ServerFnError::ServerError("Failed to advance crew".to_string())
+        })?
+        .into_iter()
+        .map(|u: db::models::CrewUpgrade| u.into())
+        .collect();
+
+    let upgrades = crew
+        .advance(&advance, &abilities, &upgrades)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        match &advance {
+            types::CrewAdvance::Ability(name) => {
+                diesel::insert_into(crew_abilities::table)
+                    .values(&db::models::NewCrewAbility {
+                        crew_id,
+                        name: name.clone(),
+                    })
+                    .execute(conn)?;
+            }
+            types::CrewAdvance::Upgrades(names) => {
+                for upgrade in upgrades.iter().filter(|u| names.contains(&u.name)) {
+                    diesel::insert_into(crew_upgrades::table)
+                        .values(&db::models::CrewUpgrade {
+                            crew_id,
+                            name: upgrade.name.clone(),
+                            boxes: upgrade.boxes as i32,
+                        })
+                        .on_conflict((crew_upgrades::crew_id, crew_upgrades::name))
+                        .do_update()
+                        .set(crew_upgrades::boxes.eq(upgrade.boxes as i32))
+                        .execute(conn)?;
+                }
+            }
+        }
+
+        diesel::update(crews::table.find(crew_id))
+            .set(crews::xp.eq(crew.xp as i32))
+            .execute(conn)?;
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to advance crew ({crew_id}): {e}");
+        ServerFnError::ServerError("Failed to advance crew".to_string())
+    })?;
+
+    Ok(crew)
+}
+
+/// Develops the crew once its rep track is full, moving up a tier or firming up its hold.
+/// Players can put in coin from their own characters' stashes towards the cost.
+#[data::cfg_server("crew/develop")]
+pub async fn develop_crew(
+    crew_id: types::CrewId,
+    contributions: Vec<(types::CharacterId, u8)>,
+) -> Result<types::Crew, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let mut crew = load_crew(&mut conn, crew_id)?;
+
+    let mut contributors: Vec<(types::Character, u8)> = vec![];
+    for (character_id, coin) in contributions.into_iter().filter(|(_, coin)| *coin > 0) {
+        let character = crate::character::load_character(&mut conn, character_id)
+            .map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+        if character.crew_id != crew_id || character.user_id != user.username {
+            return Err(ServerFnError::Request("Character not found".to_string()));
+        }
+
+        if contributors.iter().any(|(c, _)| c.id == character_id) {
+            return Err(ServerFnError::WrappedServerError(format!(
+                "{} can only put in coin once",
+                character.name
+            )));
+        }
+
+        if coin > character.stash {
+            return Err(ServerFnError::WrappedServerError(format!(
+                "{} only has {} coin in their stash",
+                character.name, character.stash
+            )));
+        }
+
+        contributors.push((character, coin));
+    }
+
+    let contributed = contributors
+        .iter()
+        .try_fold(0u8, |total, (_, coin)| total.checked_add(*coin))
+        .ok_or_else(|| ServerFnError::WrappedServerError("Too much coin put in".to_string()))?;
+
+    let claims = super::load_claims(&mut conn, crew_id).map_err(|e| {
+        tracing::error!("Failed to load claims for crew ({crew_id}): {e}");
+        ServerFnError::ServerError("Failed to develop crew".to_string())
+    })?;
+
+    crew.develop(crew.specialty.turf(&claims), contributed)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        for (character, coin) in &contributors {
+            diesel::update(characters::table.find(character.id))
+                .set(characters::stash.eq((character.stash - coin) as i32))
+                .execute(conn)?;
+
+            crate::character::write_log(
+                conn,
+                character.id,
+                format!("Put {coin} coin from their stash towards developing the crew"),
+            )?;
+        }
+
+        diesel::update(crews::table.find(crew_id))
+            .set((
+                crews::reputation.eq(crew.reputation as i32),
+                crews::tier.eq(crew.tier as i32),
+                crews::hold.eq(crew.hold),
+                crews::coin.eq(crew.coin as i32),
+            ))
+            .execute(conn)?;
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to develop crew ({crew_id}): {e}");
+        ServerFnError::ServerError("Failed to develop crew".to_string())
+    })?;
+
+    Ok(crew)
+}
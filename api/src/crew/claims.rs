@@ -0,0 +1,197 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+#[cfg(feature = "server")]
+pub(super) fn load_claims(
+    conn: &mut diesel::SqliteConnection,
+    crew_id: types::CrewId,
+) -> QueryResult<Vec<types::ClaimPosition>> {
+    let positions: Vec<i32> = crew_claims::table
+        .filter(crew_claims::crew_id.eq(crew_id))
+        .select(crew_claims::position)
+        .load(conn)?;
+
+    Ok(positions.into_iter().map(|p| p as u8).collect())
+}
+
+/// The positions of the claims the crew holds, besides their lair.
+#[data::cfg_server("crew/get_claims")]
+pub async fn get_claims(
+    crew_id: types::CrewId,
+) -> Result<Vec<types::ClaimPosition>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    load_claims(&mut conn, crew_id).map_err(|e| {
+        tracing::error!("Failed to load claims for crew ({crew_id}): {e}");
+        ServerFnError::<NoCustomError>::ServerError("Failed to load claims".to_string())
+    })
+}
+
+/// Takes a claim next to one the crew already holds.
+#[data::cfg_server("crew/seize_claim")]
+pub async fn seize_claim(
+    crew_id: types::CrewId,
+    position: types::ClaimPosition,
+) -> Result<(), ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let specialty: types::CrewSpecialty = crews::table
+        .find(crew_id)
+        .select(crews::specialty)
+        .first(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to find crew ({crew_id}): {e}");
+            ServerFnError::Request("Crew not found".to_string())
+        })?;
+
+    let held = load_claims(&mut conn, crew_id).map_err(|e| {
+        tracing::error!("Failed to load claims for crew ({crew_id}): {e}");
+        ServerFnError::ServerError("Failed to seize claim".to_string())
+    })?;
+
+    specialty
+        .check_seize(&held, position)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    diesel::insert_into(crew_claims::table)
+        .values(&db::models::CrewClaim {
+            crew_id,
+            position: position as i32,
+            district: None,
+        })
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to seize claim ({position}) for crew ({crew_id}): {e}");
+            ServerFnError::ServerError("Failed to seize claim".to_string())
+        })?;
+
+    Ok(())
+}
+
+#[data::cfg_server("crew/lose_claim")]
+pub async fn lose_claim(
+    crew_id: types::CrewId,
+    position: types::ClaimPosition,
+) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    diesel::delete(crew_claims::table.find((crew_id, position as i32)))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to lose claim ({position}) for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to lose claim".to_string())
+        })?;
+
+    Ok(())
+}
+
+/// The districts the crew's claims sit in, for claims that have one.
+#[data::cfg_server("crew/get_claim_districts")]
+pub async fn get_claim_districts(
+    crew_id: types::CrewId,
+) -> Result<Vec<(types::ClaimPosition, types::DistrictId)>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let districts: Vec<(i32, String)> = crew_claims::table
+        .filter(crew_claims::crew_id.eq(crew_id))
+        .filter(crew_claims::district.is_not_null())
+        .select((
+            crew_claims::position,
+            crew_claims::district.assume_not_null(),
+        ))
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load claim districts for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load claims".to_string())
+        })?;
+
+    Ok(districts
+        .into_iter()
+        .filter_map(|(position, district)| {
+            types::districts::District::id_of(&district).map(|d| (position as u8, d))
+        })
+        .collect())
+}
+
+/// Places a held claim in a district, or clears it with `None`.
+#[data::cfg_server("crew/set_claim_district")]
+pub async fn set_claim_district(
+    crew_id: types::CrewId,
+    position: types::ClaimPosition,
+    district: Option<types::DistrictId>,
+) -> Result<(), ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let district = district
+        .map(|district| {
+            types::districts::District::find(district)
+                .map(|d| d.name)
+                .ok_or_else(|| {
+                    ServerFnError::WrappedServerError(format!("Unknown district: {district}"))
+                })
+        })
+        .transpose()?;
+
+    let mut conn = db::connect();
+
+    let updated = diesel::update(crew_claims::table.find((crew_id, position as i32)))
+        .set(crew_claims::district.eq(district))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!(
+                "Failed to set district of claim ({position}) for crew ({crew_id}): {e}"
+            );
+            ServerFnError::ServerError("Failed to update claim".to_string())
+        })?;
+
+    if updated == 0 {
+        return Err(ServerFnError::WrappedServerError(
+            "The crew doesn't hold that claim".to_string(),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+/// Loads a cohort and checks the user is in its crew.
+#[cfg(feature = "server")]
+fn load_cohort<E>(
+    conn: &mut diesel::SqliteConnection,
+    id: types::CohortId,
+    username: &str,
+) -> Result<types::Cohort, ServerFnError<E>> {
+    let cohort: db::models::Cohort = cohorts::table
+        .find(id)
+        .select(db::models::Cohort::as_select())
+        .first(conn)
+        .map_err(|e| {
+            tracing::info!("Failed to find cohort ({id}): {e}");
+            ServerFnError::Request("Cohort not found".to_string())
+        })?;
+
+    if !super::is_in_crew(cohort.crew_id, username) {
+        return Err(ServerFnError::Request("Cohort not found".to_string()));
+    }
+
+    cohort.try_into().map_err(|e: String| {
+        tracing::error!("Invalid cohort ({id}): {e}");
+        ServerFnError::ServerError("Failed to load cohort".to_string())
+    })
+}
+
+#[cfg(feature = "server")]
+fn save_cohort(
+    conn: &mut diesel::SqliteConnection,
+    cohort: types::Cohort,
+) -> Result<types::Cohort, ServerFnError<String>> {
+    let id = cohort.id;
+
+    diesel::update(cohorts::table.find(id))
+        .set(&db::models::Cohort::from(cohort.clone()))
+        .execute(conn)
+        .map_err(|e| {
+            tracing::error!("Failed to update cohort ({id}): {e}");
+            ServerFnError::ServerError("Failed to update cohort".to_string())
+        })?;
+
+    Ok(cohort)
+}
+
+#[data::cfg_server("crew/get_cohorts")]
+pub async fn get_cohorts(crew_id: types::CrewId) -> Result<Vec<types::Cohort>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let cohorts: Vec<db::models::Cohort> = cohorts::table
+        .filter(cohorts::crew_id.eq(crew_id))
+        .order(cohorts::id.asc())
+        .select(db::models::Cohort::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load cohorts for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load cohorts".to_string())
+        })?;
+
+    cohorts
+        .into_iter()
+        .map(|cohort| {
+            cohort.try_into().map_err(|e: String| {
+                tracing::error!("Invalid cohort: {e}");
+                ServerFnError::<NoCustomError>::ServerError("Failed to load cohorts".to_string())
+            })
+        })
+        .collect()
+}
+
+#[data::cfg_server("crew/add_cohort")]
+pub async fn add_cohort(
+    crew_id: types::CrewId,
+    cohort: types::NewCohort,
+) -> Result<types::Cohort, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    cohort
+        .validate()
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    let mut conn = db::connect();
+
+    let id = diesel::insert_into(cohorts::table)
+        .values(&db::models::NewCohort::new(crew_id, &cohort))
+        .returning(cohorts::id)
+        .get_result::<types::CohortId>(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to add cohort to crew ({crew_id}): {e}");
+            ServerFnError::ServerError("Failed to add cohort".to_string())
+        })?;
+
+    load_cohort(&mut conn, id, &user.username)
+}
+
+/// Marks `levels` of harm on a cohort, spending its armor first.
+#[data::cfg_server("crew/damage_cohort")]
+pub async fn damage_cohort(
+    cohort_id: types::CohortId,
+    levels: u8,
+) -> Result<types::Cohort, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let mut cohort = load_cohort(&mut conn, cohort_id, &user.username)?;
+
+    cohort
+        .damage(levels)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    save_cohort(&mut conn, cohort)
+}
+
+#[data::cfg_server("crew/heal_cohort")]
+pub async fn heal_cohort(
+    cohort_id: types::CohortId,
+    levels: u8,
+) -> Result<types::Cohort, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let mut cohort = load_cohort(&mut conn, cohort_id, &user.username)?;
+
+    cohort
+        .heal(levels)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    save_cohort(&mut conn, cohort)
+}
+
+#[data::cfg_server("crew/remove_cohort")]
+pub async fn remove_cohort(cohort_id: types::CohortId) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    load_cohort::<NoCustomError>(&mut conn, cohort_id, &user.username)?;
+
+    diesel::delete(cohorts::table.find(cohort_id))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to remove cohort ({cohort_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to remove cohort".to_string())
+        })?;
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use db::schema::*;
+
+/// The crew's open end of session, if the GM has started one.
+#[cfg(feature = "server")]
+fn load_open(
+    conn: &mut diesel::SqliteConnection,
+    crew_id: types::CrewId,
+) -> Result<Option<types::EndOfSession>, ServerFnError> {
+    let end_of_session = end_of_sessions::table
+        .filter(
+            end_of_sessions::crew_id
+                .eq(crew_id)
+                .and(end_of_sessions::closed.eq(false)),
+        )
+        .select(db::models::EndOfSession::as_select())
+        .first(conn)
+        .optional()
+        .map_err(|e| {
+            tracing::error!("Failed to load end of session for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load end of session".to_string())
+        })?;
+
+    let Some(end_of_session) = end_of_session else {
+        return Ok(None);
+    };
+
+    let answered = end_of_session_answers::table
+        .filter(end_of_session_answers::end_of_session_id.eq(end_of_session.id))
+        .select(end_of_session_answers::character_id)
+        .load(conn)
+        .map_err(|e| {
+            tracing::error!(
+                "Failed to load end of session answers ({}): {e}",
+                end_of_session.id
+            );
+            ServerFnError::<NoCustomError>::ServerError("Failed to load end of session".to_string())
+        })?;
+
+    Ok(Some(end_of_session.into_end_of_session(answered)))
+}
+
+#[data::cfg_server("crew/get_end_of_session")]
+pub async fn get_end_of_session(
+    crew_id: types::CrewId,
+) -> Result<Option<types::EndOfSession>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    load_open(&mut conn, crew_id)
+}
+
+/// Opens the end of session questions for every player in the crew. Returns the already
+/// open one if there is one.
+#[data::cfg_server("crew/start_end_of_session")]
+pub async fn start_end_of_session(
+    crew_id: types::CrewId,
+) -> Result<types::EndOfSession, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_dm(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Only the GM can end the session".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    if let Some(end_of_session) = load_open(&mut conn, crew_id)? {
+        return Ok(end_of_session);
+    }
+
+    let end_of_session = diesel::insert_into(end_of_sessions::table)
+        .values(&db::models::NewEndOfSession { crew_id })
+        .returning(db::models::EndOfSession::as_returning())
+        .get_result(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to start end of session for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError(
+                "Failed to start end of session".to_string(),
+            )
+        })?;
+
+    Ok(end_of_session.into_end_of_session(vec![]))
+}
+
+#[data::cfg_server("crew/close_end_of_session")]
+pub async fn close_end_of_session(crew_id: types::CrewId) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_dm(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Only the GM can end the session".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    diesel::update(end_of_sessions::table)
+        .filter(
+            end_of_sessions::crew_id
+                .eq(crew_id)
+                .and(end_of_sessions::closed.eq(false)),
+        )
+        .set(end_of_sessions::closed.eq(true))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to close end of session for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError(
+                "Failed to close end of session".to_string(),
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Answers the end of session questions for a character, marking the earned XP on the
+/// chosen track. Each character answers once per session.
+#[data::cfg_server("crew/answer_end_of_session")]
+pub async fn answer_end_of_session(
+    character_id: types::CharacterId,
+    answers: types::XpAnswers,
+) -> Result<types::XP, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let character = crate::character::load_character(&mut conn, character_id)
+        .map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    if character.user_id != user.username {
+        return Err(ServerFnError::Request("Character not found".to_string()));
+    }
+
+    let end_of_session = load_open(&mut conn, character.crew_id)
+        .map_err(|e| ServerFnError::ServerError(e.to_string()))?
+        .ok_or_else(|| {
+            ServerFnError::WrappedServerError("The session has not been ended".to_string())
+        })?;
+
+    if end_of_session.answered.contains(&character_id) {
+        return Err(ServerFnError::WrappedServerError(format!(
+            "{} has already answered this session",
+            character.name
+        )));
+    }
+
+    let mut xp = character.xp.clone();
+    xp.mark(answers.track, answers.marks());
+
+    character
+        .validate_change(&types::CharacterChange::Xp(xp.clone()))
+        .map_err(|errors| ServerFnError::WrappedServerError(errors.to_string()))?;
+
+    // Recording the answers and marking the XP go together, so a failed write can be retried.
+    let xp = conn
+        .transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::insert_into(end_of_session_answers::table)
+                .values(&db::models::NewEndOfSessionAnswer::new(
+                    end_of_session.id,
+                    character_id,
+                    &answers,
+                ))
+                .execute(conn)?;
+
+            crate::character::write_xp(conn, character_id, &xp)
+        })
+        .map_err(|e| {
+            tracing::error!(
+                "Failed to record end of session answers for character ({character_id}): {e}"
+            );
+            ServerFnError::ServerError("Failed to record answers".to_string())
+        })?;
+
+    Ok(xp)
+}
@@ -0,0 +1,149 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+/// The crew's status with every faction it isn't neutral with.
+#[data::cfg_server("crew/get_faction_statuses")]
+pub async fn get_faction_statuses(
+    crew_id: types::CrewId,
+) -> Result<Vec<types::FactionStatus>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let statuses: Vec<db::models::CrewFaction> = crew_factions::table
+        .filter(crew_factions::crew_id.eq(crew_id))
+        .select(db::models::CrewFaction::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load faction statuses for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load factions".to_string())
+        })?;
+
+    let mut statuses: Vec<types::FactionStatus> = statuses
+        .iter()
+        .filter_map(db::models::CrewFaction::status)
+        .collect();
+    statuses.sort_by_key(|s| s.faction);
+
+    Ok(statuses)
+}
+
+/// Sets the crew's status with a faction. Dropping to -3 puts the faction at war with the
+/// crew, which the returned status reports.
+#[data::cfg_server("crew/set_faction_status")]
+pub async fn set_faction_status(
+    crew_id: types::CrewId,
+    faction: types::FactionId,
+    status: i8,
+) -> Result<types::FactionStatus, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_dm(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Only the GM can change faction status".to_string(),
+        ));
+    }
+
+    let Some(found) = types::factions::Faction::find(faction) else {
+        return Err(ServerFnError::WrappedServerError(format!(
+            "Unknown faction: {faction}"
+        )));
+    };
+
+    if !(types::FactionStatus::MIN..=types::FactionStatus::MAX).contains(&status) {
+        return Err(ServerFnError::WrappedServerError(format!(
+            "Status with {} must be between {} and {}",
+            found.name,
+            types::FactionStatus::MIN,
+            types::FactionStatus::MAX
+        )));
+    }
+
+    let mut conn = db::connect();
+
+    let res = if status == 0 {
+        diesel::delete(crew_factions::table.find((crew_id, found.name))).execute(&mut conn)
+    } else {
+        diesel::insert_into(crew_factions::table)
+            .values(&db::models::CrewFaction {
+                crew_id,
+                faction: found.name.to_string(),
+                status: status as i32,
+            })
+            .on_conflict((crew_factions::crew_id, crew_factions::faction))
+            .do_update()
+            .set(crew_factions::status.eq(status as i32))
+            .execute(&mut conn)
+    };
+
+    res.map_err(|e| {
+        tracing::error!(
+            "Failed to set status with {} for crew ({crew_id}): {e}",
+            found.name
+        );
+        ServerFnError::ServerError("Failed to update faction".to_string())
+    })?;
+
+    Ok(types::FactionStatus { faction, status })
+}
+
+/// Ticks every unfinished faction clock in the crew's faction game once, as the GM does
+/// between sessions. Returns the crew's faction clocks after the advance.
+#[data::cfg_server("crew/advance_faction_clocks")]
+pub async fn advance_faction_clocks(
+    crew_id: types::CrewId,
+) -> Result<Vec<types::Clock>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_dm(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Only the GM can advance faction clocks".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let clocks: Vec<db::models::Clock> = clocks::table
+        .filter(clocks::crew_id.eq(crew_id))
+        .filter(clocks::faction.is_not_null())
+        .order(clocks::id)
+        .select(db::models::Clock::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load faction clocks for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load clocks".to_string())
+        })?;
+
+    let mut clocks = clocks
+        .into_iter()
+        .map(crate::clock::into_clock)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        for clock in clocks.iter_mut().filter(|c| !c.is_full()) {
+            clock.tick(1);
+            diesel::update(clocks::table.find(clock.id))
+                .set(clocks::filled.eq(clock.filled as i32))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to advance faction clocks for crew ({crew_id}): {e}");
+        ServerFnError::<NoCustomError>::ServerError("Failed to advance clocks".to_string())
+    })?;
+
+    Ok(clocks)
+}
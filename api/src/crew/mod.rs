@@ -1,3 +1,32 @@
+mod updates;
+pub use updates::*;
+
+mod roll_log;
+pub use roll_log::*;
+
+mod end_of_session;
+pub use end_of_session::*;
+
+mod abilities;
+pub use abilities::*;
+
+mod cohorts;
+pub use cohorts::*;
+
+mod claims;
+pub use claims::*;
+
+mod advancement;
+pub use advancement::*;
+
+mod factions;
+pub use factions::*;
+
+mod npcs;
+#[cfg(feature = "server")]
+pub(crate) use npcs::find_or_create_npc;
+pub use npcs::*;
+
 #[cfg(feature = "server")]
 use diesel::prelude::*;
 
@@ -22,6 +51,17 @@ pub(crate) fn is_in_crew(crew_id: types::CrewId, user_id: &str) -> bool {
         .is_ok()
 }
 
+#[cfg(feature = "server")]
+pub(crate) fn is_dm(crew_id: types::CrewId, user_id: &str) -> bool {
+    let mut conn = db::connect();
+
+    crews::table
+        .filter(crews::id.eq(crew_id).and(crews::dm_id.eq(user_id)))
+        .select(crews::id)
+        .first::<types::CrewId>(&mut conn)
+        .is_ok()
+}
+
 #[data::cfg_server("crew/get_characters")]
 pub async fn get_crew_characters(
     crew_id: types::CrewId,
@@ -90,12 +130,7 @@ pub async fn get_crew(id: types::CrewId) -> Result<types::Crew, ServerFnError> {
             ServerFnError::<NoCustomError>::Request("Crew not found".to_string())
         })?;
 
-    Ok(types::Crew {
-        id: crew.id,
-        name: crew.name,
-        specialty: crew.specialty,
-        dm_id: crew.dm_id,
-    })
+    Ok(crew.into())
 }
 
 #[data::cfg_server("crew/my_crews")]
@@ -168,33 +203,45 @@ pub async fn create_crew(
 
     let mut conn = db::connect();
 
-    let crew = diesel::insert_into(crews)
-        .values(&crew)
-        .returning(db::models::Crew::as_returning())
-        .get_result(&mut conn)
-        .map_err(|e| {
-            tracing::error!("Failed to insert new crew: {e}");
-            ServerFnError::<NoCustomError>::ServerError("Failed to create crew".to_string())
-        })?;
-
-    diesel::insert_into(crew_members::table)
-        .values(&db::models::CrewMember {
-            crew_id: crew.id,
-            user_id: crew.dm_id.clone(),
-            display_name: dm_name,
+    let crew = conn
+        .transaction::<_, diesel::result::Error, _>(|conn| {
+            let crew = diesel::insert_into(crews)
+                .values(&crew)
+                .returning(db::models::Crew::as_returning())
+                .get_result(conn)?;
+
+            diesel::insert_into(crew_members::table)
+                .values(&db::models::CrewMember {
+                    crew_id: crew.id,
+                    user_id: crew.dm_id.clone(),
+                    display_name: dm_name,
+                })
+                .execute(conn)?;
+
+            let starting_upgrades = crew
+                .specialty
+                .starting_upgrades()
+                .iter()
+                .filter_map(|upgrade_name| crew.specialty.find_upgrade(upgrade_name))
+                .map(|upgrade| db::models::CrewUpgrade {
+                    crew_id: crew.id,
+                    name: upgrade.name.to_string(),
+                    boxes: upgrade.boxes as i32,
+                })
+                .collect::<Vec<_>>();
+
+            diesel::insert_into(crew_upgrades::table)
+                .values(&starting_upgrades)
+                .execute(conn)?;
+
+            Ok(crew)
         })
-        .execute(&mut conn)
         .map_err(|e| {
-            tracing::error!("Failed to insert new crew member: {e}");
+            tracing::error!("Failed to insert new crew: {e}");
             ServerFnError::<NoCustomError>::ServerError("Failed to create crew".to_string())
         })?;
 
-    Ok(types::Crew {
-        id: crew.id,
-        name: crew.name,
-        specialty: crew.specialty,
-        dm_id: crew.dm_id,
-    })
+    Ok(crew.into())
 }
 
 #[data::cfg_server("crew/get_player_display_name")]
@@ -282,9 +329,7 @@ pub async fn create_invite(
 
 #[data::cfg_server("crew/join")]
 pub async fn join(code: String, name: String) -> Result<types::Crew, ServerFnError<String>> {
-    let user: crate::User = extract()
-        .await
-        .map_err(|e: ServerFnError| ServerFnError::Request(e.to_string()))?;
+    let user = crate::User::extract_as().await?;
 
     let mut conn = db::connect();
 
@@ -356,12 +401,7 @@ pub async fn join(code: String, name: String) -> Result<types::Crew, ServerFnErr
             ServerFnError::<String>::ServerError("Corrupt invite data".to_string())
         })?;
 
-    Ok(types::Crew {
-        id: crew.id,
-        name: crew.name,
-        specialty: crew.specialty,
-        dm_id: crew.dm_id,
-    })
+    Ok(crew.into())
 }
 
 #[data::cfg_server("crew/delete_invite")]
@@ -0,0 +1,191 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+/// The crew's registry entry with this name, added blank if there isn't one yet.
+#[cfg(feature = "server")]
+pub(crate) fn find_or_create_npc(
+    conn: &mut diesel::SqliteConnection,
+    crew_id: types::CrewId,
+    name: &str,
+) -> QueryResult<types::NpcId> {
+    let name = name.trim();
+
+    let existing = npcs::table
+        .filter(npcs::crew_id.eq(crew_id))
+        .filter(npcs::name.eq(name))
+        .select(npcs::id)
+        .first(conn)
+        .optional()?;
+
+    if let Some(id) = existing {
+        return Ok(id);
+    }
+
+    let details = types::NpcDetails {
+        name: name.to_string(),
+        ..Default::default()
+    };
+
+    diesel::insert_into(npcs::table)
+        .values(&db::models::NewNpc::new(crew_id, &details))
+        .returning(npcs::id)
+        .get_result(conn)
+}
+
+#[cfg(feature = "server")]
+fn find_npc(
+    conn: &mut diesel::SqliteConnection,
+    id: types::NpcId,
+    username: &str,
+) -> Result<types::Npc, ServerFnError<String>> {
+    let npc: types::Npc = npcs::table
+        .find(id)
+        .select(db::models::Npc::as_select())
+        .first(conn)
+        .map(Into::into)
+        .map_err(|e| {
+            tracing::info!("Failed to find NPC ({id}): {e}");
+            ServerFnError::Request("NPC not found".to_string())
+        })?;
+
+    if !super::is_in_crew(npc.crew_id, username) {
+        return Err(ServerFnError::Request("NPC not found".to_string()));
+    }
+
+    Ok(npc)
+}
+
+/// Everyone in the crew's registry, by name.
+#[data::cfg_server("crew/get_npcs")]
+pub async fn get_npcs(crew_id: types::CrewId) -> Result<Vec<types::Npc>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    let npcs: Vec<db::models::Npc> = npcs::table
+        .filter(npcs::crew_id.eq(crew_id))
+        .order(npcs::name.asc())
+        .select(db::models::Npc::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load NPCs for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load NPCs".to_string())
+        })?;
+
+    Ok(npcs.into_iter().map(Into::into).collect())
+}
+
+#[data::cfg_server("crew/add_npc")]
+pub async fn add_npc(
+    crew_id: types::CrewId,
+    npc: types::NpcDetails,
+) -> Result<types::Npc, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    npc.validate().map_err(ServerFnError::WrappedServerError)?;
+
+    let mut conn = db::connect();
+
+    let npc = diesel::insert_into(npcs::table)
+        .values(&db::models::NewNpc::new(crew_id, &npc))
+        .returning(db::models::Npc::as_returning())
+        .get_result(&mut conn)
+        .map_err(|e| match e {
+            diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            ) => ServerFnError::WrappedServerError(format!(
+                "{} is already in the registry",
+                npc.name.trim()
+            )),
+            e => {
+                tracing::error!("Failed to add NPC to crew ({crew_id}): {e}");
+                ServerFnError::ServerError("Failed to add NPC".to_string())
+            }
+        })?;
+
+    Ok(npc.into())
+}
+
+/// Changes a registry entry. Any player in the crew can keep the registry up to date.
+#[data::cfg_server("crew/update_npc")]
+pub async fn update_npc(
+    id: types::NpcId,
+    npc: types::NpcDetails,
+) -> Result<types::Npc, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    npc.validate().map_err(ServerFnError::WrappedServerError)?;
+
+    let mut conn = db::connect();
+
+    let current = find_npc(&mut conn, id, &user.username)?;
+
+    let npc = diesel::update(npcs::table.find(id))
+        .set(&db::models::NewNpc::new(current.crew_id, &npc))
+        .returning(db::models::Npc::as_returning())
+        .get_result(&mut conn)
+        .map_err(|e| match e {
+            diesel::result::Error::DatabaseError(
+                diesel::result::DatabaseErrorKind::UniqueViolation,
+                _,
+            ) => ServerFnError::WrappedServerError(format!(
+                "{} is already in the registry",
+                npc.name.trim()
+            )),
+            e => {
+                tracing::error!("Failed to update NPC ({id}): {e}");
+                ServerFnError::ServerError("Failed to update NPC".to_string())
+            }
+        })?;
+
+    Ok(npc.into())
+}
+
+/// Removes an entry from the registry, along with any character contacts pointing at it.
+#[data::cfg_server("crew/remove_npc")]
+pub async fn remove_npc(id: types::NpcId) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    let npc = find_npc(&mut conn, id, &user.username)
+        .map_err(|e| ServerFnError::<NoCustomError>::Request(e.to_string()))?;
+
+    if !super::is_dm(npc.crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Only the GM can remove NPCs".to_string(),
+        ));
+    }
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::delete(character_contacts::table.filter(character_contacts::npc_id.eq(id)))
+            .execute(conn)?;
+        diesel::delete(npcs::table.find(id)).execute(conn)?;
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to remove NPC ({id}): {e}");
+        ServerFnError::<NoCustomError>::ServerError("Failed to remove NPC".to_string())
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use db::schema::*;
+
+/// Largest page `get_roll_log` will return.
+pub const ROLL_LOG_PAGE: u8 = 50;
+
+/// How long `wait_for_rolls` holds a request open before returning empty.
+#[cfg(feature = "server")]
+const WAIT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(25);
+#[cfg(feature = "server")]
+const WAIT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+#[cfg(feature = "server")]
+fn load_entries(
+    conn: &mut diesel::SqliteConnection,
+    crew_id: types::CrewId,
+    before: Option<types::roll::RollId>,
+    after: Option<types::roll::RollId>,
+    limit: u8,
+) -> Result<Vec<types::roll::RollLogEntry>, ServerFnError> {
+    let mut query = rolls::table
+        .left_join(characters::table)
+        .filter(rolls::crew_id.eq(crew_id))
+        .select((db::models::Roll::as_select(), characters::name.nullable()))
+        .order(rolls::id.desc())
+        .limit(limit.min(ROLL_LOG_PAGE) as i64)
+        .into_boxed();
+
+    if let Some(before) = before {
+        query = query.filter(rolls::id.lt(before));
+    }
+
+    if let Some(after) = after {
+        query = query.filter(rolls::id.gt(after));
+    }
+
+    let rolls: Vec<(db::models::Roll, Option<String>)> = query.load(conn).map_err(|e| {
+        tracing::error!("Failed to load roll log for crew ({crew_id}): {e}");
+        ServerFnError::<NoCustomError>::ServerError("Failed to load roll log".to_string())
+    })?;
+
+    rolls
+        .into_iter()
+        .map(|(roll, name)| {
+            roll.into_entry(name).map_err(|e| {
+                tracing::error!("{e}");
+                ServerFnError::<NoCustomError>::ServerError("Corrupt roll data".to_string())
+            })
+        })
+        .collect()
+}
+
+/// Newest first, optionally starting before a given roll.
+#[data::cfg_server("crew/get_roll_log")]
+pub async fn get_roll_log(
+    crew_id: types::CrewId,
+    before: Option<types::roll::RollId>,
+    limit: u8,
+) -> Result<Vec<types::roll::RollLogEntry>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    load_entries(&mut conn, crew_id, before, None, limit)
+}
+
+/// Waits until rolls newer than `after` are made, returning them newest first. Returns an empty
+/// list if nothing is rolled before the timeout, so the caller can simply call again.
+#[data::cfg_server("crew/wait_for_rolls")]
+pub async fn wait_for_rolls(
+    crew_id: types::CrewId,
+    after: types::roll::RollId,
+) -> Result<Vec<types::roll::RollLogEntry>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let deadline = tokio::time::Instant::now() + WAIT_TIMEOUT;
+
+    loop {
+        let entries = {
+            let mut conn = db::connect();
+            load_entries(&mut conn, crew_id, None, Some(after), ROLL_LOG_PAGE)?
+        };
+
+        if !entries.is_empty() || tokio::time::Instant::now() >= deadline {
+            return Ok(entries);
+        }
+
+        tokio::time::sleep(WAIT_INTERVAL).await;
+    }
+}
@@ -0,0 +1,144 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+
+#[data::cfg_server("crew/set_reputation")]
+pub async fn set_reputation(crew_id: types::CrewId, reputation: u8) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    if reputation > types::Crew::MAX_REPUTATION {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Reputation out of range".to_string(),
+        ));
+    }
+
+    use db::schema::crews::dsl;
+
+    let mut conn = db::connect();
+
+    diesel::update(dsl::crews.find(crew_id))
+        .set(dsl::reputation.eq(reputation as i32))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to update crew reputation: {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to update crew".to_string())
+        })?;
+
+    Ok(())
+}
+
+#[data::cfg_server("crew/set_heat_wanted")]
+pub async fn set_heat_wanted(
+    crew_id: types::CrewId,
+    heat: u8,
+    wanted_level: u8,
+) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    if heat > types::Crew::MAX_HEAT || wanted_level > types::Crew::MAX_WANTED_LEVEL {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Heat or wanted level out of range".to_string(),
+        ));
+    }
+
+    use db::schema::crews::dsl;
+
+    let mut conn = db::connect();
+
+    diesel::update(dsl::crews.find(crew_id))
+        .set((
+            dsl::heat.eq(heat as i32),
+            dsl::wanted_level.eq(wanted_level as i32),
+        ))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to update crew heat/wanted level: {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to update crew".to_string())
+        })?;
+
+    Ok(())
+}
+
+#[data::cfg_server("crew/set_tier_hold")]
+pub async fn set_tier_hold(
+    crew_id: types::CrewId,
+    tier: u8,
+    hold: types::Hold,
+) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    if tier > types::Crew::MAX_TIER {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Tier out of range".to_string(),
+        ));
+    }
+
+    use db::schema::crews::dsl;
+
+    let mut conn = db::connect();
+
+    diesel::update(dsl::crews.find(crew_id))
+        .set((dsl::tier.eq(tier as i32), dsl::hold.eq(hold)))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to update crew tier/hold: {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to update crew".to_string())
+        })?;
+
+    Ok(())
+}
+
+#[data::cfg_server("crew/set_coin_vaults")]
+pub async fn set_coin_vaults(
+    crew_id: types::CrewId,
+    coin: u8,
+    vaults: u8,
+) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !super::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    if vaults > types::Crew::MAX_VAULTS || coin > types::Crew::coin_capacity_with(vaults) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not enough room in the vaults".to_string(),
+        ));
+    }
+
+    use db::schema::crews::dsl;
+
+    let mut conn = db::connect();
+
+    diesel::update(dsl::crews.find(crew_id))
+        .set((dsl::coin.eq(coin as i32), dsl::vaults.eq(vaults as i32)))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to update crew coin/vaults: {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to update crew".to_string())
+        })?;
+
+    Ok(())
+}
@@ -31,11 +31,33 @@ pub struct Character {
     pub coin: i32,
     pub load: Option<i32>,
     pub items: i32,
+    pub retired: bool,
+    pub downtime_activities: i32,
+    pub pending_overindulgence: bool,
+    pub downtime_trained: i32,
+}
+
+/// Packs trained XP tracks into bits, in the order of `XpTrack::ALL`.
+pub fn trained_bits(trained: &[types::XpTrack]) -> i32 {
+    types::XpTrack::ALL
+        .iter()
+        .enumerate()
+        .filter(|(_, track)| trained.contains(track))
+        .fold(0, |bits, (i, _)| bits | (1 << i))
+}
+
+fn trained_from_bits(bits: i32) -> Vec<types::XpTrack> {
+    types::XpTrack::ALL
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| bits & (1 << i) != 0)
+        .map(|(_, track)| *track)
+        .collect()
 }
 
 pub(crate) struct IntoCharacter<
     A: Iterator<Item = CharacterAbility>,
-    C: Iterator<Item = CharacterContact>,
+    C: Iterator<Item = ContactName>,
     CI: Iterator<Item = CharacterClassItem>,
 > {
     pub(crate) character: Character,
@@ -49,7 +71,7 @@ pub(crate) struct IntoCharacter<
 
 impl<
         A: Iterator<Item = CharacterAbility>,
-        C: Iterator<Item = CharacterContact>,
+        C: Iterator<Item = ContactName>,
         CI: Iterator<Item = CharacterClassItem>,
     > From<IntoCharacter<A, C, CI>> for types::Character
 {
@@ -67,8 +89,7 @@ impl<
         let abilities = abilities.map(|a| a.name).collect();
         let class_items = class_items.map(|ci| ci.name).collect();
 
-        let contacts: (Vec<CharacterContact>, Vec<CharacterContact>) =
-            contacts.partition(|c| c.friend);
+        let contacts: (Vec<ContactName>, Vec<ContactName>) = contacts.partition(|c| c.friend);
 
         let contacts = types::Contacts {
             friends: contacts.0.into_iter().map(|c| c.name).collect(),
@@ -118,6 +139,10 @@ impl<
             dots: dots.into(),
             load,
             items: types::Items::from_bits_truncate(character.items as u16),
+            retired: character.retired,
+            downtime_activities: character.downtime_activities as u8,
+            pending_overindulgence: character.pending_overindulgence,
+            trained: trained_from_bits(character.downtime_trained),
         }
     }
 }
@@ -130,6 +155,25 @@ pub struct NewCharacter {
     pub crew_id: types::CrewId,
     pub user_id: types::UserId,
     pub class: types::Class,
+    pub look: String,
+    pub heritage: types::Heritage,
+    pub background: types::Background,
+    pub vice: types::Vice,
+}
+
+impl NewCharacter {
+    pub fn new(user_id: types::UserId, character: &types::NewCharacter) -> Self {
+        Self {
+            name: character.name.trim().to_string(),
+            crew_id: character.crew_id,
+            user_id,
+            class: character.class,
+            look: character.look.clone(),
+            heritage: character.heritage,
+            background: character.background,
+            vice: character.vice,
+        }
+    }
 }
 
 #[cfg_attr(
@@ -189,6 +233,13 @@ pub struct NewCharacterAbility {
 pub struct CharacterContact {
     pub id: i32,
     pub character_id: types::CharacterId,
+    pub npc_id: types::NpcId,
+    pub friend: bool,
+}
+
+/// A contact with the name of the NPC it points at.
+#[cfg_attr(feature = "server", derive(Queryable))]
+pub struct ContactName {
     pub name: String,
     pub friend: bool,
 }
@@ -197,7 +248,7 @@ pub struct CharacterContact {
 #[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::character_contacts))]
 pub struct NewCharacterContact {
     pub character_id: types::CharacterId,
-    pub name: String,
+    pub npc_id: types::NpcId,
     pub friend: bool,
 }
 
@@ -222,7 +273,14 @@ pub struct NewCharacterClassItem {
 
 #[cfg_attr(
     feature = "server",
-    derive(Queryable, Selectable, Associations, Identifiable, Insertable)
+    derive(
+        Queryable,
+        Selectable,
+        Associations,
+        Identifiable,
+        Insertable,
+        AsChangeset
+    )
 )]
 #[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::character_xp))]
 #[cfg_attr(feature = "server", diesel(belongs_to(Character)))]
@@ -245,6 +303,16 @@ impl CharacterXp {
             resolve: 0,
         }
     }
+
+    pub fn with_xp(character_id: types::CharacterId, xp: &types::XP) -> Self {
+        Self {
+            character_id,
+            playbook: xp.playbook as i32,
+            insight: xp.insight as i32,
+            prowess: xp.prowess as i32,
+            resolve: xp.resolve as i32,
+        }
+    }
 }
 
 impl From<CharacterXp> for types::XP {
@@ -260,7 +328,14 @@ impl From<CharacterXp> for types::XP {
 
 #[cfg_attr(
     feature = "server",
-    derive(Queryable, Selectable, Associations, Identifiable, Insertable)
+    derive(
+        Queryable,
+        Selectable,
+        Associations,
+        Identifiable,
+        Insertable,
+        AsChangeset
+    )
 )]
 #[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::character_dots))]
 #[cfg_attr(feature = "server", diesel(belongs_to(Character)))]
@@ -282,21 +357,21 @@ pub struct CharacterDots {
 }
 
 impl CharacterDots {
-    pub fn new(character_id: types::CharacterId) -> Self {
+    pub fn new(character_id: types::CharacterId, dots: &types::Dots) -> Self {
         Self {
             character_id,
-            hunt: 0,
-            study: 0,
-            survey: 0,
-            tinker: 0,
-            finesse: 0,
-            prowl: 0,
-            skirmish: 0,
-            wreck: 0,
-            attune: 0,
-            command: 0,
-            consort: 0,
-            sway: 0,
+            hunt: dots.hunt as i32,
+            study: dots.study as i32,
+            survey: dots.survey as i32,
+            tinker: dots.tinker as i32,
+            finesse: dots.finesse as i32,
+            prowl: dots.prowl as i32,
+            skirmish: dots.skirmish as i32,
+            wreck: dots.wreck as i32,
+            attune: dots.attune as i32,
+            command: dots.command as i32,
+            consort: dots.consort as i32,
+            sway: dots.sway as i32,
         }
     }
 }
@@ -319,3 +394,80 @@ impl From<CharacterDots> for types::Dots {
         }
     }
 }
+
+#[cfg_attr(
+    feature = "server",
+    derive(Queryable, Selectable, Associations, Identifiable)
+)]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::character_advancements))]
+#[cfg_attr(feature = "server", diesel(belongs_to(Character)))]
+pub struct CharacterAdvancement {
+    pub id: i32,
+    pub character_id: types::CharacterId,
+    pub kind: String,
+    pub choice: String,
+}
+
+impl TryFrom<CharacterAdvancement> for types::Advance {
+    type Error = String;
+
+    fn try_from(advancement: CharacterAdvancement) -> Result<Self, Self::Error> {
+        match advancement.kind.as_str() {
+            "Ability" => Ok(types::Advance::Ability(advancement.choice)),
+            "Action" => Ok(types::Advance::Action(advancement.choice.parse()?)),
+            kind => Err(format!("Invalid advancement kind: {kind}")),
+        }
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::character_advancements))]
+pub struct NewCharacterAdvancement {
+    pub character_id: types::CharacterId,
+    pub kind: String,
+    pub choice: String,
+}
+
+impl NewCharacterAdvancement {
+    pub fn new(character_id: types::CharacterId, advance: &types::Advance) -> Self {
+        let (kind, choice) = match advance {
+            types::Advance::Ability(name) => ("Ability", name.clone()),
+            types::Advance::Action(action) => ("Action", action.to_string()),
+        };
+
+        Self {
+            character_id,
+            kind: kind.to_string(),
+            choice,
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "server",
+    derive(Queryable, Selectable, Associations, Identifiable)
+)]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::character_log))]
+#[cfg_attr(feature = "server", diesel(belongs_to(Character)))]
+pub struct CharacterLogEntry {
+    pub id: types::CharacterLogId,
+    pub character_id: types::CharacterId,
+    pub entry: String,
+}
+
+impl From<CharacterLogEntry> for types::CharacterLogEntry {
+    fn from(entry: CharacterLogEntry) -> Self {
+        types::CharacterLogEntry {
+            id: entry.id,
+            character_id: entry.character_id,
+            entry: entry.entry,
+        }
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::character_log))]
+pub struct NewCharacterLogEntry {
+    pub character_id: types::CharacterId,
+    pub entry: String,
+}
@@ -0,0 +1,77 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+
+#[cfg_attr(feature = "server", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::clocks))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct Clock {
+    pub id: types::ClockId,
+    pub crew_id: Option<types::CrewId>,
+    pub character_id: Option<types::CharacterId>,
+    pub name: String,
+    pub segments: i32,
+    pub filled: i32,
+    pub faction: Option<String>,
+}
+
+impl Clock {
+    pub fn owner(&self) -> Option<types::ClockOwner> {
+        match (self.crew_id, self.character_id, self.faction.as_deref()) {
+            (Some(crew_id), None, None) => Some(types::ClockOwner::Crew(crew_id)),
+            (Some(crew_id), None, Some(faction)) => types::factions::Faction::id_of(faction)
+                .map(|faction| types::ClockOwner::Faction(crew_id, faction)),
+            (None, Some(character_id), None) => Some(types::ClockOwner::Character(character_id)),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<Clock> for types::Clock {
+    type Error = String;
+
+    fn try_from(clock: Clock) -> Result<Self, Self::Error> {
+        let owner = clock
+            .owner()
+            .ok_or_else(|| format!("Clock ({}) has no single owner", clock.id))?;
+
+        Ok(types::Clock {
+            id: clock.id,
+            owner,
+            name: clock.name,
+            size: types::ClockSize::try_from(clock.segments as u8)?,
+            filled: clock.filled as u8,
+        })
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::clocks))]
+pub struct NewClock {
+    pub crew_id: Option<types::CrewId>,
+    pub character_id: Option<types::CharacterId>,
+    pub name: String,
+    pub segments: i32,
+    pub faction: Option<String>,
+}
+
+impl NewClock {
+    pub fn new(owner: types::ClockOwner, name: String, size: types::ClockSize) -> Self {
+        let (crew_id, character_id, faction) = match owner {
+            types::ClockOwner::Crew(id) => (Some(id), None, None),
+            types::ClockOwner::Character(id) => (None, Some(id), None),
+            types::ClockOwner::Faction(id, faction) => (
+                Some(id),
+                None,
+                types::factions::Faction::find(faction).map(|f| f.name.to_string()),
+            ),
+        };
+
+        Self {
+            crew_id,
+            character_id,
+            name,
+            segments: size.segments() as i32,
+            faction,
+        }
+    }
+}
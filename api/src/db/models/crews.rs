@@ -15,6 +15,33 @@ pub struct Crew {
     pub name: String,
     pub specialty: types::CrewSpecialty,
     pub dm_id: types::UserId,
+    pub reputation: i32,
+    pub heat: i32,
+    pub wanted_level: i32,
+    pub tier: i32,
+    pub hold: types::Hold,
+    pub coin: i32,
+    pub vaults: i32,
+    pub xp: i32,
+}
+
+impl From<Crew> for types::Crew {
+    fn from(crew: Crew) -> Self {
+        types::Crew {
+            id: crew.id,
+            name: crew.name,
+            specialty: crew.specialty,
+            dm_id: crew.dm_id,
+            reputation: crew.reputation as u8,
+            heat: crew.heat as u8,
+            wanted_level: crew.wanted_level as u8,
+            tier: crew.tier as u8,
+            hold: crew.hold,
+            coin: crew.coin as u8,
+            vaults: crew.vaults as u8,
+            xp: crew.xp as u8,
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -65,3 +92,267 @@ pub struct NewCrewInvite {
     pub crew_id: types::CrewId,
     pub max_uses: i32,
 }
+
+#[cfg_attr(
+    feature = "server",
+    derive(Queryable, Selectable, Associations, Identifiable)
+)]
+#[cfg_attr(feature = "server", diesel(belongs_to(Crew)))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::crew_abilities))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct CrewAbility {
+    pub id: i32,
+    pub crew_id: types::CrewId,
+    pub name: String,
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::crew_abilities))]
+pub struct NewCrewAbility {
+    pub crew_id: types::CrewId,
+    pub name: String,
+}
+
+#[cfg_attr(
+    feature = "server",
+    derive(
+        Queryable,
+        Selectable,
+        Associations,
+        Identifiable,
+        Insertable,
+        AsChangeset
+    )
+)]
+#[cfg_attr(feature = "server", diesel(belongs_to(Crew)))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::crew_upgrades))]
+#[cfg_attr(feature = "server", diesel(primary_key(crew_id, name)))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct CrewUpgrade {
+    pub crew_id: types::CrewId,
+    pub name: String,
+    pub boxes: i32,
+}
+
+impl From<CrewUpgrade> for types::CrewUpgradeMark {
+    fn from(upgrade: CrewUpgrade) -> Self {
+        types::CrewUpgradeMark {
+            name: upgrade.name,
+            boxes: upgrade.boxes as u8,
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "server",
+    derive(Queryable, Selectable, Associations, Identifiable, Insertable)
+)]
+#[cfg_attr(feature = "server", diesel(belongs_to(Crew)))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::crew_claims))]
+#[cfg_attr(feature = "server", diesel(primary_key(crew_id, position)))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct CrewClaim {
+    pub crew_id: types::CrewId,
+    pub position: i32,
+    pub district: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "server",
+    derive(Queryable, Selectable, Associations, Identifiable, Insertable)
+)]
+#[cfg_attr(feature = "server", diesel(belongs_to(Crew)))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::crew_factions))]
+#[cfg_attr(feature = "server", diesel(primary_key(crew_id, faction)))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct CrewFaction {
+    pub crew_id: types::CrewId,
+    pub faction: String,
+    pub status: i32,
+}
+
+impl CrewFaction {
+    /// `None` when the faction has since left the catalogue.
+    pub fn status(&self) -> Option<types::FactionStatus> {
+        Some(types::FactionStatus {
+            faction: types::factions::Faction::id_of(&self.faction)?,
+            status: self.status as i8,
+        })
+    }
+}
+
+#[cfg_attr(
+    feature = "server",
+    derive(Queryable, Selectable, Associations, Identifiable, AsChangeset)
+)]
+#[cfg_attr(feature = "server", diesel(belongs_to(Crew)))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::cohorts))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct Cohort {
+    pub id: types::CohortId,
+    pub crew_id: types::CrewId,
+    pub name: String,
+    pub kind: String,
+    pub types: String,
+    pub edges: String,
+    pub flaws: String,
+    pub harm: i32,
+    pub armor: bool,
+}
+
+fn split<T: std::str::FromStr<Err = String>>(list: &str) -> Result<Vec<T>, String> {
+    list.split(',')
+        .filter(|s| !s.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+fn join<T: std::fmt::Display>(list: &[T]) -> String {
+    list.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl TryFrom<Cohort> for types::Cohort {
+    type Error = String;
+
+    fn try_from(cohort: Cohort) -> Result<Self, Self::Error> {
+        let kind = match cohort.kind.as_str() {
+            "Gang" => types::CohortKind::Gang(split(&cohort.types)?),
+            "Expert" => types::CohortKind::Expert(cohort.types),
+            kind => return Err(format!("Invalid cohort kind: {kind}")),
+        };
+
+        Ok(types::Cohort {
+            id: cohort.id,
+            crew_id: cohort.crew_id,
+            name: cohort.name,
+            kind,
+            edges: split(&cohort.edges)?,
+            flaws: split(&cohort.flaws)?,
+            harm: types::CohortHarm::from_level(cohort.harm as u8),
+            armor: cohort.armor,
+        })
+    }
+}
+
+impl From<types::Cohort> for Cohort {
+    fn from(cohort: types::Cohort) -> Self {
+        let (kind, cohort_types) = match cohort.kind {
+            types::CohortKind::Gang(gang_types) => ("Gang", join(&gang_types)),
+            types::CohortKind::Expert(kind) => ("Expert", kind),
+        };
+
+        Cohort {
+            id: cohort.id,
+            crew_id: cohort.crew_id,
+            name: cohort.name,
+            kind: kind.to_string(),
+            types: cohort_types,
+            edges: join(&cohort.edges),
+            flaws: join(&cohort.flaws),
+            harm: cohort.harm.level() as i32,
+            armor: cohort.armor,
+        }
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::cohorts))]
+pub struct NewCohort {
+    pub crew_id: types::CrewId,
+    pub name: String,
+    pub kind: String,
+    pub types: String,
+    pub edges: String,
+    pub flaws: String,
+    pub armor: bool,
+}
+
+impl NewCohort {
+    pub fn new(crew_id: types::CrewId, cohort: &types::NewCohort) -> Self {
+        let (kind, cohort_types) = match &cohort.kind {
+            types::CohortKind::Gang(gang_types) => ("Gang", join(gang_types)),
+            types::CohortKind::Expert(kind) => ("Expert", kind.trim().to_string()),
+        };
+
+        Self {
+            crew_id,
+            name: cohort.name.trim().to_string(),
+            kind: kind.to_string(),
+            types: cohort_types,
+            edges: join(&cohort.edges),
+            flaws: join(&cohort.flaws),
+            armor: cohort.armor,
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "server",
+    derive(Queryable, Selectable, Associations, Identifiable)
+)]
+#[cfg_attr(feature = "server", diesel(belongs_to(Crew)))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::npcs))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct Npc {
+    pub id: types::NpcId,
+    pub crew_id: types::CrewId,
+    pub name: String,
+    pub description: String,
+    pub faction: Option<String>,
+    pub district: Option<String>,
+    pub status: i32,
+}
+
+impl From<Npc> for types::Npc {
+    fn from(npc: Npc) -> Self {
+        types::Npc {
+            id: npc.id,
+            crew_id: npc.crew_id,
+            name: npc.name,
+            description: npc.description,
+            faction: npc
+                .faction
+                .as_deref()
+                .and_then(types::factions::Faction::id_of),
+            district: npc
+                .district
+                .as_deref()
+                .and_then(types::districts::District::id_of),
+            status: npc.status as i8,
+        }
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::npcs))]
+#[cfg_attr(feature = "server", diesel(treat_none_as_null = true))]
+pub struct NewNpc {
+    pub crew_id: types::CrewId,
+    pub name: String,
+    pub description: String,
+    pub faction: Option<String>,
+    pub district: Option<String>,
+    pub status: i32,
+}
+
+impl NewNpc {
+    pub fn new(crew_id: types::CrewId, npc: &types::NpcDetails) -> Self {
+        Self {
+            crew_id,
+            name: npc.name.trim().to_string(),
+            description: npc.description.trim().to_string(),
+            faction: npc
+                .faction
+                .and_then(types::factions::Faction::find)
+                .map(|f| f.name.to_string()),
+            district: npc
+                .district
+                .and_then(types::districts::District::find)
+                .map(|d| d.name.to_string()),
+            status: npc.status as i32,
+        }
+    }
+}
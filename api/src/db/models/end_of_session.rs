@@ -0,0 +1,55 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+
+#[cfg_attr(feature = "server", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::end_of_sessions))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct EndOfSession {
+    pub id: types::EndOfSessionId,
+    pub crew_id: types::CrewId,
+    pub closed: bool,
+}
+
+impl EndOfSession {
+    pub fn into_end_of_session(self, answered: Vec<types::CharacterId>) -> types::EndOfSession {
+        types::EndOfSession {
+            id: self.id,
+            crew_id: self.crew_id,
+            answered,
+        }
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::end_of_sessions))]
+pub struct NewEndOfSession {
+    pub crew_id: types::CrewId,
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::end_of_session_answers))]
+pub struct NewEndOfSessionAnswer {
+    pub end_of_session_id: types::EndOfSessionId,
+    pub character_id: types::CharacterId,
+    pub playbook: i32,
+    pub beliefs: i32,
+    pub struggle: i32,
+    pub track: String,
+}
+
+impl NewEndOfSessionAnswer {
+    pub fn new(
+        end_of_session_id: types::EndOfSessionId,
+        character_id: types::CharacterId,
+        answers: &types::XpAnswers,
+    ) -> Self {
+        Self {
+            end_of_session_id,
+            character_id,
+            playbook: answers.playbook.marks() as i32,
+            beliefs: answers.beliefs.marks() as i32,
+            struggle: answers.struggle.marks() as i32,
+            track: answers.track.to_string(),
+        }
+    }
+}
@@ -1,7 +1,15 @@
 mod characters;
+mod clocks;
 mod crews;
+mod end_of_session;
+mod rolls;
+mod scores;
 mod users;
 
 pub use characters::*;
+pub use clocks::*;
 pub use crews::*;
+pub use end_of_session::*;
+pub use rolls::*;
+pub use scores::*;
 pub use users::*;
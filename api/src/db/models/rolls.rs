@@ -0,0 +1,87 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+
+#[cfg_attr(feature = "server", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::rolls))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct Roll {
+    pub id: types::roll::RollId,
+    pub crew_id: types::CrewId,
+    pub character_id: Option<types::CharacterId>,
+    pub kind: String,
+    pub label: String,
+    pub dice: String,
+    pub zero_dice: bool,
+    pub outcome: String,
+    pub stress: Option<i32>,
+}
+
+impl Roll {
+    pub fn into_entry(
+        self,
+        character_name: Option<String>,
+    ) -> Result<types::roll::RollLogEntry, String> {
+        let dice = self
+            .dice
+            .split(',')
+            .filter(|d| !d.is_empty())
+            .map(|d| {
+                d.parse::<u8>()
+                    .map_err(|e| format!("Invalid die ({d}): {e}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(types::roll::RollLogEntry {
+            id: self.id,
+            character_id: self.character_id,
+            character_name,
+            kind: self.kind.parse()?,
+            label: self.label,
+            dice,
+            zero_dice: self.zero_dice,
+            outcome: self.outcome.parse()?,
+            stress: self.stress.map(|s| s as i8),
+        })
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::rolls))]
+pub struct NewRoll {
+    pub crew_id: types::CrewId,
+    pub character_id: Option<types::CharacterId>,
+    pub kind: String,
+    pub label: String,
+    pub dice: String,
+    pub zero_dice: bool,
+    pub outcome: String,
+    pub stress: Option<i32>,
+}
+
+impl NewRoll {
+    pub fn new(character: &types::Character, result: &types::roll::RollResult) -> Self {
+        Self {
+            character_id: Some(character.id),
+            ..Self::crew(character.crew_id, result)
+        }
+    }
+
+    /// A roll made by the crew as a whole, like engagement or entanglements.
+    pub fn crew(crew_id: types::CrewId, result: &types::roll::RollResult) -> Self {
+        Self {
+            crew_id,
+            character_id: None,
+            kind: result.roll.kind().to_string(),
+            label: result.roll.to_string(),
+            dice: result
+                .dice
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            zero_dice: result.zero_dice,
+            outcome: result.outcome.to_string(),
+            stress: result.stress.map(|s| s as i32),
+        }
+    }
+}
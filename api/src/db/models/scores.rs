@@ -0,0 +1,139 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+
+#[cfg_attr(feature = "server", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::scores))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct Score {
+    pub id: types::ScoreId,
+    pub crew_id: types::CrewId,
+    pub target: String,
+    pub plan: String,
+    pub detail: String,
+    pub status: String,
+    pub engagement_dice: Option<String>,
+    pub engagement_outcome: Option<String>,
+    pub paid_out: bool,
+    pub district: Option<String>,
+}
+
+impl Score {
+    pub fn into_score(self, characters: Vec<types::CharacterId>) -> Result<types::Score, String> {
+        let engagement = match (self.engagement_dice, self.engagement_outcome) {
+            (Some(dice), Some(outcome)) => Some(types::Engagement {
+                dice: dice
+                    .split(',')
+                    .filter(|d| !d.is_empty())
+                    .map(|d| {
+                        d.parse::<u8>()
+                            .map_err(|e| format!("Invalid die ({d}): {e}"))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+                outcome: outcome.parse()?,
+            }),
+            (None, None) => None,
+            _ => return Err(format!("Score ({}) has a partial engagement roll", self.id)),
+        };
+
+        Ok(types::Score {
+            id: self.id,
+            crew_id: self.crew_id,
+            target: self.target,
+            plan: self.plan.parse()?,
+            detail: self.detail,
+            characters,
+            status: self.status.parse()?,
+            engagement,
+            paid_out: self.paid_out,
+            district: self
+                .district
+                .as_deref()
+                .and_then(types::districts::District::id_of),
+        })
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::scores))]
+pub struct NewScore {
+    pub crew_id: types::CrewId,
+    pub target: String,
+    pub plan: String,
+    pub detail: String,
+    pub district: Option<String>,
+}
+
+impl NewScore {
+    pub fn new(crew_id: types::CrewId, plan: &types::ScorePlan) -> Self {
+        Self {
+            crew_id,
+            target: plan.target.trim().to_string(),
+            plan: plan.plan.to_string(),
+            detail: plan.detail.trim().to_string(),
+            district: plan
+                .district
+                .and_then(types::districts::District::find)
+                .map(|d| d.name.to_string()),
+        }
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Queryable, Selectable, Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::score_characters))]
+pub struct ScoreCharacter {
+    pub score_id: types::ScoreId,
+    pub character_id: types::CharacterId,
+}
+
+#[cfg_attr(feature = "server", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::stress_log))]
+#[cfg_attr(feature = "server", diesel(check_for_backend(diesel::sqlite::Sqlite)))]
+pub struct StressLogEntry {
+    pub id: types::StressSpendId,
+    pub character_id: types::CharacterId,
+    pub score_id: Option<types::ScoreId>,
+    pub kind: String,
+    pub stress: i32,
+    pub note: String,
+}
+
+impl TryFrom<StressLogEntry> for types::StressLogEntry {
+    type Error = String;
+
+    fn try_from(entry: StressLogEntry) -> Result<Self, Self::Error> {
+        Ok(types::StressLogEntry {
+            id: entry.id,
+            character_id: entry.character_id,
+            score_id: entry.score_id,
+            spend: types::StressSpend::from_kind(&entry.kind, entry.stress as u8)?,
+            note: entry.note,
+        })
+    }
+}
+
+#[cfg_attr(feature = "server", derive(Insertable))]
+#[cfg_attr(feature = "server", diesel(table_name = crate::db::schema::stress_log))]
+pub struct NewStressLogEntry {
+    pub character_id: types::CharacterId,
+    pub score_id: Option<types::ScoreId>,
+    pub kind: String,
+    pub stress: i32,
+    pub note: String,
+}
+
+impl NewStressLogEntry {
+    pub fn new(
+        character_id: types::CharacterId,
+        score_id: Option<types::ScoreId>,
+        spend: types::StressSpend,
+        note: &str,
+    ) -> Self {
+        Self {
+            character_id,
+            score_id,
+            kind: spend.kind().to_string(),
+            stress: spend.stress() as i32,
+            note: note.trim().to_string(),
+        }
+    }
+}
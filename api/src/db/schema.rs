@@ -8,6 +8,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    character_advancements (id) {
+        id -> Integer,
+        character_id -> Integer,
+        kind -> Text,
+        choice -> Text,
+    }
+}
+
 diesel::table! {
     character_class_items (id) {
         id -> Integer,
@@ -20,7 +29,7 @@ diesel::table! {
     character_contacts (id) {
         id -> Integer,
         character_id -> Integer,
-        name -> Text,
+        npc_id -> Integer,
         friend -> Bool,
     }
 }
@@ -54,6 +63,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    character_log (id) {
+        id -> Integer,
+        character_id -> Integer,
+        entry -> Text,
+    }
+}
+
 diesel::table! {
     character_xp (character_id) {
         character_id -> Integer,
@@ -84,6 +101,60 @@ diesel::table! {
         coin -> Integer,
         load -> Nullable<Integer>,
         items -> Integer,
+        retired -> Bool,
+        downtime_activities -> Integer,
+        pending_overindulgence -> Bool,
+        downtime_trained -> Integer,
+    }
+}
+
+diesel::table! {
+    clocks (id) {
+        id -> Integer,
+        crew_id -> Nullable<Integer>,
+        character_id -> Nullable<Integer>,
+        name -> Text,
+        segments -> Integer,
+        filled -> Integer,
+        faction -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    cohorts (id) {
+        id -> Integer,
+        crew_id -> Integer,
+        name -> Text,
+        kind -> Text,
+        types -> Text,
+        edges -> Text,
+        flaws -> Text,
+        harm -> Integer,
+        armor -> Bool,
+    }
+}
+
+diesel::table! {
+    crew_abilities (id) {
+        id -> Integer,
+        crew_id -> Integer,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    crew_claims (crew_id, position) {
+        crew_id -> Integer,
+        position -> Integer,
+        district -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    crew_factions (crew_id, faction) {
+        crew_id -> Integer,
+        faction -> Text,
+        status -> Integer,
     }
 }
 
@@ -104,12 +175,95 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    crew_upgrades (crew_id, name) {
+        crew_id -> Integer,
+        name -> Text,
+        boxes -> Integer,
+    }
+}
+
 diesel::table! {
     crews (id) {
         id -> Integer,
         name -> Text,
         specialty -> Text,
         dm_id -> Text,
+        reputation -> Integer,
+        heat -> Integer,
+        wanted_level -> Integer,
+        tier -> Integer,
+        hold -> Text,
+        coin -> Integer,
+        vaults -> Integer,
+        xp -> Integer,
+    }
+}
+
+diesel::table! {
+    end_of_session_answers (end_of_session_id, character_id) {
+        end_of_session_id -> Integer,
+        character_id -> Integer,
+        playbook -> Integer,
+        beliefs -> Integer,
+        struggle -> Integer,
+        track -> Text,
+    }
+}
+
+diesel::table! {
+    end_of_sessions (id) {
+        id -> Integer,
+        crew_id -> Integer,
+        closed -> Bool,
+    }
+}
+
+diesel::table! {
+    npcs (id) {
+        id -> Integer,
+        crew_id -> Integer,
+        name -> Text,
+        description -> Text,
+        status -> Integer,
+        faction -> Nullable<Text>,
+        district -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    rolls (id) {
+        id -> Integer,
+        crew_id -> Integer,
+        character_id -> Nullable<Integer>,
+        kind -> Text,
+        label -> Text,
+        dice -> Text,
+        zero_dice -> Bool,
+        outcome -> Text,
+        stress -> Nullable<Integer>,
+    }
+}
+
+diesel::table! {
+    score_characters (score_id, character_id) {
+        score_id -> Integer,
+        character_id -> Integer,
+    }
+}
+
+diesel::table! {
+    scores (id) {
+        id -> Integer,
+        crew_id -> Integer,
+        target -> Text,
+        plan -> Text,
+        detail -> Text,
+        status -> Text,
+        engagement_dice -> Nullable<Text>,
+        engagement_outcome -> Nullable<Text>,
+        paid_out -> Bool,
+        district -> Nullable<Text>,
     }
 }
 
@@ -122,6 +276,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    stress_log (id) {
+        id -> Integer,
+        character_id -> Integer,
+        score_id -> Nullable<Integer>,
+        kind -> Text,
+        stress -> Integer,
+        note -> Text,
+    }
+}
+
 diesel::table! {
     users (username) {
         username -> Text,
@@ -130,30 +295,66 @@ diesel::table! {
 }
 
 diesel::joinable!(character_abilities -> characters (character_id));
+diesel::joinable!(character_advancements -> characters (character_id));
 diesel::joinable!(character_class_items -> characters (character_id));
 diesel::joinable!(character_contacts -> characters (character_id));
+diesel::joinable!(character_contacts -> npcs (npc_id));
 diesel::joinable!(character_dots -> characters (character_id));
 diesel::joinable!(character_harm -> characters (character_id));
+diesel::joinable!(character_log -> characters (character_id));
 diesel::joinable!(character_xp -> characters (character_id));
 diesel::joinable!(characters -> crews (crew_id));
 diesel::joinable!(characters -> users (user_id));
+diesel::joinable!(clocks -> characters (character_id));
+diesel::joinable!(clocks -> crews (crew_id));
+diesel::joinable!(cohorts -> crews (crew_id));
+diesel::joinable!(crew_abilities -> crews (crew_id));
+diesel::joinable!(crew_claims -> crews (crew_id));
+diesel::joinable!(crew_factions -> crews (crew_id));
 diesel::joinable!(crew_invites -> crews (crew_id));
 diesel::joinable!(crew_members -> crews (crew_id));
 diesel::joinable!(crew_members -> users (user_id));
+diesel::joinable!(crew_upgrades -> crews (crew_id));
 diesel::joinable!(crews -> users (dm_id));
+diesel::joinable!(end_of_session_answers -> characters (character_id));
+diesel::joinable!(end_of_session_answers -> end_of_sessions (end_of_session_id));
+diesel::joinable!(end_of_sessions -> crews (crew_id));
+diesel::joinable!(npcs -> crews (crew_id));
+diesel::joinable!(rolls -> characters (character_id));
+diesel::joinable!(rolls -> crews (crew_id));
+diesel::joinable!(score_characters -> characters (character_id));
+diesel::joinable!(score_characters -> scores (score_id));
+diesel::joinable!(scores -> crews (crew_id));
 diesel::joinable!(sessions -> users (user_id));
+diesel::joinable!(stress_log -> characters (character_id));
+diesel::joinable!(stress_log -> scores (score_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     character_abilities,
+    character_advancements,
     character_class_items,
     character_contacts,
     character_dots,
     character_harm,
+    character_log,
     character_xp,
     characters,
+    clocks,
+    cohorts,
+    crew_abilities,
+    crew_claims,
+    crew_factions,
     crew_invites,
     crew_members,
+    crew_upgrades,
     crews,
+    end_of_session_answers,
+    end_of_sessions,
+    npcs,
+    rolls,
+    score_characters,
+    scores,
     sessions,
+    stress_log,
     users,
 );
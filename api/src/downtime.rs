@@ -0,0 +1,249 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+/// Starts a new downtime, giving every character in the crew their free activities back.
+#[data::cfg_server("downtime/start")]
+pub async fn start_downtime(crew_id: types::CrewId) -> Result<(), ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !crate::crew::is_dm(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Only the GM can start downtime".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    diesel::update(characters::table.filter(characters::crew_id.eq(crew_id)))
+        .set((
+            characters::downtime_activities.eq(0),
+            characters::downtime_trained.eq(0),
+        ))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to start downtime for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to start downtime".to_string())
+        })?;
+
+    Ok(())
+}
+
+/// Takes a downtime activity, rolling for it and applying the result to the character,
+/// crew or clock it affects.
+#[data::cfg_server("downtime/take")]
+pub async fn take_activity(
+    character_id: types::CharacterId,
+    activity: types::DowntimeActivity,
+    extra: Option<types::DowntimeCost>,
+) -> Result<types::DowntimeResult, ServerFnError<String>> {
+    use types::{DowntimeActivity, DowntimeCost, DowntimeOutcome};
+
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let mut character = crate::character::load_character(&mut conn, character_id)
+        .map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    if character.user_id != user.username {
+        return Err(ServerFnError::Request("Character not found".to_string()));
+    }
+
+    let mut crew: types::Crew = crews::table
+        .find(character.crew_id)
+        .select(db::models::Crew::as_select())
+        .first(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to find crew ({}): {e}", character.crew_id);
+            ServerFnError::ServerError("Crew not found".to_string())
+        })?
+        .into();
+
+    let cost = character
+        .downtime_cost(&activity, extra, &crew)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    let pool = match activity {
+        DowntimeActivity::Recover {
+            healer: types::Healer::Character(healer_id),
+        } => {
+            let healer = crate::character::load_character(&mut conn, healer_id)
+                .map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+            if healer.crew_id != character.crew_id {
+                return Err(ServerFnError::WrappedServerError(
+                    "Your healer must be in your crew".to_string(),
+                ));
+            }
+
+            Some(healer.dots.rating(types::Action::Tinker))
+        }
+        _ => activity.dice_pool(&character, &crew),
+    };
+
+    let roll = pool.map(|pool| {
+        let (dice, zero_dice) = crate::roll::roll_dice(pool);
+        types::roll::RollResult::new(types::roll::Roll::Fortune { dice: pool }, dice, zero_dice)
+    });
+
+    match cost {
+        Some(DowntimeCost::Coin) => character.coin -= 1,
+        Some(DowntimeCost::Rep) => crew.reputation -= 1,
+        None => {}
+    }
+    character.downtime_activities += 1;
+
+    let mut clock = None;
+
+    let outcome = match (activity, &roll) {
+        (DowntimeActivity::AcquireAsset { coin }, Some(roll)) => {
+            character.coin -= coin;
+            DowntimeOutcome::Asset {
+                quality: roll.outcome.asset_quality(crew.tier)
+                    + coin / types::Character::COIN_PER_ASSET_QUALITY,
+            }
+        }
+        (DowntimeActivity::LongTermProject { clock_id, .. }, Some(roll)) => {
+            let mut project = crate::clock::find_clock(&mut conn, clock_id, &user.username)
+                .and_then(crate::clock::into_clock)
+                .map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+            if project.owner != types::ClockOwner::Character(character.id)
+                && project.owner != types::ClockOwner::Crew(character.crew_id)
+            {
+                return Err(ServerFnError::Request("Clock not found".to_string()));
+            }
+
+            let ticks = roll.outcome.downtime_ticks();
+            project.tick(ticks as i8);
+
+            let outcome = DowntimeOutcome::Project {
+                ticks,
+                filled: project.filled,
+                segments: project.segments(),
+            };
+            clock = Some(project);
+            outcome
+        }
+        (DowntimeActivity::Recover { .. }, Some(roll)) => {
+            let ticks = roll.outcome.downtime_ticks();
+            let healed = character.recover(ticks);
+            DowntimeOutcome::Recovered {
+                ticks,
+                healing: character.healing,
+                healed,
+            }
+        }
+        (DowntimeActivity::ReduceHeat { .. }, Some(roll)) => {
+            let ticks = roll.outcome.downtime_ticks();
+            crew.heat = crew.heat.saturating_sub(ticks);
+            DowntimeOutcome::HeatReduced {
+                ticks,
+                heat: crew.heat,
+            }
+        }
+        (DowntimeActivity::Train { track }, None) => {
+            character.xp.mark(track, 1);
+            character.trained.push(track);
+            DowntimeOutcome::Trained {
+                xp: character.xp.clone(),
+            }
+        }
+        (DowntimeActivity::IndulgeVice, Some(roll)) => {
+            let cleared = character.indulge_vice(roll.result_die());
+            DowntimeOutcome::Indulged {
+                cleared,
+                stress: character.stress,
+                overindulged: character.pending_overindulgence,
+            }
+        }
+        _ => {
+            tracing::error!("Downtime activity {activity:?} rolled {roll:?}");
+            return Err(ServerFnError::ServerError(
+                "Failed to take downtime activity".to_string(),
+            ));
+        }
+    };
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::update(characters::table.find(character.id))
+            .set((
+                characters::coin.eq(character.coin as i32),
+                characters::stress.eq(character.stress as i32),
+                characters::healing.eq(character.healing as i32),
+                characters::downtime_activities.eq(character.downtime_activities as i32),
+                characters::pending_overindulgence.eq(character.pending_overindulgence),
+                characters::downtime_trained.eq(db::models::trained_bits(&character.trained)),
+            ))
+            .execute(conn)?;
+
+        if let DowntimeOutcome::Recovered { healed: true, .. } = outcome {
+            crate::character::write_harm(conn, character.id, &character.harm)?;
+            crate::character::write_log(
+                conn,
+                character.id,
+                "Filled their healing clock, healing every harm by a level".to_string(),
+            )?;
+        }
+
+        if let DowntimeOutcome::Indulged {
+            cleared,
+            overindulged,
+            ..
+        } = outcome
+        {
+            let mut entry = format!("Indulged in {}, clearing {cleared} stress", character.vice);
+            if overindulged {
+                entry.push_str(" and overindulging");
+            }
+            crate::character::write_log(conn, character.id, entry)?;
+        }
+
+        diesel::update(character_xp::table.find(character.id))
+            .set(&db::models::CharacterXp::with_xp(
+                character.id,
+                &character.xp,
+            ))
+            .execute(conn)?;
+
+        diesel::update(crews::table.find(crew.id))
+            .set((
+                crews::heat.eq(crew.heat as i32),
+                crews::reputation.eq(crew.reputation as i32),
+            ))
+            .execute(conn)?;
+
+        if let Some(clock) = &clock {
+            diesel::update(clocks::table.find(clock.id))
+                .set(clocks::filled.eq(clock.filled as i32))
+                .execute(conn)?;
+        }
+
+        if let Some(roll) = &roll {
+            diesel::insert_into(rolls::table)
+                .values(&db::models::NewRoll {
+                    label: activity.to_string(),
+                    ..db::models::NewRoll::new(&character, roll)
+                })
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to apply downtime for character ({character_id}): {e}");
+        ServerFnError::ServerError("Failed to take downtime activity".to_string())
+    })?;
+
+    Ok(types::DowntimeResult {
+        activity,
+        roll,
+        outcome,
+        activities_taken: character.downtime_activities,
+    })
+}
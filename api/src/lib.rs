@@ -9,6 +9,14 @@ pub mod character;
 
 pub mod crew;
 
+pub mod clock;
+
+pub mod roll;
+
+pub mod downtime;
+
+pub mod score;
+
 pub mod client;
 
 pub(crate) use auth::session::Auth;
@@ -0,0 +1,78 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+
+/// Rolls the pool, or two dice if it is empty.
+#[cfg(feature = "server")]
+pub(crate) fn roll_dice(pool: u8) -> (Vec<u8>, bool) {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    let zero_dice = pool == 0;
+    let count = if zero_dice { 2 } else { pool };
+
+    let dice = (0..count).map(|_| rng.gen_range(1..=6)).collect();
+
+    (dice, zero_dice)
+}
+
+#[cfg(feature = "server")]
+pub(crate) fn make_roll(
+    character: &types::Character,
+    roll: types::roll::Roll,
+    bonus: u8,
+) -> types::roll::RollResult {
+    let (dice, zero_dice) = roll_dice(roll.dice_pool(character, bonus));
+
+    types::roll::RollResult::new(roll, dice, zero_dice)
+}
+
+#[data::cfg_server("roll/roll")]
+pub async fn roll(
+    character_id: types::CharacterId,
+    roll: types::roll::Roll,
+    bonus: u8,
+) -> Result<types::roll::RollResult, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    let character = crate::character::load_character(&mut conn, character_id)?;
+
+    if character.user_id != user.username && !crate::crew::is_dm(character.crew_id, &user.username)
+    {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Character not found".to_string(),
+        ));
+    }
+
+    if roll.dice_pool(&character, bonus) > types::roll::Roll::MAX_DICE {
+        return Err(ServerFnError::<NoCustomError>::Request(format!(
+            "A roll can use at most {} dice",
+            types::roll::Roll::MAX_DICE
+        )));
+    }
+
+    let result = make_roll(&character, roll, bonus);
+
+    diesel::insert_into(db::schema::rolls::table)
+        .values(&db::models::NewRoll::new(&character, &result))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to log roll: {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to log roll".to_string())
+        })?;
+
+    tracing::info!(
+        "Character ({}) rolled {}: {:?} ({})",
+        character.id,
+        result.roll,
+        result.dice,
+        result.outcome
+    );
+
+    Ok(result)
+}
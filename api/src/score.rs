@@ -0,0 +1,508 @@
+#[cfg(feature = "server")]
+use diesel::prelude::*;
+use dioxus::prelude::{server_fn::error::NoCustomError, *};
+
+use crate::db;
+#[cfg(feature = "server")]
+use crate::db::schema::*;
+
+#[cfg(feature = "server")]
+pub(crate) fn load_score(
+    conn: &mut diesel::SqliteConnection,
+    id: types::ScoreId,
+) -> Result<types::Score, ServerFnError> {
+    let score: db::models::Score = scores::table
+        .find(id)
+        .select(db::models::Score::as_select())
+        .first(conn)
+        .map_err(|e| {
+            tracing::info!("Failed to find score: {e}");
+            ServerFnError::<NoCustomError>::Request("Score not found".to_string())
+        })?;
+
+    into_score(conn, score)
+}
+
+#[cfg(feature = "server")]
+fn into_score(
+    conn: &mut diesel::SqliteConnection,
+    score: db::models::Score,
+) -> Result<types::Score, ServerFnError> {
+    let characters = score_characters::table
+        .filter(score_characters::score_id.eq(score.id))
+        .select(score_characters::character_id)
+        .load(conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load characters for score ({}): {e}", score.id);
+            ServerFnError::<NoCustomError>::ServerError("Failed to load score".to_string())
+        })?;
+
+    score.into_score(characters).map_err(|e| {
+        tracing::error!("{e}");
+        ServerFnError::<NoCustomError>::ServerError("Corrupt score data".to_string())
+    })
+}
+
+/// The score being planned, run or paid out, if any. A crew only has one at a time.
+#[cfg(feature = "server")]
+pub(crate) fn load_active_score(
+    conn: &mut diesel::SqliteConnection,
+    crew_id: types::CrewId,
+) -> Result<Option<types::Score>, ServerFnError> {
+    let score = scores::table
+        .filter(scores::crew_id.eq(crew_id))
+        .filter(
+            scores::status
+                .ne(types::ScoreStatus::Completed.to_string())
+                .or(scores::paid_out.eq(false)),
+        )
+        .select(db::models::Score::as_select())
+        .first(conn)
+        .optional()
+        .map_err(|e| {
+            tracing::error!("Failed to load active score for crew ({crew_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load score".to_string())
+        })?;
+
+    score.map(|score| into_score(conn, score)).transpose()
+}
+
+/// Checks a plan is complete and only sends active characters from the crew.
+#[cfg(feature = "server")]
+fn check_plan(
+    conn: &mut diesel::SqliteConnection,
+    crew_id: types::CrewId,
+    plan: &types::ScorePlan,
+) -> Result<(), ServerFnError<String>> {
+    plan.validate().map_err(ServerFnError::WrappedServerError)?;
+
+    let eligible: i64 = characters::table
+        .filter(characters::id.eq_any(&plan.characters))
+        .filter(characters::crew_id.eq(crew_id))
+        .filter(characters::retired.eq(false))
+        .count()
+        .get_result(conn)
+        .map_err(|e| {
+            tracing::error!("Failed to check score characters: {e}");
+            ServerFnError::ServerError("Failed to check characters".to_string())
+        })?;
+
+    if eligible as usize != plan.characters.len() {
+        return Err(ServerFnError::WrappedServerError(
+            "Only active characters in the crew can go on a score".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "server")]
+fn insert_characters(
+    conn: &mut diesel::SqliteConnection,
+    score_id: types::ScoreId,
+    characters: &[types::CharacterId],
+) -> QueryResult<usize> {
+    diesel::insert_into(score_characters::table)
+        .values(
+            characters
+                .iter()
+                .map(|&character_id| db::models::ScoreCharacter {
+                    score_id,
+                    character_id,
+                })
+                .collect::<Vec<_>>(),
+        )
+        .execute(conn)
+}
+
+/// Clears every crew member's load and ticked items, ready to pick a new loadout.
+#[cfg(feature = "server")]
+fn reset_loadouts(conn: &mut diesel::SqliteConnection, crew_id: types::CrewId) -> QueryResult<()> {
+    let ids = characters::table
+        .filter(characters::crew_id.eq(crew_id))
+        .select(characters::id);
+
+    diesel::delete(
+        character_class_items::table.filter(character_class_items::character_id.eq_any(ids)),
+    )
+    .execute(conn)?;
+
+    diesel::update(characters::table.filter(characters::crew_id.eq(crew_id)))
+        .set((characters::load.eq(None::<i32>), characters::items.eq(0)))
+        .execute(conn)?;
+
+    Ok(())
+}
+
+#[data::cfg_server("score/get_active")]
+pub async fn get_active_score(
+    crew_id: types::CrewId,
+) -> Result<Option<types::Score>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    if !crate::crew::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    load_active_score(&mut conn, crew_id)
+}
+
+/// Starts planning a new score. Fails if the crew is already on one.
+#[data::cfg_server("score/create")]
+pub async fn create_score(
+    crew_id: types::CrewId,
+    plan: types::ScorePlan,
+) -> Result<types::Score, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    if !crate::crew::is_in_crew(crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Not a member of this crew".to_string(),
+        ));
+    }
+
+    let mut conn = db::connect();
+
+    check_plan(&mut conn, crew_id, &plan)?;
+
+    if load_active_score(&mut conn, crew_id)
+        .map_err(|e| ServerFnError::ServerError(e.to_string()))?
+        .is_some()
+    {
+        return Err(ServerFnError::WrappedServerError(
+            "The crew is already on a score".to_string(),
+        ));
+    }
+
+    let id = conn
+        .transaction::<_, diesel::result::Error, _>(|conn| {
+            let id = diesel::insert_into(scores::table)
+                .values(&db::models::NewScore::new(crew_id, &plan))
+                .returning(scores::id)
+                .get_result::<types::ScoreId>(conn)?;
+
+            insert_characters(conn, id, &plan.characters)?;
+            reset_loadouts(conn, crew_id)?;
+
+            Ok(id)
+        })
+        .map_err(|e| {
+            tracing::error!("Failed to create score for crew ({crew_id}): {e}");
+            ServerFnError::ServerError("Failed to create score".to_string())
+        })?;
+
+    load_score(&mut conn, id).map_err(|e| ServerFnError::ServerError(e.to_string()))
+}
+
+/// Changes the plan of a score that hasn't started yet.
+#[data::cfg_server("score/update_plan")]
+pub async fn update_plan(
+    score_id: types::ScoreId,
+    plan: types::ScorePlan,
+) -> Result<types::Score, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let score =
+        load_score(&mut conn, score_id).map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    if !crate::crew::is_in_crew(score.crew_id, &user.username) {
+        return Err(ServerFnError::Request("Score not found".to_string()));
+    }
+
+    if score.status != types::ScoreStatus::Planning {
+        return Err(ServerFnError::WrappedServerError(
+            "The plan can't change once the score has started".to_string(),
+        ));
+    }
+
+    check_plan(&mut conn, score.crew_id, &plan)?;
+
+    let new = db::models::NewScore::new(score.crew_id, &plan);
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::update(scores::table.find(score_id))
+            .set((
+                scores::target.eq(new.target),
+                scores::plan.eq(new.plan),
+                scores::detail.eq(new.detail),
+                scores::district.eq(new.district),
+            ))
+            .execute(conn)?;
+
+        diesel::delete(score_characters::table.filter(score_characters::score_id.eq(score_id)))
+            .execute(conn)?;
+
+        insert_characters(conn, score_id, &plan.characters)?;
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to update score ({score_id}): {e}");
+        ServerFnError::ServerError("Failed to update score".to_string())
+    })?;
+
+    load_score(&mut conn, score_id).map_err(|e| ServerFnError::ServerError(e.to_string()))
+}
+
+/// The GM's engagement roll, which starts the score.
+#[data::cfg_server("score/roll_engagement")]
+pub async fn roll_engagement(
+    score_id: types::ScoreId,
+    dice: u8,
+) -> Result<types::Score, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let score =
+        load_score(&mut conn, score_id).map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    if !crate::crew::is_dm(score.crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Only the GM can make the engagement roll".to_string(),
+        ));
+    }
+
+    if score.status != types::ScoreStatus::Planning {
+        return Err(ServerFnError::WrappedServerError(
+            "The engagement roll has already been made".to_string(),
+        ));
+    }
+
+    if dice > types::roll::Roll::MAX_DICE {
+        return Err(ServerFnError::Request(format!(
+            "A roll can use at most {} dice",
+            types::roll::Roll::MAX_DICE
+        )));
+    }
+
+    let (rolled, zero_dice) = crate::roll::roll_dice(dice);
+    let roll =
+        types::roll::RollResult::new(types::roll::Roll::Engagement { dice }, rolled, zero_dice);
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::update(scores::table.find(score_id))
+            .set((
+                scores::status.eq(types::ScoreStatus::Active.to_string()),
+                scores::engagement_dice.eq(roll
+                    .dice
+                    .iter()
+                    .map(|d| d.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")),
+                scores::engagement_outcome.eq(roll.outcome.to_string()),
+            ))
+            .execute(conn)?;
+
+        diesel::insert_into(rolls::table)
+            .values(&db::models::NewRoll {
+                label: format!("{} ({})", roll.roll, score.target),
+                ..db::models::NewRoll::crew(score.crew_id, &roll)
+            })
+            .execute(conn)?;
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to start score ({score_id}): {e}");
+        ServerFnError::ServerError("Failed to start score".to_string())
+    })?;
+
+    load_score(&mut conn, score_id).map_err(|e| ServerFnError::ServerError(e.to_string()))
+}
+
+#[data::cfg_server("score/complete")]
+pub async fn complete_score(score_id: types::ScoreId) -> Result<(), ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let score =
+        load_score(&mut conn, score_id).map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    if !crate::crew::is_dm(score.crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Only the GM can complete a score".to_string(),
+        ));
+    }
+
+    if score.status != types::ScoreStatus::Active {
+        return Err(ServerFnError::WrappedServerError(
+            "Only a score underway can be completed".to_string(),
+        ));
+    }
+
+    diesel::update(scores::table.find(score_id))
+        .set(scores::status.eq(types::ScoreStatus::Completed.to_string()))
+        .execute(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to complete score ({score_id}): {e}");
+            ServerFnError::ServerError("Failed to complete score".to_string())
+        })?;
+
+    Ok(())
+}
+
+/// The stress marked on a score, oldest first.
+#[data::cfg_server("score/get_stress_log")]
+pub async fn get_stress_log(
+    score_id: types::ScoreId,
+) -> Result<Vec<types::StressLogEntry>, ServerFnError> {
+    let user: crate::User = extract().await?;
+
+    let mut conn = db::connect();
+
+    let score = load_score(&mut conn, score_id)?;
+
+    if !crate::crew::is_dm(score.crew_id, &user.username) {
+        return Err(ServerFnError::<NoCustomError>::Request(
+            "Only the GM can review stress".to_string(),
+        ));
+    }
+
+    let entries: Vec<db::models::StressLogEntry> = stress_log::table
+        .filter(stress_log::score_id.eq(score_id))
+        .order(stress_log::id.asc())
+        .select(db::models::StressLogEntry::as_select())
+        .load(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to load stress log for score ({score_id}): {e}");
+            ServerFnError::<NoCustomError>::ServerError("Failed to load stress log".to_string())
+        })?;
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            entry.try_into().map_err(|e: String| {
+                tracing::error!("Invalid stress log entry: {e}");
+                ServerFnError::<NoCustomError>::ServerError("Failed to load stress log".to_string())
+            })
+        })
+        .collect()
+}
+
+/// Hands out the payoff for a completed score: coin to each character on it and the rest to
+/// the crew, rep, and heat from the score's exposure. Rolls the entanglement that follows.
+#[data::cfg_server("score/pay_off")]
+pub async fn pay_off(
+    score_id: types::ScoreId,
+    payoff: types::Payoff,
+) -> Result<types::PayoffResult, ServerFnError<String>> {
+    let user = crate::User::extract_as().await?;
+
+    let mut conn = db::connect();
+
+    let score =
+        load_score(&mut conn, score_id).map_err(|e| ServerFnError::Request(e.to_string()))?;
+
+    if !crate::crew::is_dm(score.crew_id, &user.username) {
+        return Err(ServerFnError::Request(
+            "Only the GM can pay out a score".to_string(),
+        ));
+    }
+
+    if score.status != types::ScoreStatus::Completed || score.paid_out {
+        return Err(ServerFnError::WrappedServerError(
+            "Only a completed score can be paid out, once".to_string(),
+        ));
+    }
+
+    let mut crew: types::Crew = crews::table
+        .find(score.crew_id)
+        .select(db::models::Crew::as_select())
+        .first(&mut conn)
+        .map_err(|e| {
+            tracing::error!("Failed to find crew ({}): {e}", score.crew_id);
+            ServerFnError::ServerError("Crew not found".to_string())
+        })?
+        .into();
+
+    let mut characters = score
+        .characters
+        .iter()
+        .map(|&id| crate::character::load_character(&mut conn, id))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
+
+    let crew_coin = payoff
+        .crew_coin(characters.len())
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    crew.add_coin(crew_coin)
+        .map_err(ServerFnError::WrappedServerError)?;
+
+    crew.reputation = crew
+        .reputation
+        .saturating_add(payoff.rep)
+        .min(types::Crew::MAX_REPUTATION);
+
+    let heat = payoff.heat();
+    crew.add_heat(heat);
+
+    for character in &mut characters {
+        character.take_coin(payoff.share);
+    }
+
+    let (dice, zero_dice) = crate::roll::roll_dice(crew.heat);
+    let roll = types::roll::RollResult::new(
+        types::roll::Roll::Fortune { dice: crew.heat },
+        dice,
+        zero_dice,
+    );
+
+    let entanglement = types::EntanglementRoll {
+        options: types::Entanglement::table(crew.wanted_level, roll.result_die()).to_vec(),
+        dice: roll.dice.clone(),
+        zero_dice,
+    };
+
+    conn.transaction::<_, diesel::result::Error, _>(|conn| {
+        diesel::update(crews::table.find(crew.id))
+            .set((
+                crews::coin.eq(crew.coin as i32),
+                crews::reputation.eq(crew.reputation as i32),
+                crews::heat.eq(crew.heat as i32),
+                crews::wanted_level.eq(crew.wanted_level as i32),
+            ))
+            .execute(conn)?;
+
+        for character in &characters {
+            diesel::update(characters::table.find(character.id))
+                .set((
+                    characters::coin.eq(character.coin as i32),
+                    characters::stash.eq(character.stash as i32),
+                ))
+                .execute(conn)?;
+        }
+
+        diesel::update(scores::table.find(score_id))
+            .set(scores::paid_out.eq(true))
+            .execute(conn)?;
+
+        diesel::insert_into(rolls::table)
+            .values(&db::models::NewRoll {
+                label: format!("Entanglements ({})", score.target),
+                ..db::models::NewRoll::crew(crew.id, &roll)
+            })
+            .execute(conn)?;
+
+        Ok(())
+    })
+    .map_err(|e| {
+        tracing::error!("Failed to pay out score ({score_id}): {e}");
+        ServerFnError::ServerError("Failed to pay out score".to_string())
+    })?;
+
+    Ok(types::PayoffResult {
+        crew,
+        crew_coin,
+        heat,
+        entanglement,
+    })
+}
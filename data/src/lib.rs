@@ -13,10 +13,34 @@ pub fn blades(_: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let class_items: ClassItems =
         serde_json::from_str(class_items_str).expect("Failed to parse class items JSON");
 
+    let standard_items_str = include_str!("../blades/items.json");
+    let standard_items: Vec<StandardItem> =
+        serde_json::from_str(standard_items_str).expect("Failed to parse items JSON");
+    let standard_items_count = standard_items.len();
+
     let contacts_str = include_str!("../blades/contacts.json");
     let contacts: Contacts =
         serde_json::from_str(contacts_str).expect("Failed to parse contacts JSON");
 
+    let crews_str = include_str!("../blades/crews.json");
+    let crews: Crews = serde_json::from_str(crews_str).expect("Failed to parse crews JSON");
+
+    let claims_str = include_str!("../blades/claims.json");
+    let claims: Claims = serde_json::from_str(claims_str).expect("Failed to parse claims JSON");
+
+    let factions_str = include_str!("../blades/factions.json");
+    let factions: Vec<Faction> =
+        serde_json::from_str(factions_str).expect("Failed to parse factions JSON");
+    let factions_count = factions.len();
+
+    let districts_str = include_str!("../blades/districts.json");
+    let districts: Vec<District> =
+        serde_json::from_str(districts_str).expect("Failed to parse districts JSON");
+    let districts_count = districts.len();
+
+    let classes_str = include_str!("../blades/classes.json");
+    let classes: Classes = serde_json::from_str(classes_str).expect("Failed to parse classes JSON");
+
     quote::quote! {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
         #[cfg_attr(feature = "server", derive(diesel::FromSqlRow, diesel::AsExpression))]
@@ -45,6 +69,8 @@ pub fn blades(_: proc_macro::TokenStream) -> proc_macro::TokenStream {
             }
         }
 
+        #classes
+
         pub mod playbook {
             use super::Class;
 
@@ -63,12 +89,75 @@ pub fn blades(_: proc_macro::TokenStream) -> proc_macro::TokenStream {
         }
 
         pub mod items {
+            /// An item that takes up load once ticked.
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            pub struct ClassItem {
+                pub name: super::Description<&'static str>,
+                pub load: u8,
+            }
+
+            /// One of the standard items every playbook can carry.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub struct StandardItem {
+                pub flag: crate::Items,
+                pub name: &'static str,
+                pub load: u8,
+            }
+
+            pub const STANDARD_ITEMS: [StandardItem; #standard_items_count] = [
+                #(
+                    #standard_items
+                ),*
+            ];
+
             #class_items
         }
 
         pub mod contacts {
             #contacts
         }
+
+        #crews
+
+        #claims
+
+        pub mod factions {
+            /// One of the factions of Doskvol, at the tier and hold it starts the game with.
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            pub struct Faction {
+                pub name: &'static str,
+                pub category: &'static str,
+                pub tier: u8,
+                pub hold: crate::Hold,
+                pub description: super::Description<&'static str>,
+            }
+
+            pub const FACTIONS: [Faction; #factions_count] = [
+                #(
+                    #factions
+                ),*
+            ];
+        }
+
+        pub mod districts {
+            /// A district of Doskvol. Traits are rated 0 to 4.
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            pub struct District {
+                pub name: &'static str,
+                pub description: super::Description<&'static str>,
+                pub wealth: u8,
+                pub security: u8,
+                pub criminal: u8,
+                pub occult: u8,
+                pub landmarks: &'static [&'static str],
+            }
+
+            pub const DISTRICTS: [District; #districts_count] = [
+                #(
+                    #districts
+                ),*
+            ];
+        }
     }
     .into()
 }
@@ -124,15 +213,58 @@ impl quote::ToTokens for PlaybookAbility {
     }
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct StandardItem {
+    flag: String,
+    name: String,
+    load: u8,
+}
+
+impl quote::ToTokens for StandardItem {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let flag = quote::format_ident!("{}", self.flag);
+        let name = &self.name;
+        let load = self.load;
+
+        tokens.extend(quote::quote! {
+            StandardItem {
+                flag: crate::Items::#flag,
+                name: #name,
+                load: #load,
+            }
+        });
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ClassItem {
+    name: String,
+    load: u8,
+}
+
+impl quote::ToTokens for ClassItem {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let name = &self.name;
+        let load = self.load;
+
+        tokens.extend(quote::quote! {
+            ClassItem {
+                name: super::Description::new(#name),
+                load: #load,
+            }
+        });
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct ClassItems {
-    cutter: Vec<String>,
-    hound: Vec<String>,
-    leech: Vec<String>,
-    lurk: Vec<String>,
-    slide: Vec<String>,
-    spider: Vec<String>,
-    whisper: Vec<String>,
+    cutter: Vec<ClassItem>,
+    hound: Vec<ClassItem>,
+    leech: Vec<ClassItem>,
+    lurk: Vec<ClassItem>,
+    slide: Vec<ClassItem>,
+    spider: Vec<ClassItem>,
+    whisper: Vec<ClassItem>,
 }
 
 impl quote::ToTokens for ClassItems {
@@ -156,42 +288,465 @@ impl quote::ToTokens for ClassItems {
         tokens.extend(quote::quote! {
         #[derive(Debug)]
         pub struct ClassItems {
-            pub cutter: [super::Description<&'static str>; #cutter_count],
-            pub hound: [super::Description<&'static str>; #hound_count],
-            pub leech: [super::Description<&'static str>; #leech_count],
-            pub lurk: [super::Description<&'static str>; #lurk_count],
-            pub slide: [super::Description<&'static str>; #slide_count],
-            pub spider: [super::Description<&'static str>; #spider_count],
-            pub whisper: [super::Description<&'static str>; #whisper_count],
+            pub cutter: [ClassItem; #cutter_count],
+            pub hound: [ClassItem; #hound_count],
+            pub leech: [ClassItem; #leech_count],
+            pub lurk: [ClassItem; #lurk_count],
+            pub slide: [ClassItem; #slide_count],
+            pub spider: [ClassItem; #spider_count],
+            pub whisper: [ClassItem; #whisper_count],
         }
 
         pub const CLASS_ITEMS: ClassItems = ClassItems {
-            cutter: [#(
-                        super::Description::new(#cutter)
-                        ),*],
-            hound: [#(
-                        super::Description::new(#hound)
-                        ),*],
-            leech: [#(
-                        super::Description::new(#leech)
-                        ),*],
-            lurk: [#(
-                        super::Description::new(#lurk)
-                        ),*],
-            slide: [#(
-                        super::Description::new(#slide)
-                        ),*],
-            spider: [#(
-                        super::Description::new(#spider)
-                        ),*],
-            whisper: [#(
-                        super::Description::new(#whisper)
-                        ),*],
+            cutter: [#(#cutter),*],
+            hound: [#(#hound),*],
+            leech: [#(#leech),*],
+            lurk: [#(#lurk),*],
+            slide: [#(#slide),*],
+            spider: [#(#spider),*],
+            whisper: [#(#whisper),*],
         };
         });
     }
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct ClassData {
+    xp_trigger: String,
+    starting_dots: std::collections::BTreeMap<String, u8>,
+}
+
+impl ClassData {
+    fn dots(&self) -> proc_macro2::TokenStream {
+        let actions = self
+            .starting_dots
+            .keys()
+            .map(|action| quote::format_ident!("{}", action));
+        let ratings = self.starting_dots.values();
+
+        quote::quote! {
+            crate::Dots {
+                #(#actions: #ratings,)*
+                ..Default::default()
+            }
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Classes {
+    cutter: ClassData,
+    hound: ClassData,
+    leech: ClassData,
+    lurk: ClassData,
+    slide: ClassData,
+    spider: ClassData,
+    whisper: ClassData,
+}
+
+impl quote::ToTokens for Classes {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let cutter_xp = &self.cutter.xp_trigger;
+        let hound_xp = &self.hound.xp_trigger;
+        let leech_xp = &self.leech.xp_trigger;
+        let lurk_xp = &self.lurk.xp_trigger;
+        let slide_xp = &self.slide.xp_trigger;
+        let spider_xp = &self.spider.xp_trigger;
+        let whisper_xp = &self.whisper.xp_trigger;
+
+        let cutter_dots = self.cutter.dots();
+        let hound_dots = self.hound.dots();
+        let leech_dots = self.leech.dots();
+        let lurk_dots = self.lurk.dots();
+        let slide_dots = self.slide.dots();
+        let spider_dots = self.spider.dots();
+        let whisper_dots = self.whisper.dots();
+
+        tokens.extend(quote::quote! {
+        impl Class {
+            /// The playbook's XP trigger, marked at the end of each session.
+            pub const fn xp_trigger(&self) -> &'static str {
+                match self {
+                    Class::Cutter => #cutter_xp,
+                    Class::Hound => #hound_xp,
+                    Class::Leech => #leech_xp,
+                    Class::Lurk => #lurk_xp,
+                    Class::Slide => #slide_xp,
+                    Class::Spider => #spider_xp,
+                    Class::Whisper => #whisper_xp,
+                }
+            }
+
+            /// The action dots a new character of this playbook starts with.
+            pub fn starting_dots(&self) -> crate::Dots {
+                match self {
+                    Class::Cutter => #cutter_dots,
+                    Class::Hound => #hound_dots,
+                    Class::Leech => #leech_dots,
+                    Class::Lurk => #lurk_dots,
+                    Class::Slide => #slide_dots,
+                    Class::Spider => #spider_dots,
+                    Class::Whisper => #whisper_dots,
+                }
+            }
+        }
+        });
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CrewAbility {
+    name: String,
+    description: String,
+}
+
+impl quote::ToTokens for CrewAbility {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let name = &self.name;
+        let description = &self.description;
+
+        tokens.extend(quote::quote! {
+            CrewAbility {
+                name: #name,
+                description: super::Description::new(#description),
+            }
+        });
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CrewUpgrade {
+    name: String,
+    boxes: u8,
+    description: String,
+}
+
+impl quote::ToTokens for CrewUpgrade {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let name = &self.name;
+        let boxes = self.boxes;
+        let description = &self.description;
+
+        tokens.extend(quote::quote! {
+            CrewUpgrade {
+                name: #name,
+                boxes: #boxes,
+                description: super::Description::new(#description),
+            }
+        });
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CrewData {
+    xp_trigger: String,
+    abilities: Vec<CrewAbility>,
+    upgrades: Vec<CrewUpgrade>,
+    starting_upgrades: Vec<String>,
+}
+
+impl CrewData {
+    /// The consts holding this specialty's abilities and upgrades, named after it.
+    fn consts(&self, specialty: &str) -> proc_macro2::TokenStream {
+        let abilities_ident = quote::format_ident!("{}_ABILITIES", specialty);
+        let upgrades_ident = quote::format_ident!("{}_UPGRADES", specialty);
+        let starting_ident = quote::format_ident!("{}_STARTING_UPGRADES", specialty);
+
+        let abilities = &self.abilities;
+        let upgrades = &self.upgrades;
+        let starting = &self.starting_upgrades;
+
+        let abilities_count = abilities.len();
+        let upgrades_count = upgrades.len();
+        let starting_count = starting.len();
+
+        quote::quote! {
+            pub const #abilities_ident: [CrewAbility; #abilities_count] = [#(#abilities),*];
+            pub const #upgrades_ident: [CrewUpgrade; #upgrades_count] = [#(#upgrades),*];
+            pub const #starting_ident: [&str; #starting_count] = [#(#starting),*];
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Crews {
+    upgrades: Vec<CrewUpgrade>,
+    assassins: CrewData,
+    bravos: CrewData,
+    cult: CrewData,
+    hawkers: CrewData,
+    smugglers: CrewData,
+    shadows: CrewData,
+}
+
+impl quote::ToTokens for Crews {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let upgrades = &self.upgrades;
+        let upgrades_count = upgrades.len();
+
+        let assassins = self.assassins.consts("ASSASSINS");
+        let bravos = self.bravos.consts("BRAVOS");
+        let cult = self.cult.consts("CULT");
+        let hawkers = self.hawkers.consts("HAWKERS");
+        let smugglers = self.smugglers.consts("SMUGGLERS");
+        let shadows = self.shadows.consts("SHADOWS");
+
+        let assassins_xp = &self.assassins.xp_trigger;
+        let bravos_xp = &self.bravos.xp_trigger;
+        let cult_xp = &self.cult.xp_trigger;
+        let hawkers_xp = &self.hawkers.xp_trigger;
+        let smugglers_xp = &self.smugglers.xp_trigger;
+        let shadows_xp = &self.shadows.xp_trigger;
+
+        tokens.extend(quote::quote! {
+        pub mod specialty {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            pub struct CrewAbility {
+                pub name: &'static str,
+                pub description: super::Description<&'static str>,
+            }
+
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            pub struct CrewUpgrade {
+                pub name: &'static str,
+                pub boxes: u8,
+                pub description: super::Description<&'static str>,
+            }
+
+            /// Upgrades open to every crew, whatever its specialty.
+            pub const UPGRADES: [CrewUpgrade; #upgrades_count] = [#(#upgrades),*];
+
+            #assassins
+            #bravos
+            #cult
+            #hawkers
+            #smugglers
+            #shadows
+        }
+
+        impl CrewSpecialty {
+            /// The crew's XP trigger, marked after each score.
+            pub const fn xp_trigger(&self) -> &'static str {
+                match self {
+                    CrewSpecialty::Assassins => #assassins_xp,
+                    CrewSpecialty::Bravos => #bravos_xp,
+                    CrewSpecialty::Cult => #cult_xp,
+                    CrewSpecialty::Hawkers => #hawkers_xp,
+                    CrewSpecialty::Smugglers => #smugglers_xp,
+                    CrewSpecialty::Shadows => #shadows_xp,
+                }
+            }
+
+            /// The special abilities a crew of this specialty can take.
+            pub fn abilities(&self) -> &'static [specialty::CrewAbility] {
+                match self {
+                    CrewSpecialty::Assassins => &specialty::ASSASSINS_ABILITIES,
+                    CrewSpecialty::Bravos => &specialty::BRAVOS_ABILITIES,
+                    CrewSpecialty::Cult => &specialty::CULT_ABILITIES,
+                    CrewSpecialty::Hawkers => &specialty::HAWKERS_ABILITIES,
+                    CrewSpecialty::Smugglers => &specialty::SMUGGLERS_ABILITIES,
+                    CrewSpecialty::Shadows => &specialty::SHADOWS_ABILITIES,
+                }
+            }
+
+            /// The upgrades only a crew of this specialty can take.
+            pub fn upgrades(&self) -> &'static [specialty::CrewUpgrade] {
+                match self {
+                    CrewSpecialty::Assassins => &specialty::ASSASSINS_UPGRADES,
+                    CrewSpecialty::Bravos => &specialty::BRAVOS_UPGRADES,
+                    CrewSpecialty::Cult => &specialty::CULT_UPGRADES,
+                    CrewSpecialty::Hawkers => &specialty::HAWKERS_UPGRADES,
+                    CrewSpecialty::Smugglers => &specialty::SMUGGLERS_UPGRADES,
+                    CrewSpecialty::Shadows => &specialty::SHADOWS_UPGRADES,
+                }
+            }
+
+            /// Upgrades a new crew of this specialty starts with.
+            pub fn starting_upgrades(&self) -> &'static [&'static str] {
+                match self {
+                    CrewSpecialty::Assassins => &specialty::ASSASSINS_STARTING_UPGRADES,
+                    CrewSpecialty::Bravos => &specialty::BRAVOS_STARTING_UPGRADES,
+                    CrewSpecialty::Cult => &specialty::CULT_STARTING_UPGRADES,
+                    CrewSpecialty::Hawkers => &specialty::HAWKERS_STARTING_UPGRADES,
+                    CrewSpecialty::Smugglers => &specialty::SMUGGLERS_STARTING_UPGRADES,
+                    CrewSpecialty::Shadows => &specialty::SHADOWS_STARTING_UPGRADES,
+                }
+            }
+        }
+        });
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Claim {
+    name: String,
+    description: String,
+}
+
+impl quote::ToTokens for Claim {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let name = &self.name;
+        let description = &self.description;
+
+        tokens.extend(quote::quote! {
+            Claim {
+                name: #name,
+                description: super::Description::new(#description),
+            }
+        });
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Claims {
+    links: Vec<(u8, u8)>,
+    assassins: Vec<Claim>,
+    bravos: Vec<Claim>,
+    cult: Vec<Claim>,
+    hawkers: Vec<Claim>,
+    smugglers: Vec<Claim>,
+    shadows: Vec<Claim>,
+}
+
+impl quote::ToTokens for Claims {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let links = self.links.iter().map(|(a, b)| quote::quote! { (#a, #b) });
+        let links_count = self.links.len();
+
+        let assassins = &self.assassins;
+        let bravos = &self.bravos;
+        let cult = &self.cult;
+        let hawkers = &self.hawkers;
+        let smugglers = &self.smugglers;
+        let shadows = &self.shadows;
+
+        let claims_count = assassins.len();
+        for claims in [bravos, cult, hawkers, smugglers, shadows] {
+            assert_eq!(
+                claims.len(),
+                claims_count,
+                "Every specialty needs the same claims grid"
+            );
+        }
+
+        tokens.extend(quote::quote! {
+        pub mod claims {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            pub struct Claim {
+                pub name: &'static str,
+                pub description: super::Description<&'static str>,
+            }
+
+            /// Pairs of grid positions joined on every claims map.
+            pub const LINKS: [(u8, u8); #links_count] = [#(#links),*];
+
+            pub const ASSASSINS: [Claim; #claims_count] = [#(#assassins),*];
+            pub const BRAVOS: [Claim; #claims_count] = [#(#bravos),*];
+            pub const CULT: [Claim; #claims_count] = [#(#cult),*];
+            pub const HAWKERS: [Claim; #claims_count] = [#(#hawkers),*];
+            pub const SMUGGLERS: [Claim; #claims_count] = [#(#smugglers),*];
+            pub const SHADOWS: [Claim; #claims_count] = [#(#shadows),*];
+        }
+
+        impl CrewSpecialty {
+            /// The specialty's claims grid, row by row.
+            pub fn claims(&self) -> &'static [claims::Claim] {
+                match self {
+                    CrewSpecialty::Assassins => &claims::ASSASSINS,
+                    CrewSpecialty::Bravos => &claims::BRAVOS,
+                    CrewSpecialty::Cult => &claims::CULT,
+                    CrewSpecialty::Hawkers => &claims::HAWKERS,
+                    CrewSpecialty::Smugglers => &claims::SMUGGLERS,
+                    CrewSpecialty::Shadows => &claims::SHADOWS,
+                }
+            }
+        }
+        });
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Hold {
+    Strong,
+    Weak,
+}
+
+impl quote::ToTokens for Hold {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let string = format!("{self:?}");
+        let ident = quote::format_ident!("{}", string);
+
+        tokens.extend(quote::quote! {
+            crate::Hold::#ident
+        });
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Faction {
+    name: String,
+    category: String,
+    tier: u8,
+    hold: Hold,
+    description: String,
+}
+
+impl quote::ToTokens for Faction {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let name = &self.name;
+        let category = &self.category;
+        let tier = self.tier;
+        let hold = &self.hold;
+        let description = &self.description;
+
+        tokens.extend(quote::quote! {
+            Faction {
+                name: #name,
+                category: #category,
+                tier: #tier,
+                hold: #hold,
+                description: super::Description::new(#description),
+            }
+        });
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct District {
+    name: String,
+    description: String,
+    wealth: u8,
+    security: u8,
+    criminal: u8,
+    occult: u8,
+    landmarks: Vec<String>,
+}
+
+impl quote::ToTokens for District {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let name = &self.name;
+        let description = &self.description;
+        let wealth = self.wealth;
+        let security = self.security;
+        let criminal = self.criminal;
+        let occult = self.occult;
+        let landmarks = &self.landmarks;
+
+        tokens.extend(quote::quote! {
+            District {
+                name: #name,
+                description: super::Description::new(#description),
+                wealth: #wealth,
+                security: #security,
+                criminal: #criminal,
+                occult: #occult,
+                landmarks: &[#(#landmarks),*],
+            }
+        });
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct Contacts {
     cutter: Vec<String>,
@@ -0,0 +1,7 @@
+ALTER TABLE crews DROP COLUMN reputation;
+ALTER TABLE crews DROP COLUMN heat;
+ALTER TABLE crews DROP COLUMN wanted_level;
+ALTER TABLE crews DROP COLUMN tier;
+ALTER TABLE crews DROP COLUMN hold;
+ALTER TABLE crews DROP COLUMN coin;
+ALTER TABLE crews DROP COLUMN vaults;
@@ -0,0 +1,7 @@
+ALTER TABLE crews ADD COLUMN reputation INTEGER CHECK(reputation >= 0 AND reputation <= 12) NOT NULL DEFAULT 0;
+ALTER TABLE crews ADD COLUMN heat INTEGER CHECK(heat >= 0 AND heat <= 9) NOT NULL DEFAULT 0;
+ALTER TABLE crews ADD COLUMN wanted_level INTEGER CHECK(wanted_level >= 0 AND wanted_level <= 4) NOT NULL DEFAULT 0;
+ALTER TABLE crews ADD COLUMN tier INTEGER CHECK(tier >= 0 AND tier <= 4) NOT NULL DEFAULT 0;
+ALTER TABLE crews ADD COLUMN hold TEXT CHECK(hold IN ('Strong', 'Weak')) NOT NULL DEFAULT 'Strong';
+ALTER TABLE crews ADD COLUMN coin INTEGER CHECK(coin >= 0 AND coin <= 16) NOT NULL DEFAULT 2;
+ALTER TABLE crews ADD COLUMN vaults INTEGER CHECK(vaults >= 0 AND vaults <= 2) NOT NULL DEFAULT 0;
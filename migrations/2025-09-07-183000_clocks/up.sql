@@ -0,0 +1,12 @@
+CREATE TABLE clocks (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  crew_id INTEGER,
+  character_id INTEGER,
+  name TEXT NOT NULL,
+  segments INTEGER CHECK(segments IN (4, 6, 8, 12)) NOT NULL,
+  filled INTEGER NOT NULL DEFAULT 0,
+  CHECK(filled >= 0 AND filled <= segments),
+  CHECK((crew_id IS NULL) <> (character_id IS NULL)),
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE,
+  FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE
+);
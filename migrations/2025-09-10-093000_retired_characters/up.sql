@@ -0,0 +1 @@
+ALTER TABLE characters ADD COLUMN retired BOOLEAN NOT NULL DEFAULT FALSE;
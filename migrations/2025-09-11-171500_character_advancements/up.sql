@@ -0,0 +1,7 @@
+CREATE TABLE character_advancements (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  character_id INTEGER NOT NULL,
+  kind TEXT CHECK(kind IN ('Ability', 'Action')) NOT NULL,
+  choice TEXT NOT NULL,
+  FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE
+);
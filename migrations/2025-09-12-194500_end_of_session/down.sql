@@ -0,0 +1,2 @@
+DROP TABLE end_of_session_answers;
+DROP TABLE end_of_sessions;
@@ -0,0 +1,18 @@
+CREATE TABLE end_of_sessions (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  crew_id INTEGER NOT NULL,
+  closed BOOLEAN NOT NULL DEFAULT FALSE,
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
+
+CREATE TABLE end_of_session_answers (
+  end_of_session_id INTEGER NOT NULL,
+  character_id INTEGER NOT NULL,
+  playbook INTEGER CHECK(playbook BETWEEN 0 AND 2) NOT NULL,
+  beliefs INTEGER CHECK(beliefs BETWEEN 0 AND 2) NOT NULL,
+  struggle INTEGER CHECK(struggle BETWEEN 0 AND 2) NOT NULL,
+  track TEXT CHECK(track IN ('Playbook', 'Insight', 'Prowess', 'Resolve')) NOT NULL,
+  PRIMARY KEY (end_of_session_id, character_id),
+  FOREIGN KEY (end_of_session_id) REFERENCES end_of_sessions(id) ON DELETE CASCADE,
+  FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE
+);
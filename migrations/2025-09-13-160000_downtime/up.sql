@@ -0,0 +1 @@
+ALTER TABLE characters ADD COLUMN downtime_activities INTEGER NOT NULL DEFAULT 0;
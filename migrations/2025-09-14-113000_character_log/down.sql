@@ -0,0 +1,2 @@
+DROP TABLE character_log;
+ALTER TABLE characters DROP COLUMN pending_overindulgence;
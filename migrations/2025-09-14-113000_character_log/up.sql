@@ -0,0 +1,8 @@
+ALTER TABLE characters ADD COLUMN pending_overindulgence BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE character_log (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  character_id INTEGER NOT NULL,
+  entry TEXT NOT NULL,
+  FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+DROP TABLE score_characters;
+DROP TABLE scores;
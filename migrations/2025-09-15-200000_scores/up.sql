@@ -0,0 +1,19 @@
+CREATE TABLE scores (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  crew_id INTEGER NOT NULL,
+  target TEXT NOT NULL,
+  plan TEXT CHECK(plan IN ('Assault', 'Deception', 'Stealth', 'Occult', 'Social', 'Transport')) NOT NULL,
+  detail TEXT NOT NULL,
+  status TEXT CHECK(status IN ('Planning', 'Active', 'Completed')) NOT NULL DEFAULT 'Planning',
+  engagement_dice TEXT,
+  engagement_outcome TEXT CHECK(engagement_outcome IN ('Critical', 'Success', 'Partial Success', 'Failure')),
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
+
+CREATE TABLE score_characters (
+  score_id INTEGER NOT NULL,
+  character_id INTEGER NOT NULL,
+  PRIMARY KEY (score_id, character_id),
+  FOREIGN KEY (score_id) REFERENCES scores(id) ON DELETE CASCADE,
+  FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+ALTER TABLE scores ADD COLUMN paid_out BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1 @@
+DROP TABLE stress_log;
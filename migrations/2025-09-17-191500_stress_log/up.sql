@@ -0,0 +1,10 @@
+CREATE TABLE stress_log (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  character_id INTEGER NOT NULL,
+  score_id INTEGER,
+  kind TEXT CHECK(kind IN ('Push', 'Assist', 'Flashback', 'Resistance')) NOT NULL,
+  stress INTEGER NOT NULL,
+  note TEXT NOT NULL DEFAULT '',
+  FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE,
+  FOREIGN KEY (score_id) REFERENCES scores(id) ON DELETE SET NULL
+);
@@ -0,0 +1,2 @@
+DROP TABLE crew_upgrades;
+DROP TABLE crew_abilities;
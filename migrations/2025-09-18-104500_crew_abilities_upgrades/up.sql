@@ -0,0 +1,15 @@
+CREATE TABLE crew_abilities (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  crew_id INTEGER NOT NULL,
+  name TEXT NOT NULL,
+  UNIQUE (crew_id, name),
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
+
+CREATE TABLE crew_upgrades (
+  crew_id INTEGER NOT NULL,
+  name TEXT NOT NULL,
+  boxes INTEGER NOT NULL DEFAULT 1,
+  PRIMARY KEY (crew_id, name),
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
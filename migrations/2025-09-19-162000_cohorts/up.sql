@@ -0,0 +1,13 @@
+CREATE TABLE cohorts (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  crew_id INTEGER NOT NULL,
+  name TEXT NOT NULL,
+  kind TEXT CHECK(kind IN ('Gang', 'Expert')) NOT NULL,
+  -- Comma separated gang types, or the expert's occupation
+  types TEXT NOT NULL,
+  edges TEXT NOT NULL DEFAULT '',
+  flaws TEXT NOT NULL DEFAULT '',
+  harm INTEGER NOT NULL DEFAULT 0,
+  armor BOOLEAN NOT NULL DEFAULT FALSE,
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
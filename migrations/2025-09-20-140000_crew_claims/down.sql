@@ -0,0 +1 @@
+DROP TABLE crew_claims;
@@ -0,0 +1,6 @@
+CREATE TABLE crew_claims (
+  crew_id INTEGER NOT NULL,
+  position INTEGER NOT NULL,
+  PRIMARY KEY (crew_id, position),
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
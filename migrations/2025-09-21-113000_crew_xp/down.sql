@@ -0,0 +1 @@
+ALTER TABLE crews DROP COLUMN xp;
@@ -0,0 +1 @@
+ALTER TABLE crews ADD COLUMN xp INTEGER NOT NULL DEFAULT 0;
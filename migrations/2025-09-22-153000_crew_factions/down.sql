@@ -0,0 +1,3 @@
+ALTER TABLE clocks DROP COLUMN faction;
+
+DROP TABLE crew_factions;
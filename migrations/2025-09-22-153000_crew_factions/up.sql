@@ -0,0 +1,9 @@
+CREATE TABLE crew_factions (
+  crew_id INTEGER NOT NULL,
+  faction INTEGER NOT NULL,
+  status INTEGER NOT NULL DEFAULT 0,
+  PRIMARY KEY (crew_id, faction),
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
+
+ALTER TABLE clocks ADD COLUMN faction INTEGER;
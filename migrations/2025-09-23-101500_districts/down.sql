@@ -0,0 +1,3 @@
+ALTER TABLE crew_claims DROP COLUMN district;
+
+ALTER TABLE scores DROP COLUMN district;
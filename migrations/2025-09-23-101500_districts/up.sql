@@ -0,0 +1,3 @@
+ALTER TABLE scores ADD COLUMN district INTEGER;
+
+ALTER TABLE crew_claims ADD COLUMN district INTEGER;
@@ -0,0 +1,18 @@
+CREATE TABLE character_contacts_old (
+  id INTEGER PRIMARY KEY NOT NULL,
+  character_id INTEGER NOT NULL,
+  name TEXT NOT NULL,
+  friend BOOLEAN NOT NULL,
+  FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE
+);
+
+INSERT INTO character_contacts_old (id, character_id, name, friend)
+SELECT character_contacts.id, character_contacts.character_id, npcs.name, character_contacts.friend
+FROM character_contacts
+JOIN npcs ON npcs.id = character_contacts.npc_id;
+
+DROP TABLE character_contacts;
+
+ALTER TABLE character_contacts_old RENAME TO character_contacts;
+
+DROP TABLE npcs;
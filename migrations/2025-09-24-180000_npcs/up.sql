@@ -0,0 +1,35 @@
+CREATE TABLE npcs (
+  id INTEGER PRIMARY KEY NOT NULL,
+  crew_id INTEGER NOT NULL,
+  name TEXT NOT NULL,
+  description TEXT NOT NULL DEFAULT '',
+  faction INTEGER,
+  district INTEGER,
+  status INTEGER NOT NULL DEFAULT 0,
+  UNIQUE (crew_id, name),
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
+
+INSERT INTO npcs (crew_id, name)
+SELECT DISTINCT characters.crew_id, character_contacts.name
+FROM character_contacts
+JOIN characters ON characters.id = character_contacts.character_id;
+
+CREATE TABLE character_contacts_new (
+  id INTEGER PRIMARY KEY NOT NULL,
+  character_id INTEGER NOT NULL,
+  npc_id INTEGER NOT NULL,
+  friend BOOLEAN NOT NULL,
+  FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE,
+  FOREIGN KEY (npc_id) REFERENCES npcs(id) ON DELETE CASCADE
+);
+
+INSERT INTO character_contacts_new (id, character_id, npc_id, friend)
+SELECT character_contacts.id, character_contacts.character_id, npcs.id, character_contacts.friend
+FROM character_contacts
+JOIN characters ON characters.id = character_contacts.character_id
+JOIN npcs ON npcs.crew_id = characters.crew_id AND npcs.name = character_contacts.name;
+
+DROP TABLE character_contacts;
+
+ALTER TABLE character_contacts_new RENAME TO character_contacts;
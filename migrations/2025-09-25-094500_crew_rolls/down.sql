@@ -0,0 +1,24 @@
+CREATE TABLE rolls_old (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  crew_id INTEGER NOT NULL,
+  character_id INTEGER NOT NULL,
+  kind TEXT CHECK(kind IN ('Action', 'Resistance', 'Fortune', 'Engagement')) NOT NULL,
+  label TEXT NOT NULL,
+  dice TEXT NOT NULL,
+  zero_dice BOOLEAN NOT NULL DEFAULT FALSE,
+  outcome TEXT CHECK(outcome IN ('Critical', 'Success', 'Partial Success', 'Failure')) NOT NULL,
+  stress INTEGER,
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE,
+  FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE
+);
+
+INSERT INTO rolls_old (id, crew_id, character_id, kind, label, dice, zero_dice, outcome, stress)
+SELECT id, crew_id, character_id, kind, label, dice, zero_dice, outcome, stress
+FROM rolls
+WHERE character_id IS NOT NULL;
+
+DROP TABLE rolls;
+
+ALTER TABLE rolls_old RENAME TO rolls;
+
+CREATE INDEX rolls_crew_id ON rolls(crew_id, id);
@@ -0,0 +1 @@
+ALTER TABLE characters ADD COLUMN downtime_trained INTEGER NOT NULL DEFAULT 0;
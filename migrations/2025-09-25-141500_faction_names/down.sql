@@ -0,0 +1,88 @@
+CREATE TEMP TABLE faction_names (
+  id INTEGER PRIMARY KEY NOT NULL,
+  name TEXT NOT NULL
+);
+
+INSERT INTO faction_names (id, name) VALUES
+  (0, 'The Billhooks'),
+  (1, 'The Circle of Flame'),
+  (2, 'The Crows'),
+  (3, 'The Dimmer Sisters'),
+  (4, 'The Fog Hounds'),
+  (5, 'The Gray Cloaks'),
+  (6, 'The Grinders'),
+  (7, 'The Hive'),
+  (8, 'The Lampblacks'),
+  (9, 'Lord Scurlock'),
+  (10, 'The Red Sashes'),
+  (11, 'The Silver Nails'),
+  (12, 'Ulf Ironborn'),
+  (13, 'The Unseen'),
+  (14, 'The Wraiths'),
+  (15, 'The Bluecoats'),
+  (16, 'The Brigade'),
+  (17, 'The Church of Ecstasy'),
+  (18, 'The City Council'),
+  (19, 'The Foundation'),
+  (20, 'The Imperial Military'),
+  (21, 'The Inspectors'),
+  (22, 'Ironhook Prison'),
+  (23, 'The Leviathan Hunters'),
+  (24, 'The Ministry of Preservation'),
+  (25, 'The Sparkwrights'),
+  (26, 'The Spirit Wardens'),
+  (27, 'The Cabbies'),
+  (28, 'The Cyphers'),
+  (29, 'The Dockers'),
+  (30, 'The Gondoliers'),
+  (31, 'The Ink Rakes'),
+  (32, 'The Rail Jacks'),
+  (33, 'The Sailors'),
+  (34, 'The Forgotten Gods'),
+  (35, 'The Horde'),
+  (36, 'The Path of Echoes'),
+  (37, 'The Reconciled'),
+  (38, 'Skovlander Refugees'),
+  (39, 'The Weeping Lady'),
+  (40, 'Barrowcleft'),
+  (41, 'Brightstone'),
+  (42, 'Charhollow'),
+  (43, 'Coalridge'),
+  (44, 'Crow''s Foot'),
+  (45, 'Dunslough'),
+  (46, 'Nightmarket'),
+  (47, 'Silkshore'),
+  (48, 'Six Towers'),
+  (49, 'Whitecrown');
+
+ALTER TABLE npcs ADD COLUMN faction_id INTEGER;
+UPDATE npcs SET faction_id = (SELECT id FROM faction_names WHERE name = npcs.faction);
+ALTER TABLE npcs DROP COLUMN faction;
+ALTER TABLE npcs RENAME COLUMN faction_id TO faction;
+
+DELETE FROM clocks
+WHERE faction IS NOT NULL AND faction NOT IN (SELECT name FROM faction_names);
+
+ALTER TABLE clocks ADD COLUMN faction_id INTEGER;
+UPDATE clocks SET faction_id = (SELECT id FROM faction_names WHERE name = clocks.faction);
+ALTER TABLE clocks DROP COLUMN faction;
+ALTER TABLE clocks RENAME COLUMN faction_id TO faction;
+
+CREATE TABLE crew_factions_old (
+  crew_id INTEGER NOT NULL,
+  faction INTEGER NOT NULL,
+  status INTEGER NOT NULL DEFAULT 0,
+  PRIMARY KEY (crew_id, faction),
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
+
+INSERT INTO crew_factions_old (crew_id, faction, status)
+SELECT crew_factions.crew_id, faction_names.id, crew_factions.status
+FROM crew_factions
+JOIN faction_names ON faction_names.name = crew_factions.faction;
+
+DROP TABLE crew_factions;
+
+ALTER TABLE crew_factions_old RENAME TO crew_factions;
+
+DROP TABLE faction_names;
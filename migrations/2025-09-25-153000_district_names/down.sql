@@ -0,0 +1,35 @@
+CREATE TEMP TABLE district_names (
+  id INTEGER PRIMARY KEY NOT NULL,
+  name TEXT NOT NULL
+);
+
+INSERT INTO district_names (id, name) VALUES
+  (0, 'Barrowcleft'),
+  (1, 'Brightstone'),
+  (2, 'Charhollow'),
+  (3, 'Charterhall'),
+  (4, 'Coalridge'),
+  (5, 'Crow''s Foot'),
+  (6, 'The Docks'),
+  (7, 'Dunslough'),
+  (8, 'Nightmarket'),
+  (9, 'Silkshore'),
+  (10, 'Six Towers'),
+  (11, 'Whitecrown');
+
+ALTER TABLE npcs ADD COLUMN district_id INTEGER;
+UPDATE npcs SET district_id = (SELECT id FROM district_names WHERE name = npcs.district);
+ALTER TABLE npcs DROP COLUMN district;
+ALTER TABLE npcs RENAME COLUMN district_id TO district;
+
+ALTER TABLE crew_claims ADD COLUMN district_id INTEGER;
+UPDATE crew_claims SET district_id = (SELECT id FROM district_names WHERE name = crew_claims.district);
+ALTER TABLE crew_claims DROP COLUMN district;
+ALTER TABLE crew_claims RENAME COLUMN district_id TO district;
+
+ALTER TABLE scores ADD COLUMN district_id INTEGER;
+UPDATE scores SET district_id = (SELECT id FROM district_names WHERE name = scores.district);
+ALTER TABLE scores DROP COLUMN district;
+ALTER TABLE scores RENAME COLUMN district_id TO district;
+
+DROP TABLE district_names;
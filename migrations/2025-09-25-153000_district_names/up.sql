@@ -0,0 +1,35 @@
+CREATE TEMP TABLE district_names (
+  id INTEGER PRIMARY KEY NOT NULL,
+  name TEXT NOT NULL
+);
+
+INSERT INTO district_names (id, name) VALUES
+  (0, 'Barrowcleft'),
+  (1, 'Brightstone'),
+  (2, 'Charhollow'),
+  (3, 'Charterhall'),
+  (4, 'Coalridge'),
+  (5, 'Crow''s Foot'),
+  (6, 'The Docks'),
+  (7, 'Dunslough'),
+  (8, 'Nightmarket'),
+  (9, 'Silkshore'),
+  (10, 'Six Towers'),
+  (11, 'Whitecrown');
+
+ALTER TABLE scores ADD COLUMN district_name TEXT;
+UPDATE scores SET district_name = (SELECT name FROM district_names WHERE id = scores.district);
+ALTER TABLE scores DROP COLUMN district;
+ALTER TABLE scores RENAME COLUMN district_name TO district;
+
+ALTER TABLE crew_claims ADD COLUMN district_name TEXT;
+UPDATE crew_claims SET district_name = (SELECT name FROM district_names WHERE id = crew_claims.district);
+ALTER TABLE crew_claims DROP COLUMN district;
+ALTER TABLE crew_claims RENAME COLUMN district_name TO district;
+
+ALTER TABLE npcs ADD COLUMN district_name TEXT;
+UPDATE npcs SET district_name = (SELECT name FROM district_names WHERE id = npcs.district);
+ALTER TABLE npcs DROP COLUMN district;
+ALTER TABLE npcs RENAME COLUMN district_name TO district;
+
+DROP TABLE district_names;
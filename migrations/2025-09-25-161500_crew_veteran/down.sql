@@ -0,0 +1,16 @@
+DROP INDEX crew_abilities_once;
+
+CREATE TABLE crew_abilities_new (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  crew_id INTEGER NOT NULL,
+  name TEXT NOT NULL,
+  UNIQUE (crew_id, name),
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
+
+INSERT OR IGNORE INTO crew_abilities_new (id, crew_id, name)
+SELECT id, crew_id, name FROM crew_abilities ORDER BY id;
+
+DROP TABLE crew_abilities;
+
+ALTER TABLE crew_abilities_new RENAME TO crew_abilities;
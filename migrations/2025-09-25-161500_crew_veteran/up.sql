@@ -0,0 +1,15 @@
+CREATE TABLE crew_abilities_new (
+  id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  crew_id INTEGER NOT NULL,
+  name TEXT NOT NULL,
+  FOREIGN KEY (crew_id) REFERENCES crews(id) ON DELETE CASCADE
+);
+
+INSERT INTO crew_abilities_new (id, crew_id, name)
+SELECT id, crew_id, name FROM crew_abilities;
+
+DROP TABLE crew_abilities;
+
+ALTER TABLE crew_abilities_new RENAME TO crew_abilities;
+
+CREATE UNIQUE INDEX crew_abilities_once ON crew_abilities (crew_id, name) WHERE name <> 'Veteran';
@@ -9,6 +9,7 @@ use crate::elements::ErrorMessage;
 pub fn Login(register: NavigationTarget, on_login: EventHandler) -> Element {
     let mut username = use_signal(String::default);
     let mut totp = use_signal(String::default);
+    let mut device = use_signal(String::default);
 
     let mut error = use_signal(|| None as Option<String>);
 
@@ -20,7 +21,8 @@ pub fn Login(register: NavigationTarget, on_login: EventHandler) -> Element {
                 class: "flex flex-col gap-4 container h-fit p-4 border border-border rounded",
                 onsubmit: move |e| async move {
                     e.prevent_default();
-                    let user = api::login(username(), totp()).await;
+                    let device = Some(device()).filter(|d| !d.trim().is_empty());
+                    let user = api::login(username(), totp(), device).await;
                     if let Err(_err) = user {
                         #[cfg(debug_assertions)]
                         {
@@ -49,6 +51,14 @@ pub fn Login(register: NavigationTarget, on_login: EventHandler) -> Element {
                     onchange: move |e| totp.set(e.value()),
                 }
 
+                input {
+                    r#type: "text",
+                    class: "bg-input p-2 rounded",
+                    placeholder: "Device name (optional)",
+                    value: "{device}",
+                    onchange: move |e| device.set(e.value()),
+                }
+
                 if let Some(error) = error() {
                     ErrorMessage { "{error}" }
                 }
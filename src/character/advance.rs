@@ -0,0 +1,143 @@
+use dioxus::prelude::*;
+use types::{Action, Advance, Attribute};
+
+use crate::elements::{Description, Dialog, ErrorMessage};
+
+#[component]
+pub fn AdvanceDialog(open: Signal<bool>, character: Signal<types::Character>) -> Element {
+    let mut choice = use_signal(|| None as Option<Advance>);
+    let mut error = use_signal(|| None as Option<String>);
+
+    let mut history = use_resource(move || async move {
+        let id = character.peek().id;
+        api::character::get_advancements(id).await
+    });
+
+    let abilities = use_memo(move || {
+        let character = character();
+        let mut abilities = types::playbook::PLAYBOOK
+            .iter()
+            .filter(|a| !character.has_ability(a.name))
+            .cloned()
+            .collect::<Vec<_>>();
+        // Playbook abilities first, veteran abilities after
+        abilities.sort_by_key(|a| a.class != character.class);
+        abilities
+    });
+
+    let mut reset = move || {
+        choice.set(None);
+        error.set(None);
+    };
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_36rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    let Some(advance) = choice() else {
+                        error.set(Some("Choose an advance".into()));
+                        return;
+                    };
+
+                    match api::character::advance(character().id, advance).await {
+                        Ok(c) => {
+                            character.set(c);
+                            history.restart();
+                            reset();
+                            open.set(false);
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to advance character: {e:?}");
+                            error.set(Some("Failed to advance character".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Advance" }
+
+                div { class: "flex flex-col gap-2 max-h-[60vh] overflow-y-auto",
+                    if character().can_advance_playbook() {
+                        h3 { class: "text-lg underline", "Special Ability" }
+                        for ability in abilities() {
+                            {
+                                let advance = Advance::Ability(ability.name.to_string());
+                                let veteran = ability.class != character().class;
+                                rsx! {
+                                    button {
+                                        class: if choice() == Some(advance.clone()) { "bg-primary text-primary-foreground p-2 text-left rounded cursor-pointer" } else { "bg-background hover:bg-input p-2 text-left rounded cursor-pointer" },
+                                        onclick: move |e| {
+                                            e.prevent_default();
+                                            choice.set(Some(advance.clone()));
+                                        },
+                                        h4 { class: "font-bold",
+                                            "{ability.name}"
+                                            if veteran {
+                                                span { class: "text-sm italic font-normal", " (Veteran, {ability.class})" }
+                                            }
+                                        }
+                                        Description { desc: ability.description }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    for attribute in Attribute::ALL {
+                        if character().can_advance_attribute(attribute) {
+                            h3 { class: "text-lg underline", "{attribute}" }
+                            div { class: "flex flex-row flex-wrap gap-2",
+                                for action in Action::ALL.into_iter().filter(|a| a.attribute() == attribute) {
+                                    if character().dots.rating(action) < types::Character::MAX_ACTION_RATING {
+                                        button {
+                                            class: if choice() == Some(Advance::Action(action)) { "px-2 py-1 rounded-lg bg-primary text-primary-foreground cursor-pointer" } else { "px-2 py-1 rounded-lg bg-input cursor-pointer" },
+                                            onclick: move |e| {
+                                                e.prevent_default();
+                                                choice.set(Some(Advance::Action(action)));
+                                            },
+                                            "{action} ({character().dots.rating(action)})"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(Ok(history)) = history() {
+                    if !history.is_empty() {
+                        div { class: "flex flex-col gap-1 text-sm text-muted-foreground",
+                            span { class: "underline", "Previous advances" }
+                            for advance in history {
+                                span { "{advance}" }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            reset();
+                            open.set(false);
+                        },
+                        "Cancel"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Advance"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use dioxus::prelude::*;
-use types::{Class, Description as DescriptionT};
 
+use super::log::CharacterLog;
+use super::RuleError;
 use crate::{
+    clocks::Clocks,
     common::ItemChecked,
     elements::{Description, Dialog},
 };
@@ -42,6 +44,10 @@ pub fn Center(character: Signal<types::Character>, readonly: ReadOnlySignal<bool
                 SlyFriends { character, readonly }
                 ClassItems { character, readonly }
             }
+            hr { class: "my-2" }
+            Clocks { owner: types::ClockOwner::Character(character().id), readonly }
+            hr { class: "my-2" }
+            CharacterLog { character }
         }
     }
 }
@@ -161,19 +167,22 @@ fn SlyFriends(character: Signal<types::Character>, readonly: ReadOnlySignal<bool
     let friends = use_memo(move || sly_friends().friends);
     let rivals = use_memo(move || sly_friends().rivals);
 
-    let contacts = use_memo(move || {
-        use types::contacts::CONTACTS;
-        let contacts: &[&'static str] = match character().class {
-            Class::Cutter => &CONTACTS.cutter,
-            Class::Hound => &CONTACTS.hound,
-            Class::Leech => &CONTACTS.leech,
-            Class::Lurk => &CONTACTS.lurk,
-            Class::Slide => &CONTACTS.slide,
-            Class::Spider => &CONTACTS.spider,
-            Class::Whisper => &CONTACTS.whisper,
-        };
-        contacts
+    let contacts = use_memo(move || character().class.contacts());
+
+    // Contacts share the crew's NPC registry, so show what the crew knows about them
+    let npcs = use_resource(move || async move {
+        api::crew::get_npcs(character().crew_id)
+            .await
+            .unwrap_or_default()
     });
+    let describe = move |name: &str| {
+        npcs()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|n| n.name == name)
+            .map(|n| n.description)
+            .unwrap_or_default()
+    };
 
     rsx! {
         div {
@@ -272,7 +281,7 @@ fn SlyFriends(character: Signal<types::Character>, readonly: ReadOnlySignal<bool
                             });
                         },
                     }
-                    span { "{contact}" }
+                    span { title: describe(contact), "{contact}" }
                 }
             }
         }
@@ -350,55 +359,88 @@ fn Triangle(flip: bool, fill: bool, onclick: EventHandler, readonly: Option<bool
 
 #[component]
 fn ClassItems(character: Signal<types::Character>, readonly: ReadOnlySignal<bool>) -> Element {
-    let items = use_memo(move || {
-        use types::items::CLASS_ITEMS;
-        let items: &[DescriptionT<&'static str>] = match character().class {
-            Class::Cutter => &CLASS_ITEMS.cutter,
-            Class::Hound => &CLASS_ITEMS.hound,
-            Class::Leech => &CLASS_ITEMS.leech,
-            Class::Lurk => &CLASS_ITEMS.lurk,
-            Class::Slide => &CLASS_ITEMS.slide,
-            Class::Spider => &CLASS_ITEMS.spider,
-            Class::Whisper => &CLASS_ITEMS.whisper,
-        };
-        items
-    });
+    let items = use_memo(move || character().class.items());
+
+    let rule_errors: Signal<types::ValidationErrors> = use_context();
+    let accepted: super::Accepted = use_context();
 
     rsx! {
         div {
             h3 { class: "text-lg underline", "Class Items" }
+            RuleError { field: "class_items" }
             for item in items() {
                 div { class: "flex flex-row gap-2 items-center",
-                    ItemChecked {
-                        readonly,
-                        checked: character().class_items.contains(&item.to_string()),
-                        onclick: move |has| {
-                            spawn(async move {
-                                let _res = if has {
-                                    api::character::add_class_item(character().id, item.to_string()).await
-                                } else {
-                                    api::character::remove_class_item(character().id, item.to_string()).await
-                                };
-                                #[cfg(debug_assertions)]
-                                {
-                                    if let Err(e) = &_res {
-                                        tracing::error!("Failed to modify class item: {e}");
-                                    }
-                                }
-                            });
-                            character
-                                .with_mut(|char| {
-                                    if has {
-                                        char.class_items.push(item.to_string());
-                                    } else {
-                                        char.class_items.retain(|i| i != item);
-                                    }
-                                });
-                        },
+                    div { class: "flex flex-row gap-1 items-center",
+                        // Items in italics take no load, but still need ticking
+                        for _ in 0..item.load.max(1) {
+                            ItemChecked {
+                                readonly,
+                                checked: character().class_items.contains(&item.name.to_string()),
+                                onclick: move |has| {
+                                    toggle_class_item(character, rule_errors, accepted, item.name.to_string(), has)
+                                },
+                            }
+                        }
                     }
-                    Description { desc: item.clone() }
+                    Description { desc: item.name.clone() }
                 }
             }
         }
     }
 }
+
+fn toggle_class_item(
+    mut character: Signal<types::Character>,
+    mut rule_errors: Signal<types::ValidationErrors>,
+    mut accepted: super::Accepted,
+    name: String,
+    has: bool,
+) {
+    let id = character.peek().id;
+
+    if has {
+        let mut class_items = character.peek().class_items.clone();
+        class_items.push(name.clone());
+        if let Err(errors) = character
+            .peek()
+            .validate_change(&types::CharacterChange::ClassItems(class_items))
+        {
+            rule_errors.set(errors);
+            return;
+        }
+    }
+
+    character.with_mut(|char| {
+        if has {
+            char.class_items.push(name.clone());
+        } else {
+            char.class_items.retain(|i| *i != name);
+        }
+    });
+
+    let change = types::CharacterChange::ClassItems(character.peek().class_items.clone());
+
+    spawn(async move {
+        if has {
+            let res = api::character::add_class_item(id, name).await;
+            super::report_rules(rule_errors, accepted, character, &change, &res);
+            #[cfg(debug_assertions)]
+            {
+                if let Err(e) = &res {
+                    tracing::error!("Failed to add class item: {e}");
+                }
+            }
+        } else {
+            let res = api::character::remove_class_item(id, name).await;
+            if res.is_ok() {
+                accepted.record(&change);
+            }
+            #[cfg(debug_assertions)]
+            {
+                if let Err(e) = &res {
+                    tracing::error!("Failed to remove class item: {e}");
+                }
+            }
+        }
+    });
+}
@@ -1,64 +1,112 @@
 use dioxus::prelude::*;
+use types::{Action, Background, Class, Heritage, NewCharacter, Vice};
 
-use crate::elements::Dialog;
+use crate::{
+    common::CountBtn,
+    elements::{Description, Dialog, ErrorMessage},
+};
+
+const STEPS: [&str; 6] = [
+    "Playbook", "Traits", "Actions", "Ability", "Contacts", "Look",
+];
+
+/// Checks only what the given step asks for, so the user can't move past it incomplete.
+fn step_error(draft: &NewCharacter, step: usize) -> Option<String> {
+    match step {
+        0 if draft.name.trim().is_empty() => Some("Name is required".to_string()),
+        2 => draft.validate_dots().err(),
+        3 if draft.ability.is_empty() => Some("Choose a special ability".to_string()),
+        4 if draft.friend.is_empty() || draft.rival.is_empty() => {
+            Some("Choose a close friend and a rival".to_string())
+        }
+        4 if draft.friend == draft.rival => {
+            Some("Your close friend cannot also be your rival".to_string())
+        }
+        _ => None,
+    }
+}
 
 #[component]
 pub fn CreateCharacter(
     crew_id: types::CrewId,
-    on_create: EventHandler<api::NewCharacter>,
+    on_create: EventHandler<types::Character>,
     open: Signal<bool>,
 ) -> Element {
-    let mut name = use_signal(String::new);
-    let mut class = use_signal(|| types::Class::Cutter);
-
-    let currentUser = use_context::<crate::Auth>();
-    let currentUser = use_memo(move || {
-        currentUser
-            .username()
-            .expect("User should be authenticated")
-    });
+    let mut draft = use_signal(|| NewCharacter::new(crew_id, Class::Cutter));
+    let mut step = use_signal(|| 0usize);
+    let mut error = use_signal(|| None as Option<String>);
+
+    let last = STEPS.len() - 1;
 
     rsx! {
         Dialog { open, close_on_click: true,
             form {
+                class: "flex flex-col gap-4 w-[min(90vw,_36rem)]",
                 onsubmit: move |e| async move {
                     e.prevent_default();
-                    if name().is_empty() {
+
+                    if let Some(e) = step_error(&draft(), step()) {
+                        error.set(Some(e));
+                        return;
+                    }
+
+                    if step() < last {
+                        error.set(None);
+                        step += 1;
                         return;
                     }
-                    let char = api::NewCharacter {
-                        crew_id,
-                        user_id: currentUser(),
-                        name: name(),
-                        class: class(),
-                    };
-                    open.set(false);
-                    on_create.call(char);
+
+                    if let Err(e) = draft().validate() {
+                        error.set(Some(e));
+                        return;
+                    }
+
+                    match api::character::create(draft()).await {
+                        Ok(character) => {
+                            draft.set(NewCharacter::new(crew_id, Class::Cutter));
+                            step.set(0);
+                            error.set(None);
+                            open.set(false);
+                            on_create.call(character);
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to create character: {e:?}");
+                            error.set(Some("Failed to create character".into()));
+                        }
+                    }
                 },
-                class: "flex flex-col gap-4",
-                input {
-                    class: "bg-input p-2 rounded text-input-foreground",
-                    placeholder: "Character Name",
-                    onchange: move |e| {
-                        name.set(e.value().clone());
-                    },
-                }
-                select {
-                    class: "p-2",
-                    onchange: move |e| {
-                        if let Ok(c) = std::convert::TryInto::<
-                            types::Class,
-                        >::try_into(e.value().as_str()) {
-                            class.set(c);
+                div { class: "flex flex-row gap-2 text-sm text-muted-foreground",
+                    for (i , name) in STEPS.iter().enumerate() {
+                        span { class: if i == step() { "font-bold text-foreground" } else { "" },
+                            "{name}"
                         }
+                    }
+                }
+
+                match step() {
+                    0 => rsx! {
+                        PlaybookStep { draft }
+                    },
+                    1 => rsx! {
+                        TraitsStep { draft }
+                    },
+                    2 => rsx! {
+                        ActionsStep { draft }
+                    },
+                    3 => rsx! {
+                        AbilityStep { draft }
+                    },
+                    4 => rsx! {
+                        ContactsStep { draft }
+                    },
+                    _ => rsx! {
+                        LookStep { draft }
                     },
-                    option { value: "cutter", "Cutter" }
-                    option { value: "hound", "Hound" }
-                    option { value: "leech", "Leech" }
-                    option { value: "lurk", "Lurk" }
-                    option { value: "slide", "Slide" }
-                    option { value: "spider", "Spider" }
-                    option { value: "whisper", "Whisper" }
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
                 }
 
                 div { class: "flex justify-between items-center gap-4",
@@ -66,15 +114,179 @@ pub fn CreateCharacter(
                         class: "bg-secondary text-secondary-foreground px-4 py-2 rounded",
                         onclick: move |e| {
                             e.prevent_default();
-                            open.set(false);
+                            error.set(None);
+                            if step() == 0 {
+                                open.set(false);
+                            } else {
+                                step -= 1;
+                            }
                         },
-                        "Cancel"
+                        if step() == 0 {
+                            "Cancel"
+                        } else {
+                            "Back"
+                        }
                     }
                     button { class: "bg-primary text-primary-foreground px-4 py-2 rounded",
-                        "Create Character"
+                        if step() == last {
+                            "Create Character"
+                        } else {
+                            "Next"
+                        }
                     }
                 }
             }
         }
     }
 }
+
+#[component]
+fn PlaybookStep(draft: Signal<NewCharacter>) -> Element {
+    rsx! {
+        input {
+            class: "bg-input p-2 rounded text-input-foreground",
+            placeholder: "Character Name",
+            value: "{draft().name}",
+            oninput: move |e| draft.with_mut(|d| d.name = e.value()),
+        }
+        select {
+            class: "p-2",
+            onchange: move |e| {
+                if let Ok(c) = Class::try_from(e.value().as_str()) {
+                    draft.with_mut(|d| d.set_class(c));
+                }
+            },
+            for c in Class::ALL {
+                option { value: "{c}", selected: c == draft().class, "{c}" }
+            }
+        }
+        p { class: "italic", "{draft().class.xp_trigger()}" }
+    }
+}
+
+#[component]
+fn TraitsStep(draft: Signal<NewCharacter>) -> Element {
+    rsx! {
+        div { class: "grid grid-cols-2 gap-2 items-center",
+            span { "Heritage" }
+            select {
+                onchange: move |e| draft.with_mut(|d| d.heritage = Heritage::from(e.value())),
+                for h in Heritage::ALL {
+                    option { value: "{h}", selected: h == draft().heritage, "{h}" }
+                }
+            }
+            span { "Background" }
+            select {
+                onchange: move |e| draft.with_mut(|d| d.background = Background::from(e.value())),
+                for b in Background::ALL {
+                    option { value: "{b}", selected: b == draft().background, "{b}" }
+                }
+            }
+            span { "Vice" }
+            select {
+                onchange: move |e| draft.with_mut(|d| d.vice = Vice::from(e.value())),
+                for v in Vice::ALL {
+                    option { value: "{v}", selected: v == draft().vice, "{v}" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ActionsStep(draft: Signal<NewCharacter>) -> Element {
+    let starting = use_memo(move || draft().class.starting_dots());
+    let free = NewCharacter::FREE_DOTS;
+    let max = NewCharacter::MAX_STARTING_RATING;
+
+    rsx! {
+        p { class: "italic",
+            "Assign {free} more dots, no action above {max}. {draft().free_dots_remaining()} left."
+        }
+        div { class: "grid grid-cols-2 gap-x-8 gap-y-1",
+            for action in Action::ALL {
+                div { class: "flex flex-row justify-between items-center gap-2",
+                    span { "{action}" }
+                    div { class: "flex flex-row gap-1",
+                        for i in 1..=max {
+                            CountBtn {
+                                this: i,
+                                total: draft().dots.rating(action),
+                                set: move |v: u8| {
+                                    let v = v.max(starting().rating(action));
+                                    draft.with_mut(|d| *d.dots.rating_mut(action) = v);
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AbilityStep(draft: Signal<NewCharacter>) -> Element {
+    let abilities = use_memo(move || {
+        let class = draft().class;
+        types::playbook::PLAYBOOK
+            .iter()
+            .filter(|a| a.class == class)
+            .cloned()
+            .collect::<Vec<_>>()
+    });
+
+    rsx! {
+        div { class: "flex flex-col gap-2 max-h-[60vh] overflow-y-auto",
+            for ability in abilities() {
+                button {
+                    class: if draft().ability == ability.name { "bg-primary text-primary-foreground p-2 text-left rounded cursor-pointer" } else { "bg-background hover:bg-input p-2 text-left rounded cursor-pointer" },
+                    onclick: move |e| {
+                        e.prevent_default();
+                        draft.with_mut(|d| d.ability = ability.name.to_string());
+                    },
+                    h3 { class: "text-lg underline", "{ability.name}" }
+                    Description { desc: ability.description }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ContactsStep(draft: Signal<NewCharacter>) -> Element {
+    rsx! {
+        div { class: "grid grid-cols-[1fr_auto_auto] gap-2 items-center",
+            span {}
+            span { class: "text-sm", "Friend" }
+            span { class: "text-sm", "Rival" }
+            for contact in draft().class.contacts().iter().copied() {
+                span { "{contact}" }
+                input {
+                    r#type: "radio",
+                    name: "friend",
+                    checked: draft().friend == contact,
+                    onchange: move |_| draft.with_mut(|d| d.friend = contact.to_string()),
+                }
+                input {
+                    r#type: "radio",
+                    name: "rival",
+                    checked: draft().rival == contact,
+                    onchange: move |_| draft.with_mut(|d| d.rival = contact.to_string()),
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn LookStep(draft: Signal<NewCharacter>) -> Element {
+    rsx! {
+        textarea {
+            class: "w-full h-32 p-2 border border-border rounded",
+            placeholder: "Look",
+            value: "{draft().look}",
+            oninput: move |e| draft.with_mut(|d| d.look = e.value()),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use dioxus::prelude::*;
+use types::{Action, DowntimeActivity, DowntimeCost, DowntimeResult, Healer, XpTrack};
+
+use super::roll::RollResultView;
+use crate::elements::{Dialog, ErrorMessage};
+
+const ACTIVITIES: [&str; 6] = [
+    "Acquire Asset",
+    "Long-Term Project",
+    "Recover",
+    "Reduce Heat",
+    "Train",
+    "Indulge Vice",
+];
+
+#[component]
+pub fn DowntimeDialog(open: Signal<bool>, character: Signal<types::Character>) -> Element {
+    let mut kind = use_signal(|| 0usize);
+    let mut coin = use_signal(|| 0u8);
+    let mut clock_id = use_signal(|| None as Option<types::ClockId>);
+    let action = use_signal(|| Action::Hunt);
+    let mut healer = use_signal(|| Healer::Contact { quality: 1 });
+    let mut track = use_signal(|| XpTrack::Playbook);
+    let mut extra = use_signal(|| DowntimeCost::Coin);
+
+    let mut result = use_signal(|| None as Option<DowntimeResult>);
+    let mut error = use_signal(|| None as Option<String>);
+
+    let projects = use_resource(move || async move {
+        let (id, crew_id) = {
+            let character = character.peek();
+            (character.id, character.crew_id)
+        };
+        let mut clocks = api::clock::get_clocks(types::ClockOwner::Character(id))
+            .await
+            .unwrap_or_default();
+        clocks.extend(
+            api::clock::get_clocks(types::ClockOwner::Crew(crew_id))
+                .await
+                .unwrap_or_default(),
+        );
+        clocks
+    });
+
+    let healers = use_resource(move || async move {
+        let crew_id = character.peek().crew_id;
+        api::crew::get_crew_characters(crew_id)
+            .await
+            .unwrap_or_default()
+    });
+
+    let activity = use_memo(move || match kind() {
+        0 => Some(DowntimeActivity::AcquireAsset { coin: coin() }),
+        1 => clock_id().map(|clock_id| DowntimeActivity::LongTermProject {
+            clock_id,
+            action: action(),
+        }),
+        2 => Some(DowntimeActivity::Recover { healer: healer() }),
+        3 => Some(DowntimeActivity::ReduceHeat { action: action() }),
+        4 => Some(DowntimeActivity::Train { track: track() }),
+        _ => Some(DowntimeActivity::IndulgeVice),
+    });
+
+    let needs_payment = use_memo(move || {
+        character().downtime_activities >= types::Character::FREE_DOWNTIME_ACTIVITIES
+    });
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_30rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    let Some(activity) = activity() else {
+                        error.set(Some("Choose a project clock".into()));
+                        return;
+                    };
+
+                    let id = character().id;
+                    let extra = needs_payment().then_some(extra());
+
+                    match api::downtime::take_activity(id, activity, extra).await {
+                        Ok(r) => {
+                            result.set(Some(r));
+                            error.set(None);
+                            match api::character::get(id).await {
+                                Ok(c) => character.set(c),
+                                Err(e) => tracing::error!("Failed to reload character: {e}"),
+                            }
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to take downtime activity: {e:?}");
+                            error.set(Some("Failed to take downtime activity".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Downtime" }
+                p { class: "italic text-muted-foreground",
+                    "{character().downtime_activities} activities taken, {types::Character::FREE_DOWNTIME_ACTIVITIES} free"
+                }
+                select {
+                    onchange: move |e| {
+                        if let Some(i) = ACTIVITIES.iter().position(|a| *a == e.value()) {
+                            kind.set(i);
+                            result.set(None);
+                        }
+                    },
+                    for (i , name) in ACTIVITIES.iter().enumerate() {
+                        option { value: "{name}", selected: i == kind(), "{name}" }
+                    }
+                }
+
+                match kind() {
+                    0 => rsx! {
+                        label { class: "flex flex-row gap-2 items-center",
+                            span { "Extra coin for quality" }
+                            input {
+                                class: "p-1 rounded-lg border border-border w-16",
+                                r#type: "number",
+                                min: "0",
+                                value: "{coin}",
+                                oninput: move |e| {
+                                    if let Ok(c) = e.value().parse() {
+                                        coin.set(c);
+                                    }
+                                },
+                            }
+                        }
+                    },
+                    1 => rsx! {
+                        select {
+                            onchange: move |e| clock_id.set(e.value().parse().ok()),
+                            option { value: "", selected: clock_id().is_none(), "Choose a clock" }
+                            for clock in projects().unwrap_or_default() {
+                                option {
+                                    value: "{clock.id}",
+                                    selected: clock_id() == Some(clock.id),
+                                    "{clock.name} ({clock.filled}/{clock.segments()})"
+                                }
+                            }
+                        }
+                        ActionSelect { action }
+                    },
+                    2 => rsx! {
+                        select {
+                            onchange: move |e| {
+                                healer
+                                    .set(
+                                        match e.value().parse() {
+                                            Ok(id) => Healer::Character(id),
+                                            Err(_) => Healer::Contact { quality: 1 },
+                                        },
+                                    );
+                            },
+                            option {
+                                value: "contact",
+                                selected: matches!(healer(), Healer::Contact { .. }),
+                                "A contact"
+                            }
+                            for c in healers().unwrap_or_default() {
+                                option {
+                                    value: "{c.id}",
+                                    selected: healer() == Healer::Character(c.id),
+                                    "{c.name}"
+                                }
+                            }
+                        }
+                        if let Healer::Contact { quality } = healer() {
+                            label { class: "flex flex-row gap-2 items-center",
+                                span { "Contact quality" }
+                                input {
+                                    class: "p-1 rounded-lg border border-border w-16",
+                                    r#type: "number",
+                                    min: "0",
+                                    value: "{quality}",
+                                    oninput: move |e| {
+                                        if let Ok(quality) = e.value().parse() {
+                                            healer.set(Healer::Contact { quality });
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    },
+                    3 => rsx! {
+                        ActionSelect { action }
+                    },
+                    4 => rsx! {
+                        select {
+                            onchange: move |e| {
+                                if let Ok(t) = e.value().parse() {
+                                    track.set(t);
+                                }
+                            },
+                            for t in XpTrack::ALL {
+                                option {
+                                    value: "{t}",
+                                    selected: t == track(),
+                                    disabled: character().trained.contains(&t),
+                                    "{t}"
+                                }
+                            }
+                        }
+                    },
+                    _ => rsx! {
+                        p { class: "italic", "Roll your lowest attribute and clear stress." }
+                    },
+                }
+
+                if needs_payment() {
+                    label { class: "flex flex-row gap-2 items-center",
+                        span { "Pay" }
+                        select {
+                            onchange: move |e| {
+                                extra
+                                    .set(if e.value() == "Rep" { DowntimeCost::Rep } else { DowntimeCost::Coin });
+                            },
+                            option {
+                                value: "Coin",
+                                selected: extra() == DowntimeCost::Coin,
+                                "{DowntimeCost::Coin}"
+                            }
+                            option {
+                                value: "Rep",
+                                selected: extra() == DowntimeCost::Rep,
+                                "{DowntimeCost::Rep}"
+                            }
+                        }
+                    }
+                }
+
+                if let Some(result) = result() {
+                    if let Some(roll) = result.roll {
+                        RollResultView { result: roll }
+                    }
+                    p { class: "font-bold", "{result.outcome}" }
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            result.set(None);
+                            error.set(None);
+                            open.set(false);
+                        },
+                        "Close"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Take Activity"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ActionSelect(action: Signal<Action>) -> Element {
+    rsx! {
+        select {
+            onchange: move |e| {
+                if let Ok(a) = e.value().parse() {
+                    action.set(a);
+                }
+            },
+            for a in Action::ALL {
+                option { value: "{a}", selected: a == action(), "{a}" }
+            }
+        }
+    }
+}
@@ -6,6 +6,12 @@ use crate::common::CountBtn;
 use crate::common::ItemChecked;
 use crate::elements::{Description, DescriptionEdit};
 
+use super::downtime::DowntimeDialog;
+use super::roll::RollDialog;
+use super::stress::StressDialog;
+use super::vice::Overindulge;
+use super::RuleError;
+
 #[component]
 pub fn Left(readonly: ReadOnlySignal<bool>, mut character: Signal<types::Character>) -> Element {
     let name = use_memo(move || character().name);
@@ -20,6 +26,13 @@ pub fn Left(readonly: ReadOnlySignal<bool>, mut character: Signal<types::Charact
     let healing = use_memo(move || character().healing);
     let armor = use_memo(move || character().armor);
 
+    let mut rolling = use_signal(|| false);
+    let mut taking_stress = use_signal(|| false);
+    let mut downtime = use_signal(|| false);
+
+    let rule_errors: Signal<types::ValidationErrors> = use_context();
+    let accepted: super::Accepted = use_context();
+
     let mut first_load = use_signal(|| true);
 
     use_effect(move || {
@@ -74,17 +87,30 @@ pub fn Left(readonly: ReadOnlySignal<bool>, mut character: Signal<types::Charact
         }
         let id = character.peek().id;
         let stress = stress();
-        let trauma = trauma().bits();
+        let trauma = trauma();
         let healing = healing();
-        let armor = armor().bits();
+        let armor = armor();
 
-        if !*first_load.peek() {
+        let change = types::CharacterChange::StressTraumaHealingArmor {
+            stress,
+            trauma,
+            healing,
+            armor,
+        };
+
+        if !*first_load.peek() && !accepted.holds(&change) {
             spawn(async move {
                 let _res = api::character::set_stress_truama_healing_armor(
-                    id, stress, trauma, healing, armor,
+                    id,
+                    stress,
+                    trauma.bits(),
+                    healing,
+                    armor.bits(),
                 )
                 .await;
 
+                super::report_rules(rule_errors, accepted, character, &change, &_res);
+
                 if let Err(err) = _res {
                     tracing::error!(
                         "Failed to set character stress/trauma/healing/armor: {:?}",
@@ -101,7 +127,17 @@ pub fn Left(readonly: ReadOnlySignal<bool>, mut character: Signal<types::Charact
 
     rsx! {
         div { class: "flex flex-col gap-2 flex-auto p-4 pb-2 lg:pr-2 lg:pb-4",
-            h2 { class: "block text-4xl w-max max-w-full", "{name}" }
+            div { class: "flex flex-row justify-between items-center gap-2",
+                h2 { class: "block text-4xl w-max max-w-full", "{name}" }
+                if !readonly() {
+                    button {
+                        class: "p-2 px-4 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        onclick: move |_| rolling.set(true),
+                        "Roll"
+                    }
+                    RollDialog { open: rolling, character }
+                }
+            }
             if !readonly() {
                 input {
                     class: "p-1",
@@ -167,6 +203,27 @@ pub fn Left(readonly: ReadOnlySignal<bool>, mut character: Signal<types::Charact
 
             hr { class: "my-2" }
             div { class: "flex flex-col gap-2 justify-between",
+                if character().retired {
+                    p { class: "font-bold text-destructive brightness-200", "Retired" }
+                } else if !readonly() {
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "p-1 px-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                            onclick: move |_| downtime.set(true),
+                            "Downtime"
+                        }
+                        button {
+                            class: "p-1 px-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                            onclick: move |_| taking_stress.set(true),
+                            "Take Stress"
+                        }
+                    }
+                    if character().pending_overindulgence {
+                        Overindulge { character }
+                    }
+                    StressDialog { open: taking_stress, character }
+                    DowntimeDialog { open: downtime, character }
+                }
                 div { class: "flex flex-row gap-2 items-center justify-between",
                     div {
                         span { "Stress" }
@@ -185,6 +242,7 @@ pub fn Left(readonly: ReadOnlySignal<bool>, mut character: Signal<types::Charact
                                 }
                             }
                         }
+                        RuleError { field: "stress" }
                     }
                     div {
                         span { "Trauma" }
@@ -205,6 +263,7 @@ pub fn Left(readonly: ReadOnlySignal<bool>, mut character: Signal<types::Charact
                         Trauma { trauma, character, readonly: readonly() }
                     }
                 }
+                RuleError { field: "trauma" }
             }
             hr { class: "my-2" }
             Harm { character, readonly: readonly() }
@@ -270,6 +329,8 @@ pub fn Left(readonly: ReadOnlySignal<bool>, mut character: Signal<types::Charact
                     }
                 }
             }
+            RuleError { field: "healing" }
+            RuleError { field: "armor" }
             hr { class: "my-2" }
 
             div { class: "flex flex-col gap-2",
@@ -380,6 +441,7 @@ fn Trauma(
 #[component]
 fn Harm(character: Signal<types::Character>, readonly: ReadOnlySignal<bool>) -> Element {
     let harm = use_memo(move || character().harm);
+    let penalties = use_memo(move || harm().penalties());
     let mut first_load = use_signal(|| true);
 
     use_effect(move || {
@@ -408,7 +470,7 @@ fn Harm(character: Signal<types::Character>, readonly: ReadOnlySignal<bool>) ->
 
     rsx! {
         div { class: "grid grid-cols-[auto_1fr_auto]",
-            HarmLine { num: 3, state: "Need Help",
+            HarmLine { num: 3, state: "Need Help", active: penalties().need_help,
                 input {
                     readonly,
                     class: "w-full h-full p-1 outline-hidden focus:outline-1 focus:outline-foreground focus:outline-solid focus:-outline-offset-1",
@@ -425,7 +487,7 @@ fn Harm(character: Signal<types::Character>, readonly: ReadOnlySignal<bool>) ->
                     },
                 }
             }
-            HarmLine { num: 2, state: "-1D",
+            HarmLine { num: 2, state: "-1D", active: penalties().minus_die,
                 input {
                     readonly,
                     class: "w-full h-full p-1 outline-hidden focus:outline-1 focus:outline-foreground focus:outline-solid focus:-outline-offset-1 border-r border-border",
@@ -457,7 +519,7 @@ fn Harm(character: Signal<types::Character>, readonly: ReadOnlySignal<bool>) ->
                     },
                 }
             }
-            HarmLine { num: 1, state: "Less Effect",
+            HarmLine { num: 1, state: "Less Effect", active: penalties().less_effect,
                 input {
                     readonly,
                     class: "w-full h-full p-1 outline-hidden focus:outline-1 focus:outline-foreground focus:outline-solid focus:-outline-offset-1 border-r border-border",
@@ -494,12 +556,13 @@ fn Harm(character: Signal<types::Character>, readonly: ReadOnlySignal<bool>) ->
 }
 
 #[component]
-fn HarmLine(num: u8, state: &'static str, children: Element) -> Element {
+fn HarmLine(num: u8, state: &'static str, active: bool, children: Element) -> Element {
     rsx! {
         div { class: "grid grid-cols-subgrid col-span-3 border-b border-border first:border-t",
             span { class: "text-sm p-1 bg-neutral-800 h-full", "{num}" }
             div { class: "flex flex-row grow w-full", {children} }
-            span { class: "text-sm p-1 grow text-wrap h-full bg-neutral-800 text-end",
+            span {
+                class: if active { "text-sm p-1 grow text-wrap h-full bg-neutral-800 text-end font-bold text-destructive brightness-200" } else { "text-sm p-1 grow text-wrap h-full bg-neutral-800 text-end" },
                 "{state}"
             }
         }
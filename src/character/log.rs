@@ -0,0 +1,33 @@
+use dioxus::prelude::*;
+
+#[component]
+pub fn CharacterLog(character: Signal<types::Character>) -> Element {
+    // Reload whenever something that writes to the log changes on the sheet
+    let changes = use_memo(move || {
+        let character = character();
+        (
+            character.downtime_activities,
+            character.pending_overindulgence,
+        )
+    });
+
+    let entries = use_resource(move || async move {
+        let _ = changes();
+        let id = character.peek().id;
+        api::character::get_log(id).await.unwrap_or_default()
+    });
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg underline", "Log" }
+            div { class: "flex flex-col gap-1 max-h-64 overflow-y-auto",
+                for entry in entries().unwrap_or_default() {
+                    p { key: "{entry.id}", class: "text-sm", "{entry.entry}" }
+                }
+                if entries().is_some_and(|e| e.is_empty()) {
+                    p { class: "italic text-muted-foreground", "Nothing yet" }
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,14 @@
 use dioxus::prelude::*;
 
+mod advance;
 mod center;
+mod downtime;
 mod left;
+mod log;
 mod right;
+mod roll;
+mod stress;
+mod vice;
 
 mod create;
 pub use create::CreateCharacter;
@@ -11,6 +17,63 @@ use center::Center;
 use left::Left;
 use right::Right;
 
+use crate::elements::ErrorMessage;
+
+/// The sheet as the server last stored it, so rejected updates can be rolled back.
+#[derive(Clone, Copy)]
+pub(crate) struct Accepted(Signal<types::Character>);
+
+impl Accepted {
+    /// Whether the server already holds the values in `change`, so there is nothing to send.
+    pub fn holds(&self, change: &types::CharacterChange) -> bool {
+        self.0.peek().current(change) == *change
+    }
+
+    /// Records a change the server stored.
+    pub fn record(&mut self, change: &types::CharacterChange) {
+        self.0.with_mut(|c| change.apply(c));
+    }
+}
+
+/// Shows the rules a sheet update broke and rolls the sheet back to what the server holds, or
+/// records the update and clears the errors once it goes through.
+pub(crate) fn report_rules(
+    mut rule_errors: Signal<types::ValidationErrors>,
+    mut accepted: Accepted,
+    mut character: Signal<types::Character>,
+    change: &types::CharacterChange,
+    res: &Result<(), ServerFnError<types::ValidationErrors>>,
+) {
+    match res {
+        Err(ServerFnError::WrappedServerError(errors)) => {
+            let undo = accepted.0.peek().current(change);
+            character.with_mut(|c| undo.apply(c));
+            rule_errors.set(errors.clone());
+        }
+        Ok(()) => {
+            accepted.record(change);
+            if !rule_errors.peek().is_empty() {
+                rule_errors.set(types::ValidationErrors::default());
+            }
+        }
+        Err(_) => {}
+    }
+}
+
+/// The rule a sheet field last broke, shown next to the field.
+#[component]
+pub(crate) fn RuleError(field: &'static str) -> Element {
+    let rule_errors: Signal<types::ValidationErrors> = use_context();
+
+    let error = rule_errors().get(field).map(str::to_string);
+
+    rsx! {
+        if let Some(error) = error {
+            ErrorMessage { "{error}" }
+        }
+    }
+}
+
 #[component]
 pub fn Character(
     character: ReadOnlySignal<types::Character>,
@@ -20,6 +83,9 @@ pub fn Character(
 
     let readonly = use_memo(move || readonly().unwrap_or(true));
 
+    use_context_provider(|| Signal::new(types::ValidationErrors::default()));
+    use_context_provider(|| Accepted(Signal::new(character.peek().clone())));
+
     rsx! {
         div { class: "flex flex-col lg:flex-row h-full",
             Left { readonly, character }
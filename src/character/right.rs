@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
 use types::Character;
 
+use super::advance::AdvanceDialog;
+use super::RuleError;
 use crate::common::{CountBtn, ItemChecked};
 
 #[component]
@@ -8,6 +10,9 @@ pub fn Right(readonly: ReadOnlySignal<bool>, character: Signal<types::Character>
     let coin = use_memo(move || character().coin);
     let stash = use_memo(move || character().stash);
 
+    let rule_errors: Signal<types::ValidationErrors> = use_context();
+    let accepted: super::Accepted = use_context();
+
     let mut first_load = use_signal(|| true);
 
     use_effect(move || {
@@ -18,9 +23,12 @@ pub fn Right(readonly: ReadOnlySignal<bool>, character: Signal<types::Character>
         let coin = coin();
         let stash = stash();
 
-        if !*first_load.peek() {
+        let change = types::CharacterChange::CoinStash { coin, stash };
+
+        if !*first_load.peek() && !accepted.holds(&change) {
             spawn(async move {
                 let _res = api::character::set_coin_stash(id, coin, stash).await;
+                super::report_rules(rule_errors, accepted, character, &change, &_res);
                 #[cfg(debug_assertions)]
                 {
                     if let Err(e) = _res {
@@ -63,6 +71,8 @@ pub fn Right(readonly: ReadOnlySignal<bool>, character: Signal<types::Character>
                     },
                 }
             }
+            RuleError { field: "coin" }
+            RuleError { field: "stash" }
 
             Xp { character, readonly }
 
@@ -115,6 +125,18 @@ fn Coin(coin: ReadOnlySignal<u8>, readonly: Option<bool>, set: EventHandler<u8>)
 fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
     let xp = use_memo(move || character().xp);
     let dots = use_memo(move || character().dots);
+    let mut advancing = use_signal(|| false);
+
+    let can_advance = use_memo(move || {
+        let character = character();
+        character.can_advance_playbook()
+            || types::Attribute::ALL
+                .into_iter()
+                .any(|a| character.can_advance_attribute(a))
+    });
+
+    let rule_errors: Signal<types::ValidationErrors> = use_context();
+    let accepted: super::Accepted = use_context();
 
     let mut first_load = use_signal(|| true);
 
@@ -125,11 +147,14 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
         let id = character.peek().id;
         let xp = xp();
 
-        if *first_load.peek() {
+        let change = types::CharacterChange::Xp(xp.clone());
+
+        if *first_load.peek() || accepted.holds(&change) {
             return;
         }
         spawn(async move {
             let _res = api::character::set_xp(id, xp).await;
+            super::report_rules(rule_errors, accepted, character, &change, &_res);
             #[cfg(debug_assertions)]
             {
                 if let Err(e) = _res {
@@ -145,11 +170,15 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
         }
         let id = character.peek().id;
         let dots = dots();
-        if *first_load.peek() {
+
+        let change = types::CharacterChange::Dots(dots.clone());
+
+        if *first_load.peek() || accepted.holds(&change) {
             return;
         }
         spawn(async move {
             let _res = api::character::set_dots(id, dots).await;
+            super::report_rules(rule_errors, accepted, character, &change, &_res);
             #[cfg(debug_assertions)]
             {
                 if let Err(e) = _res {
@@ -178,6 +207,18 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                     })
             },
         }
+        RuleError { field: "xp.playbook" }
+        p { class: "italic text-sm", "{character().class.xp_trigger()}" }
+        if can_advance() && !readonly() {
+            div { class: "flex justify-end pt-1",
+                button {
+                    class: "p-1 px-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                    onclick: move |_| advancing.set(true),
+                    "Advance"
+                }
+            }
+            AdvanceDialog { open: advancing, character }
+        }
 
         div { class: "flex flex-row lg:flex-col gap-2 justify-between flex-wrap lg:flex-no-wrap lg:justify-start pt-2",
             div { class: "flex flex-col",
@@ -194,11 +235,13 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                             })
                     },
                 }
+                RuleError { field: "xp.insight" }
                 DotBlock {
                     readonly,
                     params: DotBlockParamList(
                         DotBlockParams {
                             name: "Hunt",
+                            field: "dots.hunt",
                             current: dots().hunt,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.hunt = count)
@@ -206,6 +249,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                         },
                         DotBlockParams {
                             name: "Study",
+                            field: "dots.study",
                             current: dots().study,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.study = count)
@@ -213,6 +257,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                         },
                         DotBlockParams {
                             name: "Survey",
+                            field: "dots.survey",
                             current: dots().survey,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.survey = count)
@@ -220,6 +265,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                         },
                         DotBlockParams {
                             name: "Tinker",
+                            field: "dots.tinker",
                             current: dots().tinker,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.tinker = count)
@@ -241,6 +287,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                             })
                     },
                 }
+                RuleError { field: "xp.prowess" }
 
 
                 DotBlock {
@@ -248,6 +295,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                     params: DotBlockParamList(
                         DotBlockParams {
                             name: "Finesse",
+                            field: "dots.finesse",
                             current: dots().finesse,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.finesse = count)
@@ -255,6 +303,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                         },
                         DotBlockParams {
                             name: "Prowl",
+                            field: "dots.prowl",
                             current: dots().prowl,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.prowl = count)
@@ -262,6 +311,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                         },
                         DotBlockParams {
                             name: "Skirmish",
+                            field: "dots.skirmish",
                             current: dots().skirmish,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.skirmish = count)
@@ -269,6 +319,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                         },
                         DotBlockParams {
                             name: "Wreck",
+                            field: "dots.wreck",
                             current: dots().wreck,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.wreck = count)
@@ -291,11 +342,13 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                             })
                     },
                 }
+                RuleError { field: "xp.resolve" }
                 DotBlock {
                     readonly,
                     params: DotBlockParamList(
                         DotBlockParams {
                             name: "Attune",
+                            field: "dots.attune",
                             current: dots().attune,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.attune = count)
@@ -303,6 +356,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                         },
                         DotBlockParams {
                             name: "Command",
+                            field: "dots.command",
                             current: dots().command,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.command = count)
@@ -310,6 +364,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                         },
                         DotBlockParams {
                             name: "Consort",
+                            field: "dots.consort",
                             current: dots().consort,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.consort = count)
@@ -317,6 +372,7 @@ fn Xp(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Element {
                         },
                         DotBlockParams {
                             name: "Sway",
+                            field: "dots.sway",
                             current: dots().sway,
                             set: EventHandler::new(move |count| {
                                 character.with_mut(|c| c.dots.sway = count)
@@ -360,6 +416,8 @@ fn XpLine(
 #[derive(Debug, Clone, PartialEq)]
 struct DotBlockParams {
     name: &'static str,
+    /// The field its rule errors are reported against.
+    field: &'static str,
     current: u8,
     set: EventHandler<u8>,
 }
@@ -374,6 +432,13 @@ struct DotBlockParamList(
 
 #[component]
 fn DotBlock(readonly: ReadOnlySignal<bool>, params: DotBlockParamList) -> Element {
+    let fields = [
+        params.0.field,
+        params.1.field,
+        params.2.field,
+        params.3.field,
+    ];
+
     rsx! {
 
         div { class: "grid grid-cols-[auto_auto_auto_auto_0.25rem_1fr] grid-rows-4 pt-2 grid-flow-col",
@@ -414,6 +479,9 @@ fn DotBlock(readonly: ReadOnlySignal<bool>, params: DotBlockParamList) -> Elemen
                 DotLine { readonly, params: params.3 }
             }
         }
+        for field in fields {
+            RuleError { field }
+        }
     }
 }
 
@@ -439,6 +507,9 @@ fn Items(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Elemen
     let load = use_memo(move || character().load);
     let items = use_memo(move || character().items);
 
+    let rule_errors: Signal<types::ValidationErrors> = use_context();
+    let accepted: super::Accepted = use_context();
+
     let mut first_load = use_signal(|| true);
 
     use_effect(move || {
@@ -448,11 +519,14 @@ fn Items(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Elemen
         let id = character.peek().id;
         let load = load();
 
-        if *first_load.peek() {
+        let change = types::CharacterChange::Load(load);
+
+        if *first_load.peek() || accepted.holds(&change) {
             return;
         }
         spawn(async move {
             let _res = api::character::set_load(id, load).await;
+            super::report_rules(rule_errors, accepted, character, &change, &_res);
             #[cfg(debug_assertions)]
             {
                 if let Err(e) = _res {
@@ -468,11 +542,15 @@ fn Items(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Elemen
         }
         let id = character.peek().id;
         let items = items();
-        if *first_load.peek() {
+
+        let change = types::CharacterChange::Items(items);
+
+        if *first_load.peek() || accepted.holds(&change) {
             return;
         }
         spawn(async move {
             let _res = api::character::set_items(id, items.bits()).await;
+            super::report_rules(rule_errors, accepted, character, &change, &_res);
             #[cfg(debug_assertions)]
             {
                 if let Err(e) = _res {
@@ -532,16 +610,19 @@ fn Items(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Elemen
                 span { class: "italic", " heavy" }
             }
         }
+        RuleError { field: "load" }
+        if let Some(limit) = load().map(|l| character().load_limit(l)) {
+            p { class: "italic text-muted-foreground mb-2", "{character().used_load()} / {limit} load" }
+        }
+        RuleError { field: "items" }
         Item {
             flag: types::Items::BLADE,
-            boxes: 1,
             character,
             readonly,
             "A Blade or Two"
         }
         Item {
             flag: types::Items::THROWING_KNIVES,
-            boxes: 1,
             character,
             readonly,
             "Throwing Knives"
@@ -549,14 +630,12 @@ fn Items(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Elemen
         div { class: "inline-flex lg:justify-between gap-4 w-full",
             Item {
                 flag: types::Items::PISTOL,
-                boxes: 1,
                 character,
                 readonly,
                 "A Pistol"
             }
             Item {
                 flag: types::Items::PISTOL_2,
-                boxes: 1,
                 character,
                 readonly,
                 "A 2"
@@ -566,14 +645,12 @@ fn Items(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Elemen
         }
         Item {
             flag: types::Items::LARGE_WEAPON,
-            boxes: 2,
             character,
             readonly,
             "A Large Weapon"
         }
         Item {
             flag: types::Items::UNUSUAL_WEAPON,
-            boxes: 1,
             character,
             readonly,
             "An Unusual Weapon"
@@ -581,14 +658,12 @@ fn Items(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Elemen
         div { class: "inline-flex lg:justify-between gap-4 w-full",
             Item {
                 flag: types::Items::ARMOR,
-                boxes: 2,
                 character,
                 readonly,
                 "Armor"
             }
             Item {
                 flag: types::Items::HEAVY_ARMOR,
-                boxes: 3,
                 character,
                 readonly,
                 "+ Heavy"
@@ -596,56 +671,48 @@ fn Items(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Elemen
         }
         Item {
             flag: types::Items::BURGLARY_GEAR,
-            boxes: 1,
             character,
             readonly,
             "Burglary Gear"
         }
         Item {
             flag: types::Items::CLIMBING_GEAR,
-            boxes: 2,
             character,
             readonly,
             "Climbing Gear"
         }
         Item {
             flag: types::Items::ARCANE_IMPLEMENTS,
-            boxes: 1,
             character,
             readonly,
             "Arcane Implements"
         }
         Item {
             flag: types::Items::DOCUMENTS,
-            boxes: 1,
             character,
             readonly,
             "Documents"
         }
         Item {
             flag: types::Items::SUBTERFUGE_SUPPLIES,
-            boxes: 1,
             character,
             readonly,
             "Subterfuge Supplies"
         }
         Item {
             flag: types::Items::DEMO_TOOLS,
-            boxes: 2,
             character,
             readonly,
             "Demolition Tools"
         }
         Item {
             flag: types::Items::TINKER_TOOLS,
-            boxes: 1,
             character,
             readonly,
             "Tinkering Tools"
         }
         Item {
             flag: types::Items::LANTERN,
-            boxes: 1,
             character,
             readonly,
             "Lantern"
@@ -657,12 +724,13 @@ fn Items(character: Signal<Character>, readonly: ReadOnlySignal<bool>) -> Elemen
 #[component]
 fn Item(
     flag: types::Items,
-    boxes: u8,
     character: Signal<Character>,
     readonly: ReadOnlySignal<bool>,
     children: Element,
 ) -> Element {
     let has_item = use_memo(move || character().items.contains(flag));
+    let boxes = types::Items::item_load(flag);
+    let mut rule_errors: Signal<types::ValidationErrors> = use_context();
     let connector_background = if has_item() {
         "bg-primary"
     } else {
@@ -684,10 +752,18 @@ fn Item(
                         checked: has_item(),
                         readonly,
                         onclick: move |_| {
-                            character
-                                .with_mut(|c| {
-                                    c.items.toggle(flag);
-                                })
+                            let items = character.peek().items ^ flag;
+                            // Dropping an item is always allowed
+                            if !has_item() {
+                                let check = character
+                                    .peek()
+                                    .validate_change(&types::CharacterChange::Items(items));
+                                if let Err(errors) = check {
+                                    rule_errors.set(errors);
+                                    return;
+                                }
+                            }
+                            character.with_mut(|c| c.items = items)
                         },
                     }
                 }
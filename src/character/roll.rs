@@ -0,0 +1,219 @@
+use dioxus::prelude::*;
+use types::roll::{Effect, Outcome, Position, Roll, RollKind, RollResult};
+
+use crate::elements::{Dialog, ErrorMessage};
+
+#[component]
+pub fn RollDialog(open: Signal<bool>, character: Signal<types::Character>) -> Element {
+    let mut kind = use_signal(|| RollKind::Action);
+    let mut action = use_signal(|| types::Action::Hunt);
+    let mut attribute = use_signal(|| types::Attribute::Insight);
+    let mut position = use_signal(|| Position::Risky);
+    let mut effect = use_signal(|| Effect::Standard);
+    let mut dice = use_signal(|| 1u8);
+    let mut bonus = use_signal(|| 0u8);
+
+    let mut result = use_signal(|| None as Option<RollResult>);
+    let mut error = use_signal(|| None as Option<String>);
+
+    let roll = use_memo(move || match kind() {
+        RollKind::Action => Roll::Action {
+            action: action(),
+            position: position(),
+            effect: effect(),
+        },
+        RollKind::Resistance => Roll::Resistance {
+            attribute: attribute(),
+        },
+        RollKind::Fortune => Roll::Fortune { dice: dice() },
+        RollKind::Engagement => Roll::Engagement { dice: dice() },
+    });
+
+    let pool = use_memo(move || roll().dice_pool(&character(), bonus()));
+    let max_dice = Roll::MAX_DICE;
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_30rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+                    match api::roll::roll(character().id, roll(), bonus()).await {
+                        Ok(r) => {
+                            result.set(Some(r));
+                            error.set(None);
+                        }
+                        Err(e) => {
+                            tracing::error!("Failed to roll: {e}");
+                            error.set(Some("Failed to roll".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Roll" }
+                select {
+                    onchange: move |e| {
+                        if let Ok(k) = e.value().parse() {
+                            kind.set(k);
+                        }
+                    },
+                    for k in RollKind::ALL {
+                        option { value: "{k}", selected: k == kind(), "{k}" }
+                    }
+                }
+                match kind() {
+                    RollKind::Action => rsx! {
+                        select {
+                            onchange: move |e| {
+                                if let Ok(a) = e.value().parse() {
+                                    action.set(a);
+                                }
+                            },
+                            for a in types::Action::ALL {
+                                option { value: "{a}", selected: a == action(), "{a}" }
+                            }
+                        }
+                        div { class: "flex flex-row gap-2",
+                            select {
+                                class: "grow",
+                                onchange: move |e| {
+                                    if let Ok(p) = e.value().parse() {
+                                        position.set(p);
+                                    }
+                                },
+                                for p in Position::ALL {
+                                    option { value: "{p}", selected: p == position(), "{p}" }
+                                }
+                            }
+                            select {
+                                class: "grow",
+                                onchange: move |e| {
+                                    if let Ok(ef) = e.value().parse() {
+                                        effect.set(ef);
+                                    }
+                                },
+                                for ef in Effect::ALL {
+                                    option { value: "{ef}", selected: ef == effect(), "{ef}" }
+                                }
+                            }
+                        }
+                    },
+                    RollKind::Resistance => rsx! {
+                        select {
+                            onchange: move |e| {
+                                if let Ok(a) = e.value().parse() {
+                                    attribute.set(a);
+                                }
+                            },
+                            for a in types::Attribute::ALL {
+                                option { value: "{a}", selected: a == attribute(), "{a}" }
+                            }
+                        }
+                    },
+                    RollKind::Fortune | RollKind::Engagement => rsx! {
+                        label { class: "flex flex-row gap-2 items-center",
+                            span { "Dice" }
+                            input {
+                                class: "p-1 rounded-lg border border-border w-16",
+                                r#type: "number",
+                                min: "0",
+                                max: "{max_dice}",
+                                value: "{dice}",
+                                oninput: move |e| {
+                                    if let Ok(d) = e.value().parse() {
+                                        dice.set(d);
+                                    }
+                                },
+                            }
+                        }
+                    },
+                }
+                label { class: "flex flex-row gap-2 items-center",
+                    span { "Bonus Dice" }
+                    input {
+                        class: "p-1 rounded-lg border border-border w-16",
+                        r#type: "number",
+                        min: "0",
+                        max: "{max_dice}",
+                        value: "{bonus}",
+                        oninput: move |e| {
+                            if let Ok(b) = e.value().parse() {
+                                bonus.set(b);
+                            }
+                        },
+                    }
+                    span { class: "italic text-muted-foreground ml-auto", "{pool}d" }
+                }
+
+                if kind() == RollKind::Action {
+                    if character().harm.penalties().need_help {
+                        p { class: "italic text-destructive brightness-200",
+                            "Your harm means you need help to act."
+                        }
+                    } else if character().harm.penalties().minus_die {
+                        p { class: "italic text-muted-foreground", "-1d from harm" }
+                    }
+                }
+
+                if let Some(result) = result() {
+                    RollResultView { result }
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            open.set(false);
+                        },
+                        "Close"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Roll"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn RollResultView(result: RollResult) -> Element {
+    let highlight = if result.zero_dice {
+        result.dice.iter().min().copied()
+    } else {
+        result.dice.iter().max().copied()
+    };
+
+    let color = match result.outcome {
+        Outcome::Critical | Outcome::Success => "text-primary",
+        Outcome::Partial => "text-muted-foreground",
+        Outcome::Failure => "text-destructive brightness-200",
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-1",
+            div { class: "flex flex-row gap-1 items-center",
+                for die in result.dice.iter().copied() {
+                    span {
+                        class: if Some(die) == highlight { "w-6 h-6 flex justify-center items-center rounded bg-primary text-primary-foreground" } else { "w-6 h-6 flex justify-center items-center rounded bg-input" },
+                        "{die}"
+                    }
+                }
+                span { class: "font-bold {color} ml-2", "{result.outcome}" }
+            }
+            if let Some(stress) = result.stress {
+                if stress < 0 {
+                    span { class: "italic", "Clear {-stress} stress" }
+                } else {
+                    span { class: "italic", "Costs {stress} stress" }
+                }
+            }
+        }
+    }
+}
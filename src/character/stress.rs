@@ -0,0 +1,227 @@
+use dioxus::prelude::*;
+use types::{StressGain, StressSpend, Trauma, TraumaFlags};
+
+use crate::elements::{Dialog, ErrorMessage};
+
+/// Why stress is being taken. Anything but `Other` is logged against the current score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Reason {
+    Push,
+    Assist,
+    Resistance,
+    Other,
+}
+
+impl Reason {
+    const ALL: [Reason; 4] = [
+        Reason::Push,
+        Reason::Assist,
+        Reason::Resistance,
+        Reason::Other,
+    ];
+
+    fn spend(&self, amount: u8) -> Option<StressSpend> {
+        match self {
+            Reason::Push => Some(StressSpend::Push),
+            Reason::Assist => Some(StressSpend::Assist),
+            Reason::Resistance => Some(StressSpend::Resistance { stress: amount }),
+            Reason::Other => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reason::Push => write!(f, "Push yourself"),
+            Reason::Assist => write!(f, "Assist"),
+            Reason::Resistance => write!(f, "Resistance roll"),
+            Reason::Other => write!(f, "Other"),
+        }
+    }
+}
+
+#[component]
+pub fn StressDialog(open: Signal<bool>, character: Signal<types::Character>) -> Element {
+    let mut reason = use_signal(|| Reason::Push);
+    let mut note = use_signal(String::new);
+    let mut amount = use_signal(|| 1u8);
+    let mut trauma_needed = use_signal(|| false);
+    let mut trauma = use_signal(|| None as Option<Trauma>);
+    let mut taken = use_signal(|| None as Option<(Trauma, bool)>);
+    let mut error = use_signal(|| None as Option<String>);
+
+    // Pushing and assisting always cost the same
+    let cost = use_memo(move || {
+        reason()
+            .spend(amount())
+            .map_or(amount(), |spend| spend.stress())
+    });
+
+    let mut reset = move || {
+        reason.set(Reason::Push);
+        note.set(String::new());
+        amount.set(1);
+        trauma_needed.set(false);
+        trauma.set(None);
+        taken.set(None);
+        error.set(None);
+    };
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_30rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    if trauma_needed() && trauma().is_none() {
+                        error.set(Some("Choose a trauma".into()));
+                        return;
+                    }
+
+                    let id = character().id;
+                    let res = match reason().spend(amount()) {
+                        Some(spend) => api::character::spend_stress(id, spend, note(), trauma()).await,
+                        None => api::character::gain_stress(id, amount(), trauma()).await,
+                    };
+
+                    match res {
+                        Ok(StressGain::Marked { stress }) => {
+                            character.with_mut(|c| c.stress = stress);
+                            reset();
+                            open.set(false);
+                        }
+                        Ok(StressGain::TraumaNeeded) => {
+                            error.set(None);
+                            trauma_needed.set(true);
+                        }
+                        Ok(StressGain::Trauma { trauma, retired }) => {
+                            character
+                                .with_mut(|c| {
+                                    c.stress = 0;
+                                    c.trauma |= TraumaFlags::from(trauma);
+                                    c.retired = retired;
+                                });
+                            error.set(None);
+                            trauma_needed.set(false);
+                            taken.set(Some((trauma, retired)));
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to apply stress: {e:?}");
+                            error.set(Some("Failed to apply stress".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Take Stress" }
+
+                if let Some((trauma, retired)) = taken() {
+                    p {
+                        "Stress overflows. {character().name} is now "
+                        span { class: "font-bold", "{trauma}" }
+                        " and their stress is cleared."
+                    }
+                    if retired {
+                        p { class: "font-bold",
+                            "With their fourth trauma, {character().name} retires from the life."
+                        }
+                    }
+                    div { class: "flex justify-end",
+                        button {
+                            class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                            onclick: move |e| {
+                                e.prevent_default();
+                                reset();
+                                open.set(false);
+                            },
+                            "Done"
+                        }
+                    }
+                } else {
+                    select {
+                        disabled: trauma_needed(),
+                        onchange: move |e| {
+                            if let Some(r) = Reason::ALL.into_iter().find(|r| r.to_string() == e.value()) {
+                                reason.set(r);
+                            }
+                        },
+                        for r in Reason::ALL {
+                            option { value: "{r}", selected: r == reason(), "{r}" }
+                        }
+                    }
+                    if reason() != Reason::Other {
+                        input {
+                            class: "p-1 rounded-lg border border-border",
+                            placeholder: "What for?",
+                            disabled: trauma_needed(),
+                            value: "{note}",
+                            oninput: move |e| note.set(e.value()),
+                        }
+                    }
+                    label { class: "flex flex-row gap-2 items-center",
+                        span { "Stress" }
+                        input {
+                            class: "p-1 rounded-lg border border-border w-16",
+                            r#type: "number",
+                            min: "1",
+                            disabled: trauma_needed() || matches!(reason(), Reason::Push | Reason::Assist),
+                            value: "{cost}",
+                            oninput: move |e| {
+                                if let Ok(a) = e.value().parse() {
+                                    amount.set(a);
+                                }
+                            },
+                        }
+                        span { class: "italic text-muted-foreground ml-auto",
+                            "{character().stress} / {types::Character::MAX_STRESS}"
+                        }
+                    }
+
+                    if trauma_needed() {
+                        p { "This overflows your stress. Choose a trauma to take." }
+                        div { class: "flex flex-row flex-wrap gap-2",
+                            for t in Trauma::ALL {
+                                if !character().trauma.contains(TraumaFlags::from(t)) {
+                                    button {
+                                        class: if trauma() == Some(t) { "px-2 py-1 rounded-lg bg-destructive text-destructive-foreground cursor-pointer" } else { "px-2 py-1 rounded-lg bg-input cursor-pointer" },
+                                        onclick: move |e| {
+                                            e.prevent_default();
+                                            trauma.set(Some(t));
+                                        },
+                                        "{t}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if let Some(error) = error() {
+                        ErrorMessage { "{error}" }
+                    }
+
+                    div { class: "flex flex-row justify-between gap-4",
+                        button {
+                            class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                            onclick: move |e| {
+                                e.prevent_default();
+                                reset();
+                                open.set(false);
+                            },
+                            "Cancel"
+                        }
+                        button {
+                            class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                            r#type: "submit",
+                            if trauma_needed() {
+                                "Take Trauma"
+                            } else {
+                                "Take Stress"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use dioxus::prelude::*;
+use types::Overindulgence;
+
+use crate::elements::ErrorMessage;
+
+#[component]
+pub fn Overindulge(character: Signal<types::Character>) -> Element {
+    let mut error = use_signal(|| None as Option<String>);
+
+    rsx! {
+        div { class: "flex flex-col gap-2 p-2 rounded-lg border border-destructive",
+            p { class: "font-bold", "{character().name} overindulged in {character().vice}. Choose what happens:" }
+            for consequence in Overindulgence::ALL {
+                button {
+                    class: "bg-background hover:bg-input p-2 text-left rounded cursor-pointer",
+                    onclick: move |e| async move {
+                        e.prevent_default();
+                        match api::character::overindulge(character().id, consequence).await {
+                            Ok(()) => {
+                                error.set(None);
+                                character.with_mut(|c| c.pending_overindulgence = false);
+                            }
+                            Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                            Err(e) => {
+                                tracing::error!("Failed to resolve overindulgence: {e:?}");
+                                error.set(Some("Failed to resolve overindulgence".into()));
+                            }
+                        }
+                    },
+                    span { class: "font-bold", "{consequence}" }
+                    p { class: "text-sm italic", "{consequence.description()}" }
+                }
+            }
+            if let Some(error) = error() {
+                ErrorMessage { "{error}" }
+            }
+        }
+    }
+}
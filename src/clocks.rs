@@ -0,0 +1,120 @@
+use dioxus::prelude::*;
+
+use crate::elements::Clock;
+
+#[component]
+pub fn Clocks(owner: types::ClockOwner, readonly: ReadOnlySignal<bool>) -> Element {
+    let mut clocks =
+        use_resource(
+            move || async move { api::clock::get_clocks(owner).await.unwrap_or_default() },
+        );
+
+    let mut name = use_signal(String::new);
+    let mut size = use_signal(|| types::ClockSize::Four);
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg underline", "Clocks" }
+            div { class: "flex flex-row flex-wrap gap-4",
+                for clock in clocks().unwrap_or_default() {
+                    ClockCard {
+                        key: "{clock.id}",
+                        clock,
+                        readonly,
+                        on_change: move |_| clocks.restart(),
+                    }
+                }
+            }
+            if !readonly() {
+                form {
+                    class: "flex flex-row gap-2 items-center",
+                    onsubmit: move |e| async move {
+                        e.prevent_default();
+                        if name().is_empty() {
+                            return;
+                        }
+                        let res = api::clock::create(owner, name(), size()).await;
+                        if let Err(e) = res {
+                            tracing::error!("Failed to create clock: {e}");
+                        } else {
+                            name.set(String::new());
+                            clocks.restart();
+                        }
+                    },
+                    input {
+                        class: "bg-input text-input-foreground rounded p-2",
+                        placeholder: "Clock Name",
+                        value: "{name}",
+                        oninput: move |e| name.set(e.value()),
+                    }
+                    select {
+                        onchange: move |e| {
+                            if let Ok(s) = e.value().parse::<u8>() {
+                                if let Ok(s) = types::ClockSize::try_from(s) {
+                                    size.set(s);
+                                }
+                            }
+                        },
+                        for s in types::ClockSize::ALL {
+                            option { value: "{s}", selected: s == size(), "{s}" }
+                        }
+                    }
+                    button { class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        "Add Clock"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ClockCard(
+    clock: ReadOnlySignal<types::Clock>,
+    readonly: ReadOnlySignal<bool>,
+    on_change: EventHandler,
+) -> Element {
+    rsx! {
+        div { class: "flex flex-col gap-1 items-center",
+            Clock {
+                segments: clock().segments(),
+                filled: clock().filled,
+                readonly: readonly(),
+                set: move |filled: u8| async move {
+                    let ticks = filled as i8 - clock().filled as i8;
+                    let res = api::clock::tick(clock().id, ticks).await;
+                    if let Err(e) = res {
+                        tracing::error!("Failed to tick clock: {e}");
+                    }
+                    on_change.call(());
+                },
+            }
+            if readonly() {
+                span { "{clock().name}" }
+            } else {
+                input {
+                    class: "bg-transparent text-center p-1 w-32",
+                    value: "{clock().name}",
+                    onchange: move |e| async move {
+                        let res = api::clock::rename(clock().id, e.value()).await;
+                        if let Err(e) = res {
+                            tracing::error!("Failed to rename clock: {e}");
+                        }
+                        on_change.call(());
+                    },
+                }
+                button {
+                    class: "text-sm text-muted-foreground hover:underline cursor-pointer",
+                    onclick: move |_| async move {
+                        let res = api::clock::delete(clock().id).await;
+                        if let Err(e) = res {
+                            tracing::error!("Failed to delete clock: {e}");
+                        }
+                        on_change.call(());
+                    },
+                    "Remove"
+                }
+            }
+        }
+    }
+}
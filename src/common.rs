@@ -56,3 +56,24 @@ pub fn ItemChecked(
         }
     }
 }
+
+#[component]
+pub fn DistrictSelect(
+    value: Option<types::DistrictId>,
+    set: EventHandler<Option<types::DistrictId>>,
+) -> Element {
+    rsx! {
+        select {
+            class: "p-1",
+            onchange: move |e| set.call(e.value().parse().ok()),
+            option { value: "", selected: value.is_none(), "No district" }
+            for (id , district) in types::districts::DISTRICTS.iter().enumerate() {
+                option {
+                    value: "{id}",
+                    selected: value == Some(id as types::DistrictId),
+                    "{district.name}"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use dioxus::prelude::*;
+use types::{specialty::UPGRADES, CharacterPreview, CrewAdvance, CrewXpAnswers};
+
+use super::end_of_session::Question;
+use crate::{
+    common::CountBtn,
+    elements::{Dialog, ErrorMessage},
+};
+
+#[component]
+pub fn CrewAdvancement(
+    crew: Signal<types::Crew>,
+    characters: ReadOnlySignal<Vec<CharacterPreview>>,
+    is_dm: ReadOnlySignal<bool>,
+) -> Element {
+    let claims =
+        use_resource(
+            move || async move { api::crew::get_claims(crew().id).await.unwrap_or_default() },
+        );
+
+    let mut xp_open = use_signal(|| false);
+    let mut advance_open = use_signal(|| false);
+    let mut develop_open = use_signal(|| false);
+
+    let turf = use_memo(move || crew().specialty.turf(&claims().unwrap_or_default()));
+    let rep_needed = use_memo(move || types::Crew::rep_needed(turf()));
+
+    rsx! {
+        div { class: "flex flex-row flex-wrap gap-4 items-center justify-between",
+            div { class: "flex flex-row gap-2 items-center",
+                span { class: "font-bold", "XP" }
+                div { class: "flex flex-row gap-1 items-center",
+                    for i in 1..=types::Crew::MAX_XP {
+                        CountBtn {
+                            this: i,
+                            total: crew().xp,
+                            readonly: true,
+                            set: move |_| {},
+                        }
+                    }
+                }
+                if is_dm() {
+                    button {
+                        class: "p-1 px-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                        onclick: move |_| xp_open.set(true),
+                        "Mark XP"
+                    }
+                }
+                if crew().can_advance() {
+                    button {
+                        class: "p-1 px-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        onclick: move |_| advance_open.set(true),
+                        "Advance"
+                    }
+                }
+            }
+            div { class: "flex flex-row gap-2 items-center",
+                span { class: "italic text-sm",
+                    "Develop at {rep_needed()} rep for {crew().development_cost()} coin"
+                }
+                button {
+                    class: "p-1 px-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                    onclick: move |_| develop_open.set(true),
+                    "Develop"
+                }
+            }
+        }
+
+        CrewXpDialog { open: xp_open, crew }
+        AdvanceDialog { open: advance_open, crew }
+        DevelopDialog { open: develop_open, crew, characters }
+    }
+}
+
+#[component]
+fn DevelopDialog(
+    open: Signal<bool>,
+    crew: Signal<types::Crew>,
+    characters: ReadOnlySignal<Vec<CharacterPreview>>,
+) -> Element {
+    let auth: crate::Auth = use_context();
+
+    let own = use_memo(move || {
+        characters()
+            .into_iter()
+            .filter(|c| auth.username().is_some_and(|u| u == c.player_id))
+            .collect::<Vec<_>>()
+    });
+
+    let mut contributions = use_signal(Vec::<(types::CharacterId, u8)>::new);
+    let mut error = use_signal(|| None as Option<String>);
+
+    let contribution = move |id: types::CharacterId| {
+        contributions()
+            .iter()
+            .find(|(c, _)| *c == id)
+            .map_or(0, |(_, coin)| *coin)
+    };
+    let contributed = use_memo(move || contributions().iter().map(|(_, c)| *c as u32).sum::<u32>());
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_36rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    match api::crew::develop_crew(crew().id, contributions()).await {
+                        Ok(developed) => {
+                            contributions.set(vec![]);
+                            error.set(None);
+                            open.set(false);
+                            crew.set(developed);
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to develop crew: {e:?}");
+                            error.set(Some("Failed to develop crew".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Develop" }
+
+                p {
+                    "Developing costs {crew().development_cost()} coin. The crew has {crew().coin}."
+                }
+
+                if !own().is_empty() {
+                    div { class: "grid grid-cols-[1fr_auto] gap-2 items-center",
+                        for character in own() {
+                            span { "From {character.name}'s stash" }
+                            input {
+                                class: "p-1 rounded-lg border border-border w-16",
+                                r#type: "number",
+                                min: "0",
+                                value: "{contribution(character.id)}",
+                                oninput: move |e| {
+                                    let Ok(coin) = e.value().parse::<u8>() else {
+                                        return;
+                                    };
+                                    contributions.with_mut(|list| {
+                                        list.retain(|(id, _)| *id != character.id);
+                                        list.push((character.id, coin));
+                                    });
+                                },
+                            }
+                        }
+                    }
+                    p { class: "italic text-sm", "Members put in {contributed} coin" }
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            error.set(None);
+                            open.set(false);
+                        },
+                        "Cancel"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Develop"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CrewXpDialog(open: Signal<bool>, crew: Signal<types::Crew>) -> Element {
+    let mut answers = use_signal(CrewXpAnswers::default);
+    let mut error = use_signal(|| None as Option<String>);
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_36rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    match api::crew::mark_crew_xp(crew().id, answers()).await {
+                        Ok(marked) => {
+                            answers.set(CrewXpAnswers::default());
+                            error.set(None);
+                            open.set(false);
+                            crew.set(marked);
+                        }
+                        Err(e) => {
+                            tracing::error!("Failed to mark crew XP: {e:?}");
+                            error.set(Some("Failed to mark XP".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Crew XP" }
+
+                Question {
+                    question: crew().specialty.xp_trigger().to_string(),
+                    value: answers().specialty,
+                    set: move |f| answers.with_mut(|a| a.specialty = f),
+                }
+                Question {
+                    question: CrewXpAnswers::CHALLENGES.to_string(),
+                    value: answers().challenges,
+                    set: move |f| answers.with_mut(|a| a.challenges = f),
+                }
+                Question {
+                    question: CrewXpAnswers::REPUTATION.to_string(),
+                    value: answers().reputation,
+                    set: move |f| answers.with_mut(|a| a.reputation = f),
+                }
+                Question {
+                    question: CrewXpAnswers::NATURE.to_string(),
+                    value: answers().nature,
+                    set: move |f| answers.with_mut(|a| a.nature = f),
+                }
+
+                p { "Mark {answers().marks()} XP" }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            error.set(None);
+                            open.set(false);
+                        },
+                        "Cancel"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Submit"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AdvanceDialog(open: Signal<bool>, crew: Signal<types::Crew>) -> Element {
+    let abilities = use_resource(move || async move {
+        api::crew::get_crew_abilities(crew().id)
+            .await
+            .unwrap_or_default()
+    });
+
+    let mut advance = use_signal(|| None as Option<CrewAdvance>);
+    let mut error = use_signal(|| None as Option<String>);
+
+    // Abilities the crew could take, leaving out other specialties without Veteran
+    let open_abilities = use_memo(move || {
+        let taken = abilities().unwrap_or_default();
+        let specialty = crew().specialty;
+        specialty
+            .abilities()
+            .iter()
+            .chain(
+                types::CrewSpecialty::ALL
+                    .iter()
+                    .filter(|s| **s != specialty)
+                    .flat_map(|s| s.abilities()),
+            )
+            .filter(|a| specialty.check_ability(a.name, &taken).is_ok())
+            .fold(Vec::<&'static str>::new(), |mut acc, a| {
+                if !acc.contains(&a.name) {
+                    acc.push(a.name);
+                }
+                acc
+            })
+    });
+
+    let picked = use_memo(move || match advance() {
+        Some(CrewAdvance::Upgrades(names)) => names,
+        _ => vec![],
+    });
+    let picked_list = use_memo(move || picked().join(", "));
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_36rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    let Some(chosen) = advance() else {
+                        error.set(Some("Pick an ability or upgrades".into()));
+                        return;
+                    };
+
+                    match api::crew::advance_crew(crew().id, chosen).await {
+                        Ok(advanced) => {
+                            advance.set(None);
+                            error.set(None);
+                            open.set(false);
+                            crew.set(advanced);
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to advance crew: {e:?}");
+                            error.set(Some("Failed to advance crew".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Advance" }
+
+                label { class: "flex flex-col gap-1",
+                    span { "Special Ability" }
+                    select {
+                        class: "p-1",
+                        onchange: move |e| {
+                            let name = e.value();
+                            advance.set((!name.is_empty()).then_some(CrewAdvance::Ability(name)));
+                        },
+                        option { value: "", "-" }
+                        for name in open_abilities() {
+                            option {
+                                value: "{name}",
+                                selected: advance() == Some(CrewAdvance::Ability(name.to_string())),
+                                "{name}"
+                            }
+                        }
+                    }
+                }
+
+                div { class: "flex flex-col gap-1",
+                    span { "Or tick {CrewAdvance::UPGRADE_BOXES} upgrade boxes" }
+                    div { class: "flex flex-row flex-wrap gap-2",
+                        for upgrade in crew().specialty.upgrades().iter().chain(UPGRADES.iter()) {
+                            button {
+                                class: if picked().iter().any(|p| p == upgrade.name) { "px-2 py-1 rounded-lg bg-primary text-primary-foreground cursor-pointer" } else { "px-2 py-1 rounded-lg bg-input cursor-pointer" },
+                                onclick: move |e| {
+                                    e.prevent_default();
+                                    let mut names = picked();
+                                    if names.len() >= CrewAdvance::UPGRADE_BOXES {
+                                        names.clear();
+                                    }
+                                    names.push(upgrade.name.to_string());
+                                    advance.set(Some(CrewAdvance::Upgrades(names)));
+                                },
+                                "{upgrade.name}"
+                            }
+                        }
+                    }
+                    if !picked().is_empty() {
+                        p { class: "italic text-sm", "{picked_list}" }
+                    }
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            error.set(None);
+                            open.set(false);
+                        },
+                        "Cancel"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Advance"
+                    }
+                }
+            }
+        }
+    }
+}
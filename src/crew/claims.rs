@@ -0,0 +1,139 @@
+use dioxus::prelude::*;
+use types::{ClaimPosition, CrewSpecialty};
+
+use crate::{
+    common::DistrictSelect,
+    elements::{Description, ErrorMessage},
+};
+
+#[component]
+pub fn Claims(crew: ReadOnlySignal<types::Crew>) -> Element {
+    let mut held =
+        use_resource(
+            move || async move { api::crew::get_claims(crew().id).await.unwrap_or_default() },
+        );
+
+    let mut districts = use_resource(move || async move {
+        api::crew::get_claim_districts(crew().id)
+            .await
+            .unwrap_or_default()
+    });
+
+    let mut error = use_signal(|| None as Option<String>);
+
+    let district_of = move |position: ClaimPosition| {
+        districts()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|(p, _)| *p == position)
+            .map(|(_, d)| d)
+    };
+
+    let held_now = held().unwrap_or_default();
+    let specialty = crew().specialty;
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            div { class: "flex flex-row justify-between items-center",
+                h3 { class: "text-lg underline", "Claims" }
+                span { class: "italic", "Turf: {specialty.turf(&held_now)}" }
+            }
+            div { class: "grid grid-cols-5 gap-2",
+                for (i , claim) in specialty.claims().iter().enumerate() {
+                    ClaimCell {
+                        name: claim.name,
+                        description: claim.description.clone(),
+                        state: if i as ClaimPosition == CrewSpecialty::LAIR || held_now.contains(&(i as ClaimPosition)) {
+                            ClaimState::Held
+                        } else if specialty.check_seize(&held_now, i as ClaimPosition).is_ok() {
+                            ClaimState::Open
+                        } else {
+                            ClaimState::Closed
+                        },
+                        onclick: move |state| async move {
+                            let position = i as ClaimPosition;
+                            let res = match state {
+                                ClaimState::Held if position != CrewSpecialty::LAIR => {
+                                    api::crew::lose_claim(crew().id, position)
+                                        .await
+                                        .map_err(|e| e.to_string())
+                                }
+                                ClaimState::Open => {
+                                    match api::crew::seize_claim(crew().id, position).await {
+                                        Err(ServerFnError::WrappedServerError(e)) => Err(e),
+                                        res => res.map_err(|e| e.to_string()),
+                                    }
+                                }
+                                _ => return,
+                            };
+                            match res {
+                                Ok(()) => {
+                                    error.set(None);
+                                    held.restart();
+                                }
+                                Err(e) => error.set(Some(e)),
+                            }
+                        },
+                    }
+                }
+            }
+            for position in held_now.clone() {
+                if let Some(claim) = specialty.claim(position) {
+                    div { class: "flex flex-row gap-2 items-center",
+                        span { class: "font-bold", "{claim.name}" }
+                        DistrictSelect {
+                            value: district_of(position),
+                            set: move |district| async move {
+                                match api::crew::set_claim_district(crew().id, position, district).await {
+                                    Ok(()) => {
+                                        error.set(None);
+                                        districts.restart();
+                                    }
+                                    Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                                    Err(e) => {
+                                        tracing::error!("Failed to set claim district: {e:?}");
+                                        error.set(Some("Failed to set district".into()));
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+            if let Some(error) = error() {
+                ErrorMessage { "{error}" }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ClaimState {
+    Held,
+    /// Next to a held claim, so it can be seized.
+    Open,
+    Closed,
+}
+
+#[component]
+fn ClaimCell(
+    name: &'static str,
+    description: types::Description<&'static str>,
+    state: ClaimState,
+    onclick: EventHandler<ClaimState>,
+) -> Element {
+    let class = match state {
+        ClaimState::Held => "bg-primary text-primary-foreground cursor-pointer",
+        ClaimState::Open => "border border-dashed border-primary cursor-pointer hover:bg-input",
+        ClaimState::Closed => "bg-muted text-muted-foreground cursor-not-allowed",
+    };
+
+    rsx! {
+        button {
+            class: "flex flex-col gap-1 p-2 rounded-lg text-left text-sm {class}",
+            onclick: move |_| onclick.call(state),
+            span { class: "font-bold", "{name}" }
+            Description { desc: description }
+        }
+    }
+}
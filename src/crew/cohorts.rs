@@ -0,0 +1,299 @@
+use dioxus::prelude::*;
+use types::{Cohort, CohortKind, Edge, Flaw, GangType, NewCohort};
+
+use crate::elements::{Dialog, ErrorMessage};
+
+#[component]
+pub fn Cohorts(crew: ReadOnlySignal<types::Crew>) -> Element {
+    let mut cohorts =
+        use_resource(
+            move || async move { api::crew::get_cohorts(crew().id).await.unwrap_or_default() },
+        );
+    let upgrades = use_resource(move || async move {
+        api::crew::get_crew_upgrades(crew().id)
+            .await
+            .unwrap_or_default()
+    });
+
+    let mut adding = use_signal(|| false);
+    let mut error = use_signal(|| None as Option<String>);
+
+    let mut update = move |res: Result<Cohort, ServerFnError<String>>| match res {
+        Ok(_) => {
+            error.set(None);
+            cohorts.restart();
+        }
+        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+        Err(e) => {
+            tracing::error!("Failed to update cohort: {e:?}");
+            error.set(Some("Failed to update cohort".into()));
+        }
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg underline", "Cohorts" }
+            for cohort in cohorts().unwrap_or_default() {
+                div { class: "flex flex-col gap-1 p-2 rounded-lg border border-border",
+                    div { class: "flex flex-row justify-between items-center gap-2",
+                        span {
+                            span { class: "font-bold", "{cohort.name}" }
+                            match &cohort.kind {
+                                CohortKind::Gang(gang_types) => rsx! {
+                                    span { class: "italic",
+                                        " ({list(gang_types)})"
+                                    }
+                                },
+                                CohortKind::Expert(kind) => rsx! {
+                                    span { class: "italic", " ({kind})" }
+                                },
+                            }
+                        }
+                        span { "Quality {cohort.quality(&crew(), &upgrades().unwrap_or_default())}" }
+                    }
+                    if !cohort.edges.is_empty() {
+                        p { class: "text-sm",
+                            "Edges: {list(&cohort.edges)}. "
+                            "Flaws: {list(&cohort.flaws)}."
+                        }
+                    }
+                    div { class: "flex flex-row flex-wrap justify-between items-center gap-2",
+                        span {
+                            span { class: "font-bold", "{cohort.harm}" }
+                            if cohort.armor {
+                                span { class: "italic", ", armored" }
+                            }
+                        }
+                        div { class: "flex flex-row gap-2",
+                            button {
+                                class: "p-1 px-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                                onclick: move |_| async move {
+                                    update(api::crew::damage_cohort(cohort.id, 1).await)
+                                },
+                                "Harm"
+                            }
+                            button {
+                                class: "p-1 px-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                                onclick: move |_| async move { update(api::crew::heal_cohort(cohort.id, 1).await) },
+                                "Heal"
+                            }
+                            button {
+                                class: "p-1 px-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                                onclick: move |_| async move {
+                                    match api::crew::remove_cohort(cohort.id).await {
+                                        Ok(()) => {
+                                            error.set(None);
+                                            cohorts.restart();
+                                        }
+                                        Err(e) => {
+                                            tracing::error!("Failed to remove cohort: {e:?}");
+                                            error.set(Some("Failed to remove cohort".into()));
+                                        }
+                                    }
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(error) = error() {
+                ErrorMessage { "{error}" }
+            }
+            div { class: "flex justify-end",
+                button {
+                    class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                    onclick: move |_| adding.set(true),
+                    "Add Cohort"
+                }
+            }
+        }
+
+        AddCohortDialog {
+            open: adding,
+            crew_id: crew().id,
+            on_add: move |_| cohorts.restart(),
+        }
+    }
+}
+
+#[component]
+fn AddCohortDialog(
+    open: Signal<bool>,
+    crew_id: ReadOnlySignal<types::CrewId>,
+    on_add: EventHandler<Cohort>,
+) -> Element {
+    let empty = || NewCohort {
+        name: String::new(),
+        kind: CohortKind::Gang(vec![]),
+        edges: vec![],
+        flaws: vec![],
+        armor: false,
+    };
+
+    let mut cohort = use_signal(empty);
+    let mut error = use_signal(|| None as Option<String>);
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_30rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    if let Err(e) = cohort().validate() {
+                        error.set(Some(e));
+                        return;
+                    }
+
+                    match api::crew::add_cohort(crew_id(), cohort()).await {
+                        Ok(c) => {
+                            on_add.call(c);
+                            cohort.set(empty());
+                            error.set(None);
+                            open.set(false);
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to add cohort: {e:?}");
+                            error.set(Some("Failed to add cohort".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Add Cohort" }
+                input {
+                    class: "p-1 rounded-lg border border-border",
+                    placeholder: "Name",
+                    value: "{cohort().name}",
+                    oninput: move |e| cohort.with_mut(|c| c.name = e.value()),
+                }
+                div { class: "flex flex-row gap-4",
+                    label { class: "flex flex-row gap-1 items-center",
+                        input {
+                            r#type: "radio",
+                            name: "kind",
+                            checked: matches!(cohort().kind, CohortKind::Gang(_)),
+                            onchange: move |_| cohort.with_mut(|c| c.kind = CohortKind::Gang(vec![])),
+                        }
+                        "Gang"
+                    }
+                    label { class: "flex flex-row gap-1 items-center",
+                        input {
+                            r#type: "radio",
+                            name: "kind",
+                            checked: matches!(cohort().kind, CohortKind::Expert(_)),
+                            onchange: move |_| cohort.with_mut(|c| c.kind = CohortKind::Expert(String::new())),
+                        }
+                        "Expert"
+                    }
+                }
+                match cohort().kind {
+                    CohortKind::Gang(gang_types) => rsx! {
+                        div { class: "flex flex-row flex-wrap gap-2",
+                            for t in GangType::ALL {
+                                Toggle {
+                                    on: gang_types.contains(&t),
+                                    onclick: move |_| {
+                                        cohort
+                                            .with_mut(|c| {
+                                                if let CohortKind::Gang(gang_types) = &mut c.kind {
+                                                    toggle(gang_types, t);
+                                                }
+                                            })
+                                    },
+                                    "{t}"
+                                }
+                            }
+                        }
+                    },
+                    CohortKind::Expert(kind) => rsx! {
+                        input {
+                            class: "p-1 rounded-lg border border-border",
+                            placeholder: "Occupation",
+                            value: "{kind}",
+                            oninput: move |e| cohort.with_mut(|c| c.kind = CohortKind::Expert(e.value())),
+                        }
+                    },
+                }
+                div { class: "flex flex-row flex-wrap gap-2 items-center",
+                    span { class: "font-bold", "Edges" }
+                    for edge in Edge::ALL {
+                        Toggle {
+                            on: cohort().edges.contains(&edge),
+                            onclick: move |_| cohort.with_mut(|c| toggle(&mut c.edges, edge)),
+                            "{edge}"
+                        }
+                    }
+                }
+                div { class: "flex flex-row flex-wrap gap-2 items-center",
+                    span { class: "font-bold", "Flaws" }
+                    for flaw in Flaw::ALL {
+                        Toggle {
+                            on: cohort().flaws.contains(&flaw),
+                            onclick: move |_| cohort.with_mut(|c| toggle(&mut c.flaws, flaw)),
+                            "{flaw}"
+                        }
+                    }
+                }
+                label { class: "flex flex-row gap-2 items-center",
+                    input {
+                        r#type: "checkbox",
+                        checked: cohort().armor,
+                        onchange: move |e| cohort.with_mut(|c| c.armor = e.checked()),
+                    }
+                    "Armored"
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            open.set(false);
+                        },
+                        "Cancel"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Add"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Toggle(on: bool, onclick: EventHandler<()>, children: Element) -> Element {
+    rsx! {
+        button {
+            class: if on { "px-2 py-1 rounded-lg bg-primary text-primary-foreground cursor-pointer" } else { "px-2 py-1 rounded-lg bg-input cursor-pointer" },
+            onclick: move |e| {
+                e.prevent_default();
+                onclick.call(());
+            },
+            {children}
+        }
+    }
+}
+
+fn toggle<T: PartialEq>(list: &mut Vec<T>, item: T) {
+    if let Some(i) = list.iter().position(|i| *i == item) {
+        list.remove(i);
+    } else {
+        list.push(item);
+    }
+}
+
+fn list<T: std::fmt::Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
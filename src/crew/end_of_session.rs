@@ -0,0 +1,216 @@
+use dioxus::prelude::*;
+use types::{CharacterPreview, TriggerFrequency, XpAnswers, XpTrack};
+
+use crate::elements::{Dialog, ErrorMessage};
+
+#[component]
+pub fn EndOfSession(
+    crew_id: ReadOnlySignal<types::CrewId>,
+    characters: ReadOnlySignal<Vec<CharacterPreview>>,
+    is_dm: ReadOnlySignal<bool>,
+) -> Element {
+    let mut end_of_session =
+        use_resource(move || async move { api::crew::get_end_of_session(crew_id()).await });
+
+    let mut answering = use_signal(|| None as Option<CharacterPreview>);
+    let mut answer_open = use_signal(|| false);
+
+    let auth: crate::Auth = use_context();
+
+    let current = match end_of_session() {
+        Some(Ok(current)) => current,
+        Some(Err(e)) => {
+            tracing::error!("Failed to load end of session: {e}");
+            None
+        }
+        None => None,
+    };
+
+    rsx! {
+        if let Some(current) = current {
+            div { class: "flex flex-col gap-2",
+                div { class: "flex flex-row justify-between items-center",
+                    h3 { class: "text-lg underline", "End of Session" }
+                    button {
+                        class: "text-sm text-muted-foreground hover:underline cursor-pointer",
+                        onclick: move |_| end_of_session.restart(),
+                        "Refresh"
+                    }
+                }
+                for character in characters() {
+                    div { class: "flex flex-row justify-between items-center gap-2",
+                        span { "{character.name}" }
+                        if current.answered.contains(&character.id) {
+                            span { class: "italic text-muted-foreground", "Answered" }
+                        } else if auth.username().is_some_and(|u| u == character.player_id) {
+                            button {
+                                class: "p-1 px-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                                onclick: move |_| {
+                                    answering.set(Some(character.clone()));
+                                    answer_open.set(true);
+                                },
+                                "Answer"
+                            }
+                        } else {
+                            span { class: "italic text-muted-foreground", "Waiting" }
+                        }
+                    }
+                }
+                if is_dm() {
+                    div { class: "flex justify-end",
+                        button {
+                            class: "p-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                            onclick: move |_| async move {
+                                if let Err(e) = api::crew::close_end_of_session(crew_id()).await {
+                                    tracing::error!("Failed to close end of session: {e}");
+                                }
+                                end_of_session.restart();
+                            },
+                            "Close"
+                        }
+                    }
+                }
+            }
+        } else if is_dm() {
+            div { class: "flex justify-end",
+                button {
+                    class: "p-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                    onclick: move |_| async move {
+                        if let Err(e) = api::crew::start_end_of_session(crew_id()).await {
+                            tracing::error!("Failed to start end of session: {e}");
+                        }
+                        end_of_session.restart();
+                    },
+                    "End Session"
+                }
+            }
+        }
+
+        if let Some(character) = answering() {
+            XpQuestions {
+                open: answer_open,
+                character,
+                on_answer: move |_| end_of_session.restart(),
+            }
+        }
+    }
+}
+
+#[component]
+fn XpQuestions(
+    open: Signal<bool>,
+    character: ReadOnlySignal<CharacterPreview>,
+    on_answer: EventHandler<types::XP>,
+) -> Element {
+    let mut answers = use_signal(|| XpAnswers::new(XpTrack::Playbook));
+    let mut error = use_signal(|| None as Option<String>);
+
+    let playbook_trigger = use_memo(move || character().class.xp_trigger());
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_36rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    match api::crew::answer_end_of_session(character().id, answers()).await {
+                        Ok(xp) => {
+                            answers.set(XpAnswers::new(XpTrack::Playbook));
+                            error.set(None);
+                            open.set(false);
+                            on_answer.call(xp);
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to answer end of session: {e:?}");
+                            error.set(Some("Failed to submit answers".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "End of Session: {character().name}" }
+
+                Question {
+                    question: playbook_trigger().to_string(),
+                    value: answers().playbook,
+                    set: move |f| answers.with_mut(|a| a.playbook = f),
+                }
+                Question {
+                    question: XpAnswers::BELIEFS.to_string(),
+                    value: answers().beliefs,
+                    set: move |f| answers.with_mut(|a| a.beliefs = f),
+                }
+                Question {
+                    question: XpAnswers::STRUGGLE.to_string(),
+                    value: answers().struggle,
+                    set: move |f| answers.with_mut(|a| a.struggle = f),
+                }
+
+                label { class: "flex flex-row gap-2 items-center",
+                    span { "Mark {answers().marks()} XP on" }
+                    select {
+                        class: "p-1",
+                        onchange: move |e| {
+                            if let Ok(track) = e.value().parse() {
+                                answers.with_mut(|a| a.track = track);
+                            }
+                        },
+                        for track in XpTrack::ALL {
+                            option {
+                                value: "{track}",
+                                selected: track == answers().track,
+                                "{track}"
+                            }
+                        }
+                    }
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            error.set(None);
+                            open.set(false);
+                        },
+                        "Cancel"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Submit"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub(super) fn Question(
+    question: String,
+    value: TriggerFrequency,
+    set: EventHandler<TriggerFrequency>,
+) -> Element {
+    rsx! {
+        div { class: "flex flex-col gap-1",
+            p { class: "italic", "{question}" }
+            div { class: "flex flex-row gap-2",
+                for frequency in TriggerFrequency::ALL {
+                    button {
+                        class: if value == frequency { "px-2 py-1 rounded-lg bg-primary text-primary-foreground cursor-pointer" } else { "px-2 py-1 rounded-lg bg-input cursor-pointer" },
+                        onclick: move |e| {
+                            e.prevent_default();
+                            set.call(frequency);
+                        },
+                        "{frequency}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use dioxus::prelude::*;
+use types::{factions::Faction, FactionId, FactionStatus};
+
+use crate::{clocks::Clocks, elements::ErrorMessage};
+
+#[component]
+pub fn Factions(crew: ReadOnlySignal<types::Crew>, is_dm: ReadOnlySignal<bool>) -> Element {
+    let mut statuses = use_resource(move || async move {
+        api::crew::get_faction_statuses(crew().id)
+            .await
+            .unwrap_or_default()
+    });
+
+    let mut error = use_signal(|| None as Option<String>);
+
+    let status = move |faction: FactionId| {
+        statuses()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|s| s.faction == faction)
+            .unwrap_or(FactionStatus { faction, status: 0 })
+    };
+
+    let at_war = use_memo(move || {
+        statuses()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| s.is_war())
+            .filter_map(|s| s.faction())
+            .map(|f| f.name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    });
+
+    let set = move |(faction, status): (FactionId, i8)| async move {
+        match api::crew::set_faction_status(crew().id, faction, status).await {
+            Ok(_) => {
+                error.set(None);
+                statuses.restart();
+            }
+            Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+            Err(e) => {
+                tracing::error!("Failed to set faction status: {e:?}");
+                error.set(Some("Failed to set faction status".into()));
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg underline", "Factions" }
+            if !at_war().is_empty() {
+                ErrorMessage { "At war with {at_war}" }
+            }
+            for category in Faction::categories() {
+                details {
+                    summary { class: "font-bold cursor-pointer", "{category}" }
+                    div { class: "flex flex-col gap-1 p-2",
+                        for (id , faction) in Faction::in_category(category) {
+                            FactionRow {
+                                faction: faction.clone(),
+                                status: status(id),
+                                readonly: !is_dm(),
+                                set: move |s| {
+                                    spawn(set((id, s)));
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(error) = error() {
+                ErrorMessage { "{error}" }
+            }
+            if is_dm() {
+                FactionClocks { crew_id: crew().id }
+            }
+        }
+    }
+}
+
+#[component]
+fn FactionRow(
+    faction: Faction,
+    status: FactionStatus,
+    readonly: bool,
+    set: EventHandler<i8>,
+) -> Element {
+    let label_class = if status.is_war() {
+        "italic text-destructive brightness-200"
+    } else {
+        "italic"
+    };
+    let shown = format!("{:+} {}", status.status, status.label());
+
+    rsx! {
+        div { class: "flex flex-row gap-2 items-center justify-between",
+            div { class: "flex flex-col",
+                span {
+                    span { class: "font-bold", "{faction.name}" }
+                    span { class: "text-sm italic", " Tier {faction.tier}, {faction.hold}" }
+                }
+                span { class: "text-sm", "{faction.description}" }
+            }
+            div { class: "flex flex-row gap-2 items-center",
+                if !readonly {
+                    button {
+                        class: "px-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                        disabled: status.status <= FactionStatus::MIN,
+                        onclick: move |_| set.call(status.status - 1),
+                        "-"
+                    }
+                }
+                span { class: label_class, "{shown}" }
+                if !readonly {
+                    button {
+                        class: "px-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                        disabled: status.status >= FactionStatus::MAX,
+                        onclick: move |_| set.call(status.status + 1),
+                        "+"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The GM's faction clocks, one faction at a time.
+#[component]
+fn FactionClocks(crew_id: ReadOnlySignal<types::CrewId>) -> Element {
+    let mut selected = use_signal(|| 0 as FactionId);
+    // Remounts the clocks after advancing so they reload
+    let mut generation = use_signal(|| 0usize);
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            div { class: "flex flex-row gap-2 items-center justify-between",
+                select {
+                    class: "p-1",
+                    onchange: move |e| {
+                        if let Ok(id) = e.value().parse() {
+                            selected.set(id);
+                        }
+                    },
+                    for (id , faction) in types::factions::FACTIONS.iter().enumerate() {
+                        option {
+                            value: "{id}",
+                            selected: id as FactionId == selected(),
+                            "{faction.name}"
+                        }
+                    }
+                }
+                button {
+                    class: "p-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                    onclick: move |_| async move {
+                        if let Err(e) = api::crew::advance_faction_clocks(crew_id()).await {
+                            tracing::error!("Failed to advance faction clocks: {e}");
+                        }
+                        generation.with_mut(|g| *g += 1);
+                    },
+                    "Advance Faction Clocks"
+                }
+            }
+            Clocks {
+                key: "{selected}-{generation}",
+                owner: types::ClockOwner::Faction(crew_id(), selected()),
+                readonly: false,
+            }
+        }
+    }
+}
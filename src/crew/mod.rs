@@ -3,7 +3,41 @@ use dioxus::prelude::*;
 mod create;
 pub use create::CreateCrew;
 
-use crate::{character::CreateCharacter, elements::Dialog};
+mod sheet;
+use sheet::CrewSheet;
+
+mod advancement;
+use advancement::CrewAdvancement;
+
+mod upgrades;
+use upgrades::{CrewAbilities, CrewUpgrades};
+
+mod cohorts;
+use cohorts::Cohorts;
+
+mod claims;
+use claims::Claims;
+
+mod factions;
+use factions::Factions;
+
+mod npcs;
+use npcs::Npcs;
+
+mod roll_log;
+use roll_log::RollLog;
+
+mod end_of_session;
+use end_of_session::EndOfSession;
+
+mod score;
+use score::ActiveScore;
+
+mod payoff;
+
+mod stress_log;
+
+use crate::{character::CreateCharacter, clocks::Clocks, elements::Dialog};
 
 #[component]
 pub fn Crew<R: 'static + Clone + PartialEq + Routable>(
@@ -15,6 +49,8 @@ pub fn Crew<R: 'static + Clone + PartialEq + Routable>(
         async move { api::crew::get_crew_characters(id).await.unwrap_or_default() }
     })?;
 
+    let mut sheet = use_signal(|| crew());
+
     let mut open_create_character = use_signal(|| false);
 
     let mut show_invites = use_signal(|| false);
@@ -30,6 +66,61 @@ pub fn Crew<R: 'static + Clone + PartialEq + Routable>(
             p { class: "italic", "{crew().specialty}" }
             }
 
+            CrewSheet { crew: sheet }
+            CrewAdvancement {
+                crew: sheet,
+                characters: crew_characters().unwrap_or_default(),
+                is_dm: is_dm(),
+            }
+
+            hr { class: "my-2" }
+
+            div { class: "grid grid-cols-1 lg:grid-cols-2 gap-4",
+                CrewAbilities { crew: sheet() }
+                CrewUpgrades { crew: sheet() }
+            }
+
+            hr { class: "my-2" }
+
+            Cohorts { crew: sheet() }
+
+            hr { class: "my-2" }
+
+            Claims { crew }
+
+            hr { class: "my-2" }
+
+            Factions { crew, is_dm: is_dm() }
+
+            hr { class: "my-2" }
+
+            Npcs { crew, is_dm: is_dm() }
+
+            hr { class: "my-2" }
+
+            ActiveScore {
+                crew_id: crew().id,
+                characters: crew_characters().unwrap_or_default(),
+                is_dm: is_dm(),
+                on_crew_change: move |crew| sheet.set(crew),
+            }
+
+            hr { class: "my-2" }
+
+            Clocks { owner: types::ClockOwner::Crew(crew().id), readonly: false }
+
+            hr { class: "my-2" }
+
+            RollLog { crew_id: crew().id }
+
+            hr { class: "my-2" }
+
+            EndOfSession {
+                crew_id: crew().id,
+                characters: crew_characters().unwrap_or_default(),
+                is_dm: is_dm(),
+            }
+
             if is_dm() {
                 div { class: "flex flex-row gap-4 items-center",
                     button {
@@ -39,6 +130,15 @@ pub fn Crew<R: 'static + Clone + PartialEq + Routable>(
                         },
                         "Invites"
                     }
+                    button {
+                        class: "p-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                        onclick: move |_| async move {
+                            if let Err(e) = api::downtime::start_downtime(crew().id).await {
+                                tracing::error!("Failed to start downtime: {e}");
+                            }
+                        },
+                        "Start Downtime"
+                    }
                 }
             }
 
@@ -73,14 +173,7 @@ pub fn Crew<R: 'static + Clone + PartialEq + Routable>(
 
         CreateCharacter {
             crew_id: crew().id,
-            on_create: move |new_character| async move {
-                let res = api::character::create(new_character).await;
-                if let Err(err) = res {
-                    tracing::error!("Failed to create crew: {:?}", err);
-                } else {
-                    crew_characters.restart();
-                }
-            },
+            on_create: move |_| crew_characters.restart(),
 
             open: open_create_character,
         }
@@ -0,0 +1,226 @@
+use dioxus::prelude::*;
+use types::{districts::District, factions::Faction, FactionStatus, Npc, NpcDetails};
+
+use crate::{
+    common::DistrictSelect,
+    elements::{Dialog, ErrorMessage},
+};
+
+#[component]
+pub fn Npcs(crew: ReadOnlySignal<types::Crew>, is_dm: ReadOnlySignal<bool>) -> Element {
+    let mut npcs =
+        use_resource(
+            move || async move { api::crew::get_npcs(crew().id).await.unwrap_or_default() },
+        );
+
+    let mut editing = use_signal(|| None as Option<Npc>);
+    let mut open = use_signal(|| false);
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg underline", "NPCs" }
+            for npc in npcs().unwrap_or_default() {
+                NpcRow {
+                    key: "{npc.id}",
+                    npc: npc.clone(),
+                    is_dm,
+                    on_edit: move |npc| {
+                        editing.set(Some(npc));
+                        open.set(true);
+                    },
+                    on_remove: move |_| npcs.restart(),
+                }
+            }
+            div { class: "flex justify-end",
+                button {
+                    class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                    onclick: move |_| {
+                        editing.set(None);
+                        open.set(true);
+                    },
+                    "Add NPC"
+                }
+            }
+        }
+
+        NpcDialog {
+            open,
+            crew_id: crew().id,
+            npc: editing(),
+            on_save: move |_| npcs.restart(),
+        }
+    }
+}
+
+#[component]
+fn NpcRow(
+    npc: ReadOnlySignal<Npc>,
+    is_dm: ReadOnlySignal<bool>,
+    on_edit: EventHandler<Npc>,
+    on_remove: EventHandler,
+) -> Element {
+    let faction = use_memo(move || npc().faction.and_then(Faction::find).map(|f| f.name));
+    let district = use_memo(move || npc().district.and_then(District::find).map(|d| d.name));
+    let place = use_memo(move || {
+        [faction(), district()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(", ")
+    });
+
+    rsx! {
+        div { class: "flex flex-row gap-2 items-center justify-between",
+            div { class: "flex flex-col",
+                span {
+                    span { class: "font-bold", "{npc().name}" }
+                    if !place().is_empty() {
+                        span { class: "text-sm italic", " {place}" }
+                    }
+                }
+                if !npc().description.is_empty() {
+                    span { class: "text-sm", "{npc().description}" }
+                }
+            }
+            div { class: "flex flex-row gap-2 items-center",
+                span { class: "italic", "{npc().status_label()}" }
+                button {
+                    class: "text-sm text-muted-foreground hover:underline cursor-pointer",
+                    onclick: move |_| on_edit.call(npc()),
+                    "Edit"
+                }
+                if is_dm() {
+                    button {
+                        class: "text-sm text-muted-foreground hover:underline cursor-pointer",
+                        onclick: move |_| async move {
+                            if let Err(e) = api::crew::remove_npc(npc().id).await {
+                                tracing::error!("Failed to remove NPC: {e}");
+                            }
+                            on_remove.call(());
+                        },
+                        "Remove"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NpcDialog(
+    open: Signal<bool>,
+    crew_id: ReadOnlySignal<types::CrewId>,
+    npc: ReadOnlySignal<Option<Npc>>,
+    on_save: EventHandler<Npc>,
+) -> Element {
+    let mut details = use_signal(NpcDetails::default);
+    let mut error = use_signal(|| None as Option<String>);
+
+    // Start from the entry being edited, or a blank one
+    use_effect(move || {
+        details.set(npc().map(Into::into).unwrap_or_default());
+    });
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_30rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    if let Err(e) = details().validate() {
+                        error.set(Some(e));
+                        return;
+                    }
+
+                    let res = match npc() {
+                        Some(npc) => api::crew::update_npc(npc.id, details()).await,
+                        None => api::crew::add_npc(crew_id(), details()).await,
+                    };
+
+                    match res {
+                        Ok(npc) => {
+                            details.set(NpcDetails::default());
+                            error.set(None);
+                            open.set(false);
+                            on_save.call(npc);
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to save NPC: {e:?}");
+                            error.set(Some("Failed to save NPC".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "NPC" }
+                input {
+                    class: "bg-input p-2 rounded text-input-foreground",
+                    placeholder: "Name",
+                    value: "{details().name}",
+                    oninput: move |e| details.with_mut(|d| d.name = e.value()),
+                }
+                textarea {
+                    class: "bg-input p-2 rounded text-input-foreground",
+                    placeholder: "Description",
+                    value: "{details().description}",
+                    oninput: move |e| details.with_mut(|d| d.description = e.value()),
+                }
+                select {
+                    class: "p-1",
+                    onchange: move |e| details.with_mut(|d| d.faction = e.value().parse().ok()),
+                    option { value: "", selected: details().faction.is_none(), "No faction" }
+                    for (id , faction) in types::factions::FACTIONS.iter().enumerate() {
+                        option {
+                            value: "{id}",
+                            selected: details().faction == Some(id as types::FactionId),
+                            "{faction.name}"
+                        }
+                    }
+                }
+                DistrictSelect {
+                    value: details().district,
+                    set: move |d| details.with_mut(|details| details.district = d),
+                }
+                label { class: "flex flex-row gap-2 items-center",
+                    span { "Status" }
+                    select {
+                        class: "p-1",
+                        onchange: move |e| {
+                            if let Ok(status) = e.value().parse() {
+                                details.with_mut(|d| d.status = status);
+                            }
+                        },
+                        for status in FactionStatus::MIN..=FactionStatus::MAX {
+                            option {
+                                value: "{status}",
+                                selected: details().status == status,
+                                {FactionStatus::label_for(status)}
+                            }
+                        }
+                    }
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            error.set(None);
+                            open.set(false);
+                        },
+                        "Cancel"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Save"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use dioxus::prelude::*;
+use types::{Exposure, Payoff, PayoffResult};
+
+use crate::elements::{Dialog, ErrorMessage};
+
+#[component]
+pub fn PayoffDialog(
+    open: Signal<bool>,
+    score: ReadOnlySignal<types::Score>,
+    on_paid: EventHandler<types::Crew>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut payoff = use_signal(Payoff::new);
+    let mut result = use_signal(|| None as Option<PayoffResult>);
+    let mut error = use_signal(|| None as Option<String>);
+
+    let crew_coin = use_memo(move || payoff().crew_coin(score().characters.len()));
+
+    let mut close = move || {
+        if result().is_some() {
+            on_close.call(());
+        }
+        payoff.set(Payoff::new());
+        result.set(None);
+        error.set(None);
+        open.set(false);
+    };
+
+    rsx! {
+        Dialog { open, close_on_click: false,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_30rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    match api::score::pay_off(score().id, payoff()).await {
+                        Ok(r) => {
+                            error.set(None);
+                            on_paid.call(r.crew.clone());
+                            result.set(Some(r));
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to pay out score: {e:?}");
+                            error.set(Some("Failed to pay out score".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Payoff: {score().target}" }
+
+                if let Some(result) = result() {
+                    p { "The crew takes {result.crew_coin} coin and {result.heat} heat." }
+                    p {
+                        "Entanglement roll: "
+                        span { class: "font-mono", "{result.entanglement.dice:?}" }
+                    }
+                    for entanglement in result.entanglement.options {
+                        div {
+                            span { class: "font-bold", "{entanglement}" }
+                            p { class: "text-sm italic", "{entanglement.description()}" }
+                        }
+                    }
+                    div { class: "flex justify-end",
+                        button {
+                            class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                            onclick: move |e| {
+                                e.prevent_default();
+                                close();
+                            },
+                            "Done"
+                        }
+                    }
+                } else {
+                    div { class: "grid grid-cols-[auto_1fr] gap-2 items-center",
+                        NumberField {
+                            label: "Coin",
+                            value: payoff().coin,
+                            set: move |v| payoff.with_mut(|p| p.coin = v),
+                        }
+                        NumberField {
+                            label: "Share per character",
+                            value: payoff().share,
+                            set: move |v| payoff.with_mut(|p| p.share = v),
+                        }
+                        NumberField {
+                            label: "Rep",
+                            value: payoff().rep,
+                            set: move |v| payoff.with_mut(|p| p.rep = v),
+                        }
+                        span { "Exposure" }
+                        select {
+                            onchange: move |e| {
+                                if let Ok(x) = e.value().parse() {
+                                    payoff.with_mut(|p| p.exposure = x);
+                                }
+                            },
+                            for x in Exposure::ALL {
+                                option { value: "{x}", selected: x == payoff().exposure, "{x}" }
+                            }
+                        }
+                    }
+                    div { class: "flex flex-col gap-1",
+                        FlagField {
+                            label: "High profile or well-connected target",
+                            checked: payoff().high_profile,
+                            set: move |v| payoff.with_mut(|p| p.high_profile = v),
+                        }
+                        FlagField {
+                            label: "On hostile turf",
+                            checked: payoff().hostile_turf,
+                            set: move |v| payoff.with_mut(|p| p.hostile_turf = v),
+                        }
+                        FlagField {
+                            label: "At war",
+                            checked: payoff().at_war,
+                            set: move |v| payoff.with_mut(|p| p.at_war = v),
+                        }
+                        FlagField {
+                            label: "A killing was involved",
+                            checked: payoff().killing,
+                            set: move |v| payoff.with_mut(|p| p.killing = v),
+                        }
+                    }
+                    match crew_coin() {
+                        Ok(coin) => rsx! {
+                            p { class: "italic text-muted-foreground",
+                                "{coin} coin to the crew, {payoff().heat()} heat"
+                            }
+                        },
+                        Err(e) => rsx! {
+                            ErrorMessage { "{e}" }
+                        },
+                    }
+
+                    if let Some(error) = error() {
+                        ErrorMessage { "{error}" }
+                    }
+
+                    div { class: "flex flex-row justify-between gap-4",
+                        button {
+                            class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                            onclick: move |e| {
+                                e.prevent_default();
+                                close();
+                            },
+                            "Cancel"
+                        }
+                        button {
+                            class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                            r#type: "submit",
+                            "Pay Out"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NumberField(label: &'static str, value: u8, set: EventHandler<u8>) -> Element {
+    rsx! {
+        span { "{label}" }
+        input {
+            class: "p-1 rounded-lg border border-border w-16",
+            r#type: "number",
+            min: "0",
+            value: "{value}",
+            oninput: move |e| {
+                if let Ok(v) = e.value().parse() {
+                    set.call(v);
+                }
+            },
+        }
+    }
+}
+
+#[component]
+fn FlagField(label: &'static str, checked: bool, set: EventHandler<bool>) -> Element {
+    rsx! {
+        label { class: "flex flex-row gap-2 items-center",
+            input {
+                r#type: "checkbox",
+                checked,
+                onchange: move |e| set.call(e.checked()),
+            }
+            "{label}"
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use dioxus::prelude::*;
+use types::roll::{Outcome, RollLogEntry};
+
+const PAGE: u8 = 20;
+
+#[component]
+pub fn RollLog(crew_id: ReadOnlySignal<types::CrewId>) -> Element {
+    let mut entries = use_signal(Vec::<RollLogEntry>::new);
+    let mut more = use_signal(|| true);
+
+    use_future(move || async move {
+        let crew_id = crew_id();
+
+        match api::crew::get_roll_log(crew_id, None, PAGE).await {
+            Ok(page) => {
+                more.set(page.len() == PAGE as usize);
+                entries.set(page);
+            }
+            Err(e) => {
+                tracing::error!("Failed to load roll log: {e}");
+                return;
+            }
+        }
+
+        loop {
+            let after = entries.peek().first().map(|e| e.id).unwrap_or(0);
+
+            match api::crew::wait_for_rolls(crew_id, after).await {
+                Ok(new) => {
+                    if !new.is_empty() {
+                        entries.with_mut(|entries| {
+                            entries.splice(0..0, new);
+                        });
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("Failed to wait for rolls: {e}");
+                    return;
+                }
+            }
+        }
+    });
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg underline", "Rolls" }
+            div { class: "flex flex-col gap-1 max-h-96 overflow-y-auto",
+                for entry in entries() {
+                    RollLogRow { key: "{entry.id}", entry }
+                }
+                if entries().is_empty() {
+                    p { class: "italic text-muted-foreground", "No rolls yet" }
+                }
+            }
+            if more() && !entries().is_empty() {
+                button {
+                    class: "text-sm text-muted-foreground hover:underline cursor-pointer",
+                    onclick: move |_| async move {
+                        let before = entries.peek().last().map(|e| e.id);
+                        match api::crew::get_roll_log(crew_id(), before, PAGE).await {
+                            Ok(page) => {
+                                more.set(page.len() == PAGE as usize);
+                                entries.with_mut(|entries| entries.extend(page));
+                            }
+                            Err(e) => {
+                                tracing::error!("Failed to load roll log: {e}");
+                            }
+                        }
+                    },
+                    "Load more"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn RollLogRow(entry: RollLogEntry) -> Element {
+    let color = match entry.outcome {
+        Outcome::Critical | Outcome::Success => "text-primary",
+        Outcome::Partial => "text-muted-foreground",
+        Outcome::Failure => "text-destructive brightness-200",
+    };
+
+    let dice = entry
+        .dice
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let roller = entry.character_name.as_deref().unwrap_or("Crew");
+
+    rsx! {
+        div { class: "flex flex-row flex-wrap gap-2 items-center p-1 rounded hover:bg-input",
+            span { class: "font-bold", "{roller}" }
+            span { class: "italic", "{entry.label}" }
+            span { class: "font-mono", "[{dice}]" }
+            if entry.zero_dice {
+                span { class: "text-sm text-muted-foreground", "(lowest)" }
+            }
+            span { class: "font-bold {color}", "{entry.outcome}" }
+            if let Some(stress) = entry.stress {
+                if stress < 0 {
+                    span { class: "text-sm italic", "clears {-stress} stress" }
+                } else {
+                    span { class: "text-sm italic", "{stress} stress" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use dioxus::prelude::*;
+use types::{CharacterPreview, PlanType, Score, ScorePlan, ScoreStatus};
+
+use super::{payoff::PayoffDialog, stress_log::ScoreStress};
+use crate::{
+    common::DistrictSelect,
+    elements::{Dialog, ErrorMessage},
+};
+
+#[component]
+pub fn ActiveScore(
+    crew_id: ReadOnlySignal<types::CrewId>,
+    characters: ReadOnlySignal<Vec<CharacterPreview>>,
+    is_dm: ReadOnlySignal<bool>,
+    on_crew_change: EventHandler<types::Crew>,
+) -> Element {
+    let mut score =
+        use_resource(move || async move { api::score::get_active_score(crew_id()).await });
+
+    let mut planning = use_signal(|| false);
+    let mut paying = use_signal(|| false);
+    let mut engagement_dice = use_signal(|| 1u8);
+    let max_dice = types::roll::Roll::MAX_DICE;
+    let mut error = use_signal(|| None as Option<String>);
+
+    let current = match score() {
+        Some(Ok(current)) => current,
+        Some(Err(e)) => {
+            tracing::error!("Failed to load score: {e}");
+            None
+        }
+        None => None,
+    };
+
+    let name_of = move |id: types::CharacterId| {
+        characters()
+            .into_iter()
+            .find(|c| c.id == id)
+            .map(|c| c.name)
+            .unwrap_or_default()
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg underline", "Score" }
+            if let Some(current) = current.clone() {
+                div { class: "flex flex-row justify-between items-center gap-2",
+                    h4 { class: "text-xl", "{current.target}" }
+                    span { class: "italic", "{current.status}" }
+                }
+                if let Some(district) = current.district.and_then(types::districts::District::find) {
+                    p { class: "italic", "{district.name}" }
+                }
+                p {
+                    span { class: "font-bold", "{current.plan} plan. " }
+                    "{current.plan.detail()}: {current.detail}"
+                }
+                p { class: "text-sm",
+                    "Going: "
+                    {current.characters.iter().map(|&id| name_of(id)).collect::<Vec<_>>().join(", ")}
+                }
+
+                if let Some(engagement) = current.engagement.clone() {
+                    p {
+                        span { class: "font-mono", "{engagement.dice:?} " }
+                        span { class: "font-bold", "{engagement.outcome}. " }
+                        "{engagement.describe()}"
+                    }
+                }
+
+                if is_dm() && current.status != ScoreStatus::Planning {
+                    ScoreStress { score: current.clone(), characters }
+                }
+
+                match current.status {
+                    ScoreStatus::Planning => rsx! {
+                        div { class: "flex flex-row gap-2 justify-end items-center",
+                            button {
+                                class: "p-2 bg-muted text-muted-foreground rounded-lg cursor-pointer",
+                                onclick: move |_| planning.set(true),
+                                "Edit Plan"
+                            }
+                            if is_dm() {
+                                input {
+                                    class: "p-1 rounded-lg border border-border w-16",
+                                    r#type: "number",
+                                    min: "0",
+                                    max: "{max_dice}",
+                                    value: "{engagement_dice}",
+                                    oninput: move |e| {
+                                        if let Ok(d) = e.value().parse() {
+                                            engagement_dice.set(d);
+                                        }
+                                    },
+                                }
+                                button {
+                                    class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                                    onclick: move |_| async move {
+                                        match api::score::roll_engagement(current.id, engagement_dice()).await {
+                                            Ok(_) => {
+                                                error.set(None);
+                                                score.restart();
+                                            }
+                                            Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                                            Err(e) => {
+                                                tracing::error!("Failed to roll engagement: {e:?}");
+                                                error.set(Some("Failed to roll engagement".into()));
+                                            }
+                                        }
+                                    },
+                                    "Roll Engagement"
+                                }
+                            }
+                        }
+                    },
+                    ScoreStatus::Active if is_dm() => rsx! {
+                        div { class: "flex justify-end",
+                            button {
+                                class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                                onclick: move |_| async move {
+                                    match api::score::complete_score(current.id).await {
+                                        Ok(()) => {
+                                            error.set(None);
+                                            score.restart();
+                                        }
+                                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                                        Err(e) => {
+                                            tracing::error!("Failed to complete score: {e:?}");
+                                            error.set(Some("Failed to complete score".into()));
+                                        }
+                                    }
+                                },
+                                "Complete Score"
+                            }
+                        }
+                    },
+                    ScoreStatus::Completed if !current.paid_out => rsx! {
+                        if is_dm() {
+                            div { class: "flex justify-end",
+                                button {
+                                    class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                                    onclick: move |_| paying.set(true),
+                                    "Payoff"
+                                }
+                            }
+                            PayoffDialog {
+                                open: paying,
+                                score: current.clone(),
+                                on_paid: move |crew| on_crew_change.call(crew),
+                                on_close: move |_| score.restart(),
+                            }
+                        } else {
+                            p { class: "italic text-muted-foreground", "Awaiting payoff" }
+                        }
+                    },
+                    _ => rsx! {},
+                }
+            } else {
+                p { class: "italic text-muted-foreground", "No score underway" }
+                div { class: "flex justify-end",
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        onclick: move |_| planning.set(true),
+                        "Plan Score"
+                    }
+                }
+            }
+
+            if let Some(error) = error() {
+                ErrorMessage { "{error}" }
+            }
+        }
+
+        PlanDialog {
+            open: planning,
+            crew_id,
+            characters,
+            score: current,
+            on_save: move |_| score.restart(),
+        }
+    }
+}
+
+#[component]
+fn PlanDialog(
+    open: Signal<bool>,
+    crew_id: ReadOnlySignal<types::CrewId>,
+    characters: ReadOnlySignal<Vec<CharacterPreview>>,
+    score: ReadOnlySignal<Option<Score>>,
+    on_save: EventHandler<Score>,
+) -> Element {
+    let empty = || ScorePlan {
+        target: String::new(),
+        plan: PlanType::Assault,
+        detail: String::new(),
+        district: None,
+        characters: vec![],
+    };
+
+    let mut plan = use_signal(empty);
+    let mut error = use_signal(|| None as Option<String>);
+
+    // Start from the current plan whenever the score changes
+    use_effect(move || {
+        plan.set(match score() {
+            Some(score) => ScorePlan {
+                target: score.target,
+                plan: score.plan,
+                detail: score.detail,
+                district: score.district,
+                characters: score.characters,
+            },
+            None => empty(),
+        });
+    });
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            form {
+                class: "flex flex-col gap-4 w-[min(90vw,_30rem)]",
+                onsubmit: move |e| async move {
+                    e.prevent_default();
+
+                    if let Err(e) = plan().validate() {
+                        error.set(Some(e));
+                        return;
+                    }
+
+                    let res = match score() {
+                        Some(score) => api::score::update_plan(score.id, plan()).await,
+                        None => api::score::create_score(crew_id(), plan()).await,
+                    };
+
+                    match res {
+                        Ok(score) => {
+                            error.set(None);
+                            open.set(false);
+                            on_save.call(score);
+                        }
+                        Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                        Err(e) => {
+                            tracing::error!("Failed to save score: {e:?}");
+                            error.set(Some("Failed to save score".into()));
+                        }
+                    }
+                },
+                h2 { class: "text-2xl font-bold", "Plan Score" }
+                input {
+                    class: "bg-input p-2 rounded text-input-foreground",
+                    placeholder: "Target",
+                    value: "{plan().target}",
+                    oninput: move |e| plan.with_mut(|p| p.target = e.value()),
+                }
+                select {
+                    onchange: move |e| {
+                        if let Ok(p) = e.value().parse() {
+                            plan.with_mut(|plan| plan.plan = p);
+                        }
+                    },
+                    for p in PlanType::ALL {
+                        option { value: "{p}", selected: p == plan().plan, "{p}" }
+                    }
+                }
+                input {
+                    class: "bg-input p-2 rounded text-input-foreground",
+                    placeholder: "{plan().plan.detail()}",
+                    value: "{plan().detail}",
+                    oninput: move |e| plan.with_mut(|p| p.detail = e.value()),
+                }
+                DistrictSelect {
+                    value: plan().district,
+                    set: move |d| plan.with_mut(|p| p.district = d),
+                }
+                div { class: "flex flex-col gap-1",
+                    span { "Going on the score" }
+                    for character in characters() {
+                        label { class: "flex flex-row gap-2 items-center",
+                            input {
+                                r#type: "checkbox",
+                                checked: plan().characters.contains(&character.id),
+                                onchange: move |_| {
+                                    plan.with_mut(|p| {
+                                        if let Some(i) = p.characters.iter().position(|&c| c == character.id) {
+                                            p.characters.remove(i);
+                                        } else {
+                                            p.characters.push(character.id);
+                                        }
+                                    })
+                                },
+                            }
+                            "{character.name}"
+                        }
+                    }
+                }
+
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+
+                div { class: "flex flex-row justify-between gap-4",
+                    button {
+                        class: "p-2 bg-secondary text-secondary-foreground rounded-lg cursor-pointer",
+                        onclick: move |e| {
+                            e.prevent_default();
+                            error.set(None);
+                            open.set(false);
+                        },
+                        "Cancel"
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        "Save"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use dioxus::prelude::*;
+
+use crate::common::{CountBtn, ItemChecked};
+
+#[component]
+pub fn CrewSheet(crew: Signal<types::Crew>) -> Element {
+    let reputation = use_memo(move || crew().reputation);
+    let heat = use_memo(move || crew().heat);
+    let wanted_level = use_memo(move || crew().wanted_level);
+    let tier = use_memo(move || crew().tier);
+    let hold = use_memo(move || crew().hold);
+    let coin = use_memo(move || crew().coin);
+    let vaults = use_memo(move || crew().vaults);
+
+    let mut first_load = use_signal(|| true);
+
+    use_effect(move || {
+        let id = crew.peek().id;
+        let reputation = reputation();
+
+        if !*first_load.peek() {
+            spawn(async move {
+                let _res = api::crew::set_reputation(id, reputation).await;
+                #[cfg(debug_assertions)]
+                {
+                    if let Err(e) = _res {
+                        tracing::error!("Failed to set reputation: {e}");
+                    }
+                }
+            });
+        }
+    });
+
+    use_effect(move || {
+        let id = crew.peek().id;
+        let heat = heat();
+        let wanted_level = wanted_level();
+
+        if !*first_load.peek() {
+            spawn(async move {
+                let _res = api::crew::set_heat_wanted(id, heat, wanted_level).await;
+                #[cfg(debug_assertions)]
+                {
+                    if let Err(e) = _res {
+                        tracing::error!("Failed to set heat/wanted level: {e}");
+                    }
+                }
+            });
+        }
+    });
+
+    use_effect(move || {
+        let id = crew.peek().id;
+        let tier = tier();
+        let hold = hold();
+
+        if !*first_load.peek() {
+            spawn(async move {
+                let _res = api::crew::set_tier_hold(id, tier, hold).await;
+                #[cfg(debug_assertions)]
+                {
+                    if let Err(e) = _res {
+                        tracing::error!("Failed to set tier/hold: {e}");
+                    }
+                }
+            });
+        }
+    });
+
+    use_effect(move || {
+        let id = crew.peek().id;
+        let coin = coin();
+        let vaults = vaults();
+
+        if !*first_load.peek() {
+            spawn(async move {
+                let _res = api::crew::set_coin_vaults(id, coin, vaults).await;
+                #[cfg(debug_assertions)]
+                {
+                    if let Err(e) = _res {
+                        tracing::error!("Failed to set coin/vaults: {e}");
+                    }
+                }
+            });
+        }
+    });
+
+    use_effect(move || {
+        first_load.set(false);
+    });
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            div { class: "flex flex-row flex-wrap gap-4 items-center justify-between",
+                Track {
+                    name: "Rep",
+                    max: types::Crew::MAX_REPUTATION,
+                    current: reputation(),
+                    set: move |r| crew.with_mut(|c| c.reputation = r),
+                }
+                Track {
+                    name: "Heat",
+                    max: types::Crew::MAX_HEAT,
+                    current: heat(),
+                    set: move |h| crew.with_mut(|c| c.heat = h),
+                }
+                Track {
+                    name: "Wanted Level",
+                    max: types::Crew::MAX_WANTED_LEVEL,
+                    current: wanted_level(),
+                    set: move |w| crew.with_mut(|c| c.wanted_level = w),
+                }
+            }
+            div { class: "flex flex-row flex-wrap gap-4 items-center justify-between",
+                Track {
+                    name: "Tier",
+                    max: types::Crew::MAX_TIER,
+                    current: tier(),
+                    set: move |t| crew.with_mut(|c| c.tier = t),
+                }
+                div { class: "flex flex-row gap-2 items-center",
+                    span { class: "font-bold", "Hold" }
+                    for h in types::Hold::ALL {
+                        div { class: "flex flex-row gap-1 items-center",
+                            ItemChecked {
+                                checked: hold() == h,
+                                readonly: false,
+                                onclick: move |_| crew.with_mut(|c| c.hold = h),
+                            }
+                            span { class: "italic", "{h}" }
+                        }
+                    }
+                }
+                div { class: "flex flex-row gap-2 items-center",
+                    span { class: "font-bold", "Coin" }
+                    div { class: "flex flex-row gap-1 items-center",
+                        for i in 1..=types::Crew::coin_capacity_with(vaults()) {
+                            CountBtn {
+                                this: i,
+                                total: coin(),
+                                set: move |c| crew.with_mut(|crew| crew.coin = c),
+                            }
+                        }
+                    }
+                    span { class: "italic", "Vaults" }
+                    for v in 1..=types::Crew::MAX_VAULTS {
+                        ItemChecked {
+                            checked: vaults() >= v,
+                            readonly: false,
+                            onclick: move |set| {
+                                crew.with_mut(|c| {
+                                    c.vaults = if set { v } else { v - 1 };
+                                    c.coin = c.coin.min(c.coin_capacity());
+                                })
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Track(name: &'static str, max: u8, current: u8, set: EventHandler<u8>) -> Element {
+    rsx! {
+        div { class: "flex flex-row gap-2 items-center",
+            span { class: "font-bold", "{name}" }
+            div { class: "flex flex-row gap-1 items-center",
+                for i in 1..=max {
+                    CountBtn { this: i, total: current, set }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use dioxus::prelude::*;
+use types::{CharacterPreview, Score, ScoreStatus, StressGain, StressSpend, Trauma};
+
+use crate::elements::ErrorMessage;
+
+/// The stress marked on a score, for the GM to review, along with flashbacks at a cost of
+/// their choosing.
+#[component]
+pub fn ScoreStress(
+    score: ReadOnlySignal<Score>,
+    characters: ReadOnlySignal<Vec<CharacterPreview>>,
+) -> Element {
+    let mut log = use_resource(move || async move { api::score::get_stress_log(score().id).await });
+
+    let mut character = use_signal(|| None as Option<types::CharacterId>);
+    let mut cost = use_signal(|| 1u8);
+    let mut note = use_signal(String::new);
+    let mut trauma_needed = use_signal(|| false);
+    let mut trauma = use_signal(|| None as Option<Trauma>);
+    let mut error = use_signal(|| None as Option<String>);
+
+    let name_of = move |id: types::CharacterId| {
+        characters()
+            .into_iter()
+            .find(|c| c.id == id)
+            .map(|c| c.name)
+            .unwrap_or_default()
+    };
+
+    let entries = match log() {
+        Some(Ok(entries)) => entries,
+        Some(Err(e)) => {
+            tracing::error!("Failed to load stress log: {e}");
+            vec![]
+        }
+        None => vec![],
+    };
+
+    let totals = score()
+        .characters
+        .into_iter()
+        .map(|id| {
+            let total: u32 = entries
+                .iter()
+                .filter(|e| e.character_id == id)
+                .map(|e| e.spend.stress() as u32)
+                .sum();
+            (name_of(id), total)
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h4 { class: "font-bold", "Stress" }
+            div { class: "flex flex-row flex-wrap gap-4 text-sm",
+                for (name , total) in totals {
+                    span { "{name}: {total}" }
+                }
+            }
+            for entry in entries {
+                p { class: "text-sm",
+                    span { class: "font-bold", "{name_of(entry.character_id)}" }
+                    " {entry.spend} ({entry.spend.stress()} stress)"
+                    if !entry.note.is_empty() {
+                        span { class: "italic", ": {entry.note}" }
+                    }
+                }
+            }
+
+            if score().status == ScoreStatus::Active {
+                form {
+                    class: "flex flex-row flex-wrap gap-2 items-center",
+                    onsubmit: move |e| async move {
+                        e.prevent_default();
+
+                        let Some(id) = character() else {
+                            error.set(Some("Choose who flashes back".into()));
+                            return;
+                        };
+                        if trauma_needed() && trauma().is_none() {
+                            error.set(Some("Choose a trauma".into()));
+                            return;
+                        }
+
+                        let spend = StressSpend::Flashback { cost: cost() };
+                        match api::character::spend_stress(id, spend, note(), trauma()).await {
+                            Ok(StressGain::TraumaNeeded) => {
+                                error.set(None);
+                                trauma_needed.set(true);
+                            }
+                            Ok(_) => {
+                                error.set(None);
+                                note.set(String::new());
+                                trauma_needed.set(false);
+                                trauma.set(None);
+                                log.restart();
+                            }
+                            Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+                            Err(e) => {
+                                tracing::error!("Failed to spend stress: {e:?}");
+                                error.set(Some("Failed to spend stress".into()));
+                            }
+                        }
+                    },
+                    select {
+                        disabled: trauma_needed(),
+                        onchange: move |e| character.set(e.value().parse().ok()),
+                        option { value: "", selected: character().is_none(), "Flashback by..." }
+                        for id in score().characters {
+                            option { value: "{id}", selected: character() == Some(id), "{name_of(id)}" }
+                        }
+                    }
+                    input {
+                        class: "p-1 rounded-lg border border-border w-16",
+                        r#type: "number",
+                        min: "0",
+                        disabled: trauma_needed(),
+                        value: "{cost}",
+                        oninput: move |e| {
+                            if let Ok(c) = e.value().parse() {
+                                cost.set(c);
+                            }
+                        },
+                    }
+                    input {
+                        class: "p-1 rounded-lg border border-border grow",
+                        placeholder: "What happened?",
+                        disabled: trauma_needed(),
+                        value: "{note}",
+                        oninput: move |e| note.set(e.value()),
+                    }
+                    button {
+                        class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                        r#type: "submit",
+                        if trauma_needed() {
+                            "Take Trauma"
+                        } else {
+                            "Flashback"
+                        }
+                    }
+                }
+
+                if trauma_needed() {
+                    p { "This overflows their stress. Choose a trauma for them to take." }
+                    div { class: "flex flex-row flex-wrap gap-2",
+                        for t in Trauma::ALL {
+                            button {
+                                class: if trauma() == Some(t) { "px-2 py-1 rounded-lg bg-destructive text-destructive-foreground cursor-pointer" } else { "px-2 py-1 rounded-lg bg-input cursor-pointer" },
+                                onclick: move |_| trauma.set(Some(t)),
+                                "{t}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(error) = error() {
+                ErrorMessage { "{error}" }
+            }
+        }
+    }
+}
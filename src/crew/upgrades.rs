@@ -0,0 +1,223 @@
+use dioxus::prelude::*;
+use types::specialty::{CrewAbility, CrewUpgrade, UPGRADES};
+
+use crate::{
+    common::CountBtn,
+    elements::{Description, Dialog, ErrorMessage},
+};
+
+#[component]
+pub fn CrewAbilities(crew: ReadOnlySignal<types::Crew>) -> Element {
+    let mut abilities = use_resource(move || async move {
+        api::crew::get_crew_abilities(crew().id)
+            .await
+            .unwrap_or_default()
+    });
+
+    let mut open = use_signal(|| false);
+
+    let taken = use_memo(move || {
+        let names = abilities().unwrap_or_default();
+        types::CrewSpecialty::ALL
+            .iter()
+            .flat_map(|s| s.abilities())
+            .filter(|a| names.iter().any(|n| n == a.name))
+            .fold(Vec::<&'static CrewAbility>::new(), |mut acc, a| {
+                // Shared abilities like Patron appear under several specialties
+                if !acc.iter().any(|t| t.name == a.name) {
+                    acc.push(a);
+                }
+                acc
+            })
+    });
+    let veterans =
+        use_memo(move || types::CrewSpecialty::veterans(&abilities().unwrap_or_default()));
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg underline", "Special Abilities" }
+            p { class: "italic text-sm", "XP: {crew().specialty.xp_trigger()}" }
+            for ability in taken() {
+                div {
+                    span { class: "font-bold", "{ability.name}" }
+                    if ability.name == types::CrewSpecialty::VETERAN && veterans() > 1 {
+                        span { class: "font-bold", " ×{veterans}" }
+                    }
+                    Description { desc: ability.description.clone() }
+                }
+            }
+            div { class: "flex justify-end",
+                button {
+                    class: "p-2 bg-primary text-primary-foreground rounded-lg cursor-pointer",
+                    onclick: move |_| open.set(true),
+                    "Modify"
+                }
+            }
+        }
+
+        AbilitiesDialog {
+            open,
+            crew,
+            taken: abilities().unwrap_or_default(),
+            on_change: move |_| abilities.restart(),
+        }
+    }
+}
+
+#[component]
+fn AbilitiesDialog(
+    open: Signal<bool>,
+    crew: ReadOnlySignal<types::Crew>,
+    taken: ReadOnlySignal<Vec<String>>,
+    on_change: EventHandler<()>,
+) -> Element {
+    let mut error = use_signal(|| None as Option<String>);
+
+    // The crew's own abilities first, then everything Veteran can reach
+    let abilities = use_memo(move || {
+        let specialty = crew().specialty;
+        let mut all: Vec<(types::CrewSpecialty, &'static CrewAbility)> = vec![];
+        for s in std::iter::once(specialty.clone()).chain(
+            types::CrewSpecialty::ALL
+                .into_iter()
+                .filter(|s| *s != specialty),
+        ) {
+            for a in s.abilities() {
+                if !all.iter().any(|(_, t)| t.name == a.name) {
+                    all.push((s.clone(), a));
+                }
+            }
+        }
+        all
+    });
+    let veterans = use_memo(move || types::CrewSpecialty::veterans(&taken()));
+
+    let change = move |(name, add): (&'static str, bool)| async move {
+        let id = crew().id;
+        let res = if add {
+            api::crew::add_crew_ability(id, name.to_string()).await
+        } else {
+            api::crew::remove_crew_ability(id, name.to_string()).await
+        };
+        match res {
+            Ok(()) => {
+                error.set(None);
+                on_change.call(());
+            }
+            Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+    };
+
+    rsx! {
+        Dialog { open, close_on_click: true,
+            div { class: "flex flex-col gap-4 w-full h-full",
+                h2 { class: "text-3xl", "Special Abilities" }
+                hr {}
+                div { class: "flex flex-col gap-2 max-h-full overflow-y-auto",
+                    for (specialty , ability) in abilities() {
+                        button {
+                            class: if taken().iter().any(|t| t == ability.name) { "flex flex-col gap-1 p-2 rounded-lg text-left bg-primary hover:bg-primary/80 text-primary-foreground cursor-pointer" } else { "flex flex-col gap-1 p-2 rounded-lg text-left bg-background hover:bg-input text-foreground cursor-pointer" },
+                            onclick: move |_| {
+                                let add = !taken().iter().any(|t| t == ability.name);
+                                change((ability.name, add))
+                            },
+                            span { class: "flex flex-row justify-between gap-2",
+                                span { class: "font-bold", "{ability.name}" }
+                                span { class: "italic", "{specialty}" }
+                            }
+                            Description { desc: ability.description.clone() }
+                        }
+                        if ability.name == types::CrewSpecialty::VETERAN && veterans() > 0
+                            && veterans() < types::CrewSpecialty::MAX_VETERAN
+                        {
+                            button {
+                                class: "p-1 px-2 bg-muted text-muted-foreground rounded-lg cursor-pointer w-fit self-end",
+                                onclick: move |_| change((ability.name, true)),
+                                "Take {ability.name} again"
+                            }
+                        }
+                    }
+                }
+                if let Some(error) = error() {
+                    ErrorMessage { "{error}" }
+                }
+                div { class: "inline-flex justify-end",
+                    button {
+                        class: "bg-primary text-primary-foreground w-fit rounded-lg p-2 cursor-pointer",
+                        onclick: move |_| open.set(false),
+                        "Close"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn CrewUpgrades(crew: ReadOnlySignal<types::Crew>) -> Element {
+    let mut marks = use_resource(move || async move {
+        api::crew::get_crew_upgrades(crew().id)
+            .await
+            .unwrap_or_default()
+    });
+
+    let mut error = use_signal(|| None as Option<String>);
+
+    let ticked = move |name: &str| {
+        marks()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|m| m.name == name)
+            .map_or(0, |m| m.boxes)
+    };
+
+    let set = move |(name, boxes): (&'static str, u8)| async move {
+        match api::crew::set_crew_upgrade(crew().id, name.to_string(), boxes).await {
+            Ok(()) => {
+                error.set(None);
+                marks.restart();
+            }
+            Err(ServerFnError::WrappedServerError(e)) => error.set(Some(e)),
+            Err(e) => {
+                tracing::error!("Failed to set upgrade: {e:?}");
+                error.set(Some("Failed to set upgrade".into()));
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-2",
+            h3 { class: "text-lg underline", "Upgrades" }
+            div { class: "grid grid-cols-1 md:grid-cols-2 gap-x-4 gap-y-1",
+                for upgrade in crew().specialty.upgrades().iter().chain(UPGRADES.iter()) {
+                    Upgrade {
+                        upgrade: upgrade.clone(),
+                        ticked: ticked(upgrade.name),
+                        set: move |boxes| {
+                            spawn(set((upgrade.name, boxes)));
+                        },
+                    }
+                }
+            }
+            if let Some(error) = error() {
+                ErrorMessage { "{error}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn Upgrade(upgrade: CrewUpgrade, ticked: u8, set: EventHandler<u8>) -> Element {
+    rsx! {
+        div { class: "flex flex-row gap-2 items-center",
+            div { class: "flex flex-row gap-1 items-center",
+                for i in 1..=upgrade.boxes {
+                    CountBtn { this: i, total: ticked, set }
+                }
+            }
+            span { class: "font-bold", "{upgrade.name}" }
+            span { class: "text-sm italic", "{upgrade.description}" }
+        }
+    }
+}
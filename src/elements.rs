@@ -107,3 +107,63 @@ pub fn ErrorMessage(children: Element, class: ReadOnlySignal<Option<String>>) ->
         p { class: "text-destructive brightness-200 {class}", {children} }
     }
 }
+
+#[component]
+pub fn Clock(
+    segments: u8,
+    filled: u8,
+    readonly: Option<bool>,
+    set: EventHandler<u8>,
+    class: Option<String>,
+) -> Element {
+    let readonly = readonly.unwrap_or(false);
+    let class = class.unwrap_or_default();
+
+    let cursor = if readonly {
+        "cursor-not-allowed"
+    } else {
+        "cursor-pointer"
+    };
+
+    let point = |segment: u8| {
+        let angle =
+            std::f32::consts::TAU * segment as f32 / segments as f32 - std::f32::consts::FRAC_PI_2;
+        (50.0 + 45.0 * angle.cos(), 50.0 + 45.0 * angle.sin())
+    };
+
+    rsx! {
+        svg { class: "w-16 h-16 {class}", view_box: "0 0 100 100",
+            for this in 1..=segments {
+                {
+                    let (x1, y1) = point(this - 1);
+                    let (x2, y2) = point(this);
+                    let fill = if this <= filled {
+                        "text-primary"
+                    } else {
+                        "text-input"
+                    };
+                    let hover = if readonly { "" } else { "hover:brightness-80" };
+                    rsx! {
+                        path {
+                            class: "{fill} {hover} {cursor}",
+                            d: "M 50 50 L {x1} {y1} A 45 45 0 0 1 {x2} {y2} Z",
+                            fill: "currentColor",
+                            stroke: "var(--color-border)",
+                            stroke_width: "2",
+                            onclick: move |_| {
+                                if readonly {
+                                    return;
+                                }
+                                if this != filled {
+                                    set(this);
+                                } else {
+                                    set(this - 1);
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
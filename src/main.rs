@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 
-use views::{Character, Crew, Home, JoinCrew, Login, Register};
+use views::{Character, Crew, Districts, Home, JoinCrew, Login, Register, Settings};
 
 mod views;
 
@@ -22,6 +22,10 @@ enum Route {
             Crew { id: types::CrewId },
             #[route("/character/:id")]
             Character { id: types::CrewId },
+            #[route("/districts")]
+            Districts {},
+            #[route("/settings")]
+            Settings {},
         #[end_layout]
         #[route("/:..route")]
         PageNotFound { route: Vec<String> },
@@ -79,9 +83,14 @@ fn Navbar() -> Element {
                     "Back"
                 }
                 div { class: "flex flex-row gap-2",
+                    Link {
+                        to: Route::Districts {},
+                        class: "hover:underline w-fit p-2",
+                        "Districts"
+                    }
 
                     if let Some(username) = auth.username() {
-                        Link { to: "", class: "hover:underline w-fit p-2", "{username}" }
+                        Link { to: Route::Settings {}, class: "hover:underline w-fit p-2", "{username}" }
                     }
 
                     button {
@@ -185,6 +194,7 @@ fn AuthRequired() -> Element {
     }
 }
 
+pub mod clocks;
 pub mod common;
 pub mod elements;
 
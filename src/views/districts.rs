@@ -0,0 +1,53 @@
+use dioxus::prelude::*;
+use types::districts::{District, DISTRICTS};
+
+use crate::common::CountBtn;
+
+#[component]
+pub fn Districts() -> Element {
+    rsx! {
+        div { class: "flex flex-col gap-4 p-4",
+            h1 { class: "text-3xl font-bold mb-4", "Districts of Doskvol" }
+            div { class: "grid grid-cols-1 lg:grid-cols-2 gap-4",
+                for district in DISTRICTS.iter() {
+                    DistrictCard { district: district.clone() }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DistrictCard(district: District) -> Element {
+    rsx! {
+        div { class: "flex flex-col gap-2 p-4 rounded-lg border border-border",
+            h2 { class: "text-xl font-bold", "{district.name}" }
+            p { class: "italic", "{district.description}" }
+            div { class: "grid grid-cols-2 gap-x-4 gap-y-1",
+                for (name , value) in district.traits() {
+                    div { class: "flex flex-row gap-2 items-center justify-between",
+                        span { class: "text-sm", "{name}" }
+                        div { class: "flex flex-row gap-1",
+                            for i in 1..=District::MAX_TRAIT {
+                                CountBtn {
+                                    this: i,
+                                    total: value,
+                                    readonly: true,
+                                    set: move |_| {},
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "flex flex-col gap-1",
+                span { class: "font-bold", "Landmarks" }
+                ul { class: "list-disc list-inside text-sm",
+                    for landmark in district.landmarks {
+                        li { "{landmark}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -12,3 +12,9 @@ pub use auth::{Login, Register};
 
 mod join_crew;
 pub use join_crew::JoinCrew;
+
+mod districts;
+pub use districts::Districts;
+
+mod settings;
+pub use settings::Settings;
@@ -0,0 +1,82 @@
+use dioxus::prelude::*;
+
+use crate::elements::ErrorMessage;
+
+#[component]
+pub fn Settings() -> Element {
+    let mut auth: crate::Auth = use_context();
+
+    let mut sessions =
+        use_resource(move || async move { api::auth::list_sessions().await.unwrap_or_default() });
+
+    let mut error = use_signal(|| None as Option<String>);
+
+    let revoke = move |session: types::Session| async move {
+        match api::auth::revoke_session(session.id).await {
+            Ok(_) => {
+                error.set(None);
+                if session.current {
+                    auth.refresh();
+                } else {
+                    sessions.restart();
+                }
+            }
+            Err(e) => {
+                tracing::error!("Failed to revoke session: {e:?}");
+                error.set(Some("Failed to revoke session".into()));
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-4 p-4",
+            h1 { class: "text-3xl font-bold mb-4", "Settings" }
+            h2 { class: "text-xl font-bold", "Sessions" }
+            p { class: "italic text-sm",
+                "Every device logged in to your account. Revoke any you don't recognise."
+            }
+            div { class: "flex flex-col gap-2",
+                for session in sessions().unwrap_or_default() {
+                    SessionRow {
+                        key: "{session.id}",
+                        session: session.clone(),
+                        revoke: move |_| {
+                            spawn(revoke(session.clone()));
+                        },
+                    }
+                }
+            }
+            if let Some(error) = error() {
+                ErrorMessage { "{error}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn SessionRow(session: types::Session, revoke: EventHandler) -> Element {
+    let name = session
+        .name
+        .clone()
+        .unwrap_or_else(|| "Unknown device".into());
+
+    rsx! {
+        div { class: "flex flex-row gap-2 items-center justify-between p-2 rounded-lg border border-border",
+            div { class: "flex flex-col",
+                span { class: "font-bold break-all", "{name}" }
+                if session.current {
+                    span { class: "text-sm italic", "This device" }
+                }
+            }
+            button {
+                class: "p-2 bg-destructive text-destructive-foreground rounded-lg cursor-pointer",
+                onclick: move |_| revoke.call(()),
+                if session.current {
+                    "Log Out"
+                } else {
+                    "Revoke"
+                }
+            }
+        }
+    }
+}
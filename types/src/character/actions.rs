@@ -0,0 +1,171 @@
+use super::Dots;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Attribute {
+    Insight,
+    Prowess,
+    Resolve,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Action {
+    Hunt,
+    Study,
+    Survey,
+    Tinker,
+    Finesse,
+    Prowl,
+    Skirmish,
+    Wreck,
+    Attune,
+    Command,
+    Consort,
+    Sway,
+}
+
+impl Attribute {
+    pub const ALL: [Attribute; 3] = [Attribute::Insight, Attribute::Prowess, Attribute::Resolve];
+
+    pub const fn actions(&self) -> [Action; 4] {
+        match self {
+            Attribute::Insight => [Action::Hunt, Action::Study, Action::Survey, Action::Tinker],
+            Attribute::Prowess => [
+                Action::Finesse,
+                Action::Prowl,
+                Action::Skirmish,
+                Action::Wreck,
+            ],
+            Attribute::Resolve => [
+                Action::Attune,
+                Action::Command,
+                Action::Consort,
+                Action::Sway,
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for Attribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Attribute::Insight => write!(f, "Insight"),
+            Attribute::Prowess => write!(f, "Prowess"),
+            Attribute::Resolve => write!(f, "Resolve"),
+        }
+    }
+}
+
+impl std::str::FromStr for Attribute {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "insight" => Ok(Attribute::Insight),
+            "prowess" => Ok(Attribute::Prowess),
+            "resolve" => Ok(Attribute::Resolve),
+            _ => Err(format!("Invalid attribute: {s}")),
+        }
+    }
+}
+
+impl Action {
+    pub const ALL: [Action; 12] = [
+        Action::Hunt,
+        Action::Study,
+        Action::Survey,
+        Action::Tinker,
+        Action::Finesse,
+        Action::Prowl,
+        Action::Skirmish,
+        Action::Wreck,
+        Action::Attune,
+        Action::Command,
+        Action::Consort,
+        Action::Sway,
+    ];
+
+    pub const fn attribute(&self) -> Attribute {
+        match self {
+            Action::Hunt | Action::Study | Action::Survey | Action::Tinker => Attribute::Insight,
+            Action::Finesse | Action::Prowl | Action::Skirmish | Action::Wreck => {
+                Attribute::Prowess
+            }
+            Action::Attune | Action::Command | Action::Consort | Action::Sway => Attribute::Resolve,
+        }
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Hunt => write!(f, "Hunt"),
+            Action::Study => write!(f, "Study"),
+            Action::Survey => write!(f, "Survey"),
+            Action::Tinker => write!(f, "Tinker"),
+            Action::Finesse => write!(f, "Finesse"),
+            Action::Prowl => write!(f, "Prowl"),
+            Action::Skirmish => write!(f, "Skirmish"),
+            Action::Wreck => write!(f, "Wreck"),
+            Action::Attune => write!(f, "Attune"),
+            Action::Command => write!(f, "Command"),
+            Action::Consort => write!(f, "Consort"),
+            Action::Sway => write!(f, "Sway"),
+        }
+    }
+}
+
+impl std::str::FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Action::ALL
+            .into_iter()
+            .find(|a| a.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Invalid action: {s}"))
+    }
+}
+
+impl Dots {
+    pub fn rating(&self, action: Action) -> u8 {
+        match action {
+            Action::Hunt => self.hunt,
+            Action::Study => self.study,
+            Action::Survey => self.survey,
+            Action::Tinker => self.tinker,
+            Action::Finesse => self.finesse,
+            Action::Prowl => self.prowl,
+            Action::Skirmish => self.skirmish,
+            Action::Wreck => self.wreck,
+            Action::Attune => self.attune,
+            Action::Command => self.command,
+            Action::Consort => self.consort,
+            Action::Sway => self.sway,
+        }
+    }
+
+    pub fn rating_mut(&mut self, action: Action) -> &mut u8 {
+        match action {
+            Action::Hunt => &mut self.hunt,
+            Action::Study => &mut self.study,
+            Action::Survey => &mut self.survey,
+            Action::Tinker => &mut self.tinker,
+            Action::Finesse => &mut self.finesse,
+            Action::Prowl => &mut self.prowl,
+            Action::Skirmish => &mut self.skirmish,
+            Action::Wreck => &mut self.wreck,
+            Action::Attune => &mut self.attune,
+            Action::Command => &mut self.command,
+            Action::Consort => &mut self.consort,
+            Action::Sway => &mut self.sway,
+        }
+    }
+
+    /// An attribute is rated by how many of its actions have at least one dot.
+    pub fn attribute_rating(&self, attribute: Attribute) -> u8 {
+        attribute
+            .actions()
+            .into_iter()
+            .filter(|&action| self.rating(action) > 0)
+            .count() as u8
+    }
+}
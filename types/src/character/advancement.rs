@@ -0,0 +1,101 @@
+use crate::{Action, Attribute, Character, XP};
+
+/// A choice made when an XP track is full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Advance {
+    /// A special ability, from the character's playbook or another as a veteran ability.
+    Ability(String),
+    /// An extra dot in an action, paid for with that action's attribute track.
+    Action(Action),
+}
+
+impl Advance {
+    /// Whether this is an ability from another playbook.
+    pub fn is_veteran(&self, character: &Character) -> bool {
+        match self {
+            Advance::Ability(name) => crate::playbook::PLAYBOOK
+                .iter()
+                .any(|a| a.name == name && a.class != character.class),
+            Advance::Action(_) => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Advance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Advance::Ability(name) => write!(f, "{name}"),
+            Advance::Action(action) => write!(f, "+1 {action}"),
+        }
+    }
+}
+
+impl XP {
+    pub fn attribute(&self, attribute: Attribute) -> u8 {
+        match attribute {
+            Attribute::Insight => self.insight,
+            Attribute::Prowess => self.prowess,
+            Attribute::Resolve => self.resolve,
+        }
+    }
+
+    pub fn attribute_mut(&mut self, attribute: Attribute) -> &mut u8 {
+        match attribute {
+            Attribute::Insight => &mut self.insight,
+            Attribute::Prowess => &mut self.prowess,
+            Attribute::Resolve => &mut self.resolve,
+        }
+    }
+}
+
+impl Character {
+    pub fn can_advance_playbook(&self) -> bool {
+        self.xp.playbook >= Self::MAX_PLAYBOOK_XP
+    }
+
+    pub fn can_advance_attribute(&self, attribute: Attribute) -> bool {
+        self.xp.attribute(attribute) >= Self::MAX_ATTRIBUTE_XP
+    }
+
+    /// Spends a full XP track on an advance, clearing the track.
+    pub fn advance(&mut self, advance: &Advance) -> Result<(), String> {
+        match advance {
+            Advance::Ability(name) => {
+                if !self.can_advance_playbook() {
+                    return Err("The playbook XP track is not full".to_string());
+                }
+
+                if !crate::playbook::PLAYBOOK.iter().any(|a| a.name == name) {
+                    return Err(format!("Unknown special ability: {name}"));
+                }
+
+                if self.has_ability(name) {
+                    return Err(format!("{} already has {name}", self.name));
+                }
+
+                self.abilities.push(name.clone());
+                self.xp.playbook = 0;
+            }
+            Advance::Action(action) => {
+                let attribute = action.attribute();
+
+                if !self.can_advance_attribute(attribute) {
+                    return Err(format!("The {attribute} XP track is not full"));
+                }
+
+                let rating = self.dots.rating_mut(*action);
+                if *rating >= Self::MAX_ACTION_RATING {
+                    return Err(format!(
+                        "{action} is already at {}",
+                        Self::MAX_ACTION_RATING
+                    ));
+                }
+
+                *rating += 1;
+                *self.xp.attribute_mut(attribute) = 0;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{
+    Action, Attribute, Character, ClockId, Crew, XP, XpTrack,
+    roll::{Outcome, RollResult},
+};
+
+/// Someone treating a character's harm during recovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Healer {
+    /// A character in the crew, rolling their Tinker.
+    Character(crate::CharacterId),
+    /// A contact or NPC, rolling their quality.
+    Contact { quality: u8 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum DowntimeActivity {
+    /// Roll the crew's tier for a temporary asset, spending coin to raise its quality.
+    AcquireAsset { coin: u8 },
+    /// Roll an action to tick a project clock.
+    LongTermProject { clock_id: ClockId, action: Action },
+    /// Get treatment, ticking the healing clock.
+    Recover { healer: Healer },
+    /// Roll an action to clear crew heat.
+    ReduceHeat { action: Action },
+    /// Mark XP on a track.
+    Train { track: XpTrack },
+    /// Roll the lowest attribute to clear stress.
+    IndulgeVice,
+}
+
+/// What is spent for a downtime activity beyond the free ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum DowntimeCost {
+    Coin,
+    Rep,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum DowntimeOutcome {
+    Asset {
+        quality: u8,
+    },
+    Project {
+        ticks: u8,
+        filled: u8,
+        segments: u8,
+    },
+    Recovered {
+        ticks: u8,
+        healing: u8,
+        /// The healing clock filled and every harm dropped a level.
+        healed: bool,
+    },
+    HeatReduced {
+        ticks: u8,
+        heat: u8,
+    },
+    Trained {
+        /// The character's XP after training.
+        xp: XP,
+    },
+    Indulged {
+        cleared: u8,
+        stress: u8,
+        overindulged: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DowntimeResult {
+    pub activity: DowntimeActivity,
+    /// Training is the only activity without a roll.
+    pub roll: Option<RollResult>,
+    pub outcome: DowntimeOutcome,
+    /// Activities taken this downtime, including this one.
+    pub activities_taken: u8,
+}
+
+impl Healer {
+    /// Highest quality a contact treating a character can have, one above the crew's tier.
+    pub fn max_contact_quality(crew: &Crew) -> u8 {
+        crew.tier + 1
+    }
+}
+
+impl DowntimeActivity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DowntimeActivity::AcquireAsset { .. } => "Acquire Asset",
+            DowntimeActivity::LongTermProject { .. } => "Long-Term Project",
+            DowntimeActivity::Recover { .. } => "Recover",
+            DowntimeActivity::ReduceHeat { .. } => "Reduce Heat",
+            DowntimeActivity::Train { .. } => "Train",
+            DowntimeActivity::IndulgeVice => "Indulge Vice",
+        }
+    }
+
+    /// Dice rolled for the activity, or `None` if it has no roll. A healer's Tinker is looked
+    /// up by the caller.
+    pub fn dice_pool(&self, character: &Character, crew: &Crew) -> Option<u8> {
+        match self {
+            DowntimeActivity::AcquireAsset { .. } => Some(crew.tier),
+            DowntimeActivity::LongTermProject { action, .. }
+            | DowntimeActivity::ReduceHeat { action } => Some(character.dots.rating(*action)),
+            DowntimeActivity::Recover { healer } => match healer {
+                Healer::Character(_) => None,
+                Healer::Contact { quality } => Some(*quality),
+            },
+            DowntimeActivity::Train { .. } => None,
+            DowntimeActivity::IndulgeVice => Some(character.lowest_attribute_rating()),
+        }
+    }
+}
+
+impl std::fmt::Display for DowntimeActivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DowntimeActivity::LongTermProject { action, .. }
+            | DowntimeActivity::ReduceHeat { action } => write!(f, "{} ({action})", self.name()),
+            DowntimeActivity::Train { track } => write!(f, "{} ({track})", self.name()),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+impl std::fmt::Display for DowntimeCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DowntimeCost::Coin => write!(f, "1 Coin"),
+            DowntimeCost::Rep => write!(f, "1 Rep"),
+        }
+    }
+}
+
+impl std::fmt::Display for DowntimeOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DowntimeOutcome::Asset { quality } => write!(f, "Acquired a quality {quality} asset"),
+            DowntimeOutcome::Project {
+                ticks,
+                filled,
+                segments,
+            } => write!(f, "Ticked {ticks} segments ({filled}/{segments})"),
+            DowntimeOutcome::Recovered {
+                ticks,
+                healing,
+                healed,
+            } => {
+                write!(
+                    f,
+                    "Ticked {ticks} healing ({healing}/{})",
+                    Character::MAX_HEALING
+                )?;
+                if *healed {
+                    write!(f, ". Every harm drops a level")?;
+                }
+                Ok(())
+            }
+            DowntimeOutcome::HeatReduced { ticks, heat } => {
+                write!(f, "Reduced heat by {ticks}, now {heat}")
+            }
+            DowntimeOutcome::Trained { .. } => write!(f, "Marked 1 XP"),
+            DowntimeOutcome::Indulged {
+                cleared,
+                stress,
+                overindulged,
+            } => {
+                write!(f, "Cleared {cleared} stress, now {stress}")?;
+                if *overindulged {
+                    write!(f, ". Overindulged!")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Outcome {
+    /// Segments ticked by a long-term project, recovery or heat reduction roll.
+    pub fn downtime_ticks(&self) -> u8 {
+        match self {
+            Outcome::Critical => 5,
+            Outcome::Success => 3,
+            Outcome::Partial => 2,
+            Outcome::Failure => 1,
+        }
+    }
+
+    /// Quality levels above or below the crew's tier for an acquired asset.
+    pub fn asset_quality(&self, tier: u8) -> u8 {
+        match self {
+            Outcome::Critical => tier + 2,
+            Outcome::Success => tier + 1,
+            Outcome::Partial => tier,
+            Outcome::Failure => tier.saturating_sub(1),
+        }
+    }
+}
+
+impl RollResult {
+    /// The die the result was read from.
+    pub fn result_die(&self) -> u8 {
+        let result = if self.zero_dice {
+            self.dice.iter().min()
+        } else {
+            self.dice.iter().max()
+        };
+
+        result.copied().unwrap_or(1)
+    }
+}
+
+impl Character {
+    /// Activities each character takes in downtime without paying for them.
+    pub const FREE_DOWNTIME_ACTIVITIES: u8 = 2;
+    /// Coin spent for each quality level added to an acquired asset.
+    pub const COIN_PER_ASSET_QUALITY: u8 = 2;
+
+    pub fn lowest_attribute_rating(&self) -> u8 {
+        Attribute::ALL
+            .into_iter()
+            .map(|a| self.dots.attribute_rating(a))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Checks the character can take another downtime activity, returning what it costs.
+    pub fn downtime_cost(
+        &self,
+        activity: &DowntimeActivity,
+        extra: Option<DowntimeCost>,
+        crew: &Crew,
+    ) -> Result<Option<DowntimeCost>, String> {
+        if self.retired {
+            return Err(format!("{} has retired", self.name));
+        }
+
+        if self.pending_overindulgence {
+            return Err(format!(
+                "{} must deal with their overindulgence first",
+                self.name
+            ));
+        }
+
+        match activity {
+            DowntimeActivity::Recover {
+                healer: Healer::Contact { quality },
+            } if *quality > Healer::max_contact_quality(crew) => {
+                return Err(format!(
+                    "A contact's quality cannot exceed {}",
+                    Healer::max_contact_quality(crew)
+                ));
+            }
+            DowntimeActivity::Train { track } if self.trained.contains(track) => {
+                return Err(format!(
+                    "{} has already trained {track} this downtime",
+                    self.name
+                ));
+            }
+            _ => {}
+        }
+
+        let activity_coin = match activity {
+            DowntimeActivity::AcquireAsset { coin } => *coin,
+            _ => 0,
+        };
+
+        let cost = if self.downtime_activities < Self::FREE_DOWNTIME_ACTIVITIES {
+            None
+        } else {
+            match extra {
+                Some(cost) => Some(cost),
+                None => {
+                    return Err(format!(
+                        "{} has used their {} free activities; spend coin or rep for more",
+                        self.name,
+                        Self::FREE_DOWNTIME_ACTIVITIES
+                    ));
+                }
+            }
+        };
+
+        let coin = activity_coin.checked_add(u8::from(cost == Some(DowntimeCost::Coin)));
+        if coin.is_none_or(|coin| coin > self.coin) {
+            return Err(format!("{} only has {} coin", self.name, self.coin));
+        }
+
+        if cost == Some(DowntimeCost::Rep) && crew.reputation == 0 {
+            return Err("The crew has no rep to spend".to_string());
+        }
+
+        Ok(cost)
+    }
+}
@@ -1,6 +1,66 @@
 #[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 pub struct Harm(pub [String; 2], pub [String; 2], pub String);
 
+/// What a character's harm stops them doing, from the highest level each filled slot is in.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct HarmPenalties {
+    /// Level 1: less effect.
+    pub less_effect: bool,
+    /// Level 2: -1d to action rolls.
+    pub minus_die: bool,
+    /// Level 3: cannot act without help.
+    pub need_help: bool,
+}
+
+impl Harm {
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().chain(self.1.iter()).all(|h| h.is_empty()) && self.2.is_empty()
+    }
+
+    pub fn penalties(&self) -> HarmPenalties {
+        HarmPenalties {
+            less_effect: self.0.iter().any(|h| !h.is_empty()),
+            minus_die: self.1.iter().any(|h| !h.is_empty()),
+            need_help: !self.2.is_empty(),
+        }
+    }
+
+    /// Moves every harm down a level, healing level 1 harm entirely.
+    pub fn heal(&mut self) {
+        let level_2 = std::mem::take(&mut self.1);
+        let level_3 = std::mem::take(&mut self.2);
+
+        self.0 = level_2;
+        self.1 = [level_3, String::new()];
+
+        // Keep harm in the first slot of each level
+        for level in [&mut self.0, &mut self.1] {
+            if level[0].is_empty() {
+                level.swap(0, 1);
+            }
+        }
+    }
+}
+
+impl crate::Character {
+    /// Ticks the healing clock. Filling it heals every harm by a level, then clears the
+    /// clock, carrying over extra ticks. Returns whether the clock filled.
+    pub fn recover(&mut self, ticks: u8) -> bool {
+        let total = self.healing + ticks;
+
+        if total < Self::MAX_HEALING {
+            self.healing = total;
+            return false;
+        }
+
+        self.harm.heal();
+        self.healing = (total - Self::MAX_HEALING).min(Self::MAX_HEALING - 1);
+        true
+    }
+}
+
 bitflags::bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
     pub struct ArmorFlags: u8 {
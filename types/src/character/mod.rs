@@ -1,12 +1,26 @@
+mod actions;
+mod advancement;
+mod downtime;
 mod harm_armor;
+mod new;
+mod stress;
 mod traits;
+mod validation;
+mod vice;
 mod xp;
 
+pub use actions::*;
+pub use advancement::*;
+pub use downtime::*;
 pub use harm_armor::*;
+pub use new::*;
+pub use stress::*;
 pub use traits::*;
+pub use validation::*;
+pub use vice::*;
 pub use xp::*;
 
-use crate::{Class, Description};
+use crate::{Class, Description, XpTrack};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct CharacterPreview {
@@ -44,6 +58,14 @@ pub struct Character {
     pub dots: Dots,
     pub load: Option<Load>,
     pub items: Items,
+    /// Taken out of play after their fourth trauma.
+    pub retired: bool,
+    /// Downtime activities taken since the GM last started downtime.
+    pub downtime_activities: u8,
+    /// Overindulged their vice and has yet to choose the consequence.
+    pub pending_overindulgence: bool,
+    /// XP tracks trained since the GM last started downtime, as each is trained once.
+    pub trained: Vec<XpTrack>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
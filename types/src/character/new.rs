@@ -0,0 +1,123 @@
+use crate::{Action, Background, Class, Dots, Heritage, Vice};
+
+/// Everything chosen while creating a character, validated as a whole by the server.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NewCharacter {
+    pub crew_id: crate::CrewId,
+    pub name: String,
+    pub look: String,
+    pub class: Class,
+    pub heritage: Heritage,
+    pub background: Background,
+    pub vice: Vice,
+    pub dots: Dots,
+    pub ability: String,
+    pub friend: String,
+    pub rival: String,
+}
+
+impl NewCharacter {
+    /// Dots a new character may assign on top of their playbook's starting dots.
+    pub const FREE_DOTS: u8 = 4;
+    /// No action may start above this rating.
+    pub const MAX_STARTING_RATING: u8 = 2;
+
+    pub fn new(crew_id: crate::CrewId, class: Class) -> Self {
+        Self {
+            crew_id,
+            name: String::new(),
+            look: String::new(),
+            class,
+            heritage: Heritage::Akoros,
+            background: Background::Academic,
+            vice: Vice::Faith,
+            dots: class.starting_dots(),
+            ability: String::new(),
+            friend: String::new(),
+            rival: String::new(),
+        }
+    }
+
+    /// Switches playbook, resetting everything that depends on it.
+    pub fn set_class(&mut self, class: Class) {
+        self.class = class;
+        self.dots = class.starting_dots();
+        self.ability.clear();
+        self.friend.clear();
+        self.rival.clear();
+    }
+
+    /// Free dots not yet assigned, negative if too many have been.
+    pub fn free_dots_remaining(&self) -> i16 {
+        let starting = self.class.starting_dots();
+
+        let assigned: i16 = Action::ALL
+            .iter()
+            .map(|&a| self.dots.rating(a) as i16 - starting.rating(a) as i16)
+            .sum();
+
+        Self::FREE_DOTS as i16 - assigned
+    }
+
+    pub fn validate_dots(&self) -> Result<(), String> {
+        let starting = self.class.starting_dots();
+
+        for action in Action::ALL {
+            let rating = self.dots.rating(action);
+
+            if rating < starting.rating(action) {
+                return Err(format!(
+                    "{action} cannot be lower than the {} starting rating",
+                    self.class
+                ));
+            }
+
+            if rating > Self::MAX_STARTING_RATING {
+                return Err(format!(
+                    "{action} cannot start above {}",
+                    Self::MAX_STARTING_RATING
+                ));
+            }
+        }
+
+        match self.free_dots_remaining() {
+            0 => Ok(()),
+            n if n > 0 => Err(format!("{n} action dots left to assign")),
+            n => Err(format!("{} too many action dots assigned", -n)),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Name is required".to_string());
+        }
+
+        self.validate_dots()?;
+
+        if !crate::playbook::PLAYBOOK
+            .iter()
+            .any(|a| a.class == self.class && a.name == self.ability)
+        {
+            return Err(format!(
+                "Choose a starting special ability from the {} playbook",
+                self.class
+            ));
+        }
+
+        let contacts = self.class.contacts();
+
+        if !contacts.contains(&self.friend.as_str()) {
+            return Err("Choose a close friend from your playbook".to_string());
+        }
+
+        if !contacts.contains(&self.rival.as_str()) {
+            return Err("Choose a rival from your playbook".to_string());
+        }
+
+        if self.friend == self.rival {
+            return Err("Your close friend cannot also be your rival".to_string());
+        }
+
+        Ok(())
+    }
+}
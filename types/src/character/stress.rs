@@ -0,0 +1,122 @@
+use crate::{BitCount, Character, Trauma, TraumaFlags};
+
+/// What happened when stress was applied to a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum StressGain {
+    /// The stress was marked without overflowing.
+    Marked { stress: u8 },
+    /// The stress would overflow. Nothing was applied; apply it again with the player's choice
+    /// of trauma.
+    TraumaNeeded,
+    /// The stress overflowed, so stress was cleared and the trauma taken.
+    Trauma { trauma: Trauma, retired: bool },
+}
+
+impl Character {
+    /// Marks stress, taking the given trauma if it overflows. At the fourth trauma the
+    /// character retires.
+    pub fn gain_stress(
+        &mut self,
+        amount: u8,
+        trauma: Option<Trauma>,
+    ) -> Result<StressGain, String> {
+        if self.retired {
+            return Err(format!("{} has retired", self.name));
+        }
+
+        let stress = self.stress.saturating_add(amount);
+
+        if stress <= Self::MAX_STRESS {
+            self.stress = stress;
+            return Ok(StressGain::Marked { stress });
+        }
+
+        let Some(trauma) = trauma else {
+            return Ok(StressGain::TraumaNeeded);
+        };
+
+        let flag = TraumaFlags::from(trauma);
+        if self.trauma.contains(flag) {
+            return Err(format!("{} is already {trauma}", self.name));
+        }
+
+        self.stress = 0;
+        self.trauma |= flag;
+        self.retired = self.trauma.count_bits() >= Self::MAX_TRAUMA;
+
+        Ok(StressGain::Trauma {
+            trauma,
+            retired: self.retired,
+        })
+    }
+}
+
+pub type StressSpendId = i32;
+
+/// A reason to mark stress during a score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum StressSpend {
+    /// +1d or +1 effect on a roll, or acting despite incapacitating harm.
+    Push,
+    /// +1d to a teammate's roll.
+    Assist,
+    /// Stepping back in time, at a cost set by the GM.
+    Flashback { cost: u8 },
+    /// The stress from a resistance roll.
+    Resistance { stress: u8 },
+}
+
+/// A stress spend, as recorded against the score it happened on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct StressLogEntry {
+    pub id: StressSpendId,
+    pub character_id: crate::CharacterId,
+    pub score_id: Option<crate::ScoreId>,
+    pub spend: StressSpend,
+    pub note: String,
+}
+
+impl StressSpend {
+    pub const PUSH_STRESS: u8 = 2;
+    pub const ASSIST_STRESS: u8 = 1;
+
+    pub fn stress(&self) -> u8 {
+        match self {
+            StressSpend::Push => Self::PUSH_STRESS,
+            StressSpend::Assist => Self::ASSIST_STRESS,
+            StressSpend::Flashback { cost } => *cost,
+            StressSpend::Resistance { stress } => *stress,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            StressSpend::Push => "Push",
+            StressSpend::Assist => "Assist",
+            StressSpend::Flashback { .. } => "Flashback",
+            StressSpend::Resistance { .. } => "Resistance",
+        }
+    }
+
+    /// Rebuilds a spend from its kind and the stress it cost.
+    pub fn from_kind(kind: &str, stress: u8) -> Result<Self, String> {
+        match kind {
+            "Push" => Ok(StressSpend::Push),
+            "Assist" => Ok(StressSpend::Assist),
+            "Flashback" => Ok(StressSpend::Flashback { cost: stress }),
+            "Resistance" => Ok(StressSpend::Resistance { stress }),
+            _ => Err(format!("Invalid stress spend: {kind}")),
+        }
+    }
+}
+
+impl std::fmt::Display for StressSpend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StressSpend::Push => write!(f, "Pushed themself"),
+            StressSpend::Assist => write!(f, "Assisted"),
+            StressSpend::Flashback { .. } => write!(f, "Flashback"),
+            StressSpend::Resistance { .. } => write!(f, "Resisted a consequence"),
+        }
+    }
+}
@@ -0,0 +1,352 @@
+use crate::{
+    Action, ArmorFlags, BitCount, Character, Class, Dots, Items, Load, TraumaFlags, XP,
+    items::{CLASS_ITEMS, ClassItem, STANDARD_ITEMS},
+};
+
+/// A single rule broken by a change, keyed by the sheet field it applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// All rules broken by a change. Travels as the custom error of server functions, so it
+/// round-trips through `Display` / `FromStr` as `field: message` lines.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ValidationErrors(pub Vec<FieldError>);
+
+/// A write to one group of character sheet fields.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum CharacterChange {
+    StressTraumaHealingArmor {
+        stress: u8,
+        trauma: TraumaFlags,
+        healing: u8,
+        armor: ArmorFlags,
+    },
+    CoinStash {
+        coin: u8,
+        stash: u8,
+    },
+    Xp(XP),
+    Dots(Dots),
+    Load(Option<Load>),
+    Items(Items),
+    ClassItems(Vec<String>),
+}
+
+impl CharacterChange {
+    /// Writes the changed fields into the character.
+    pub fn apply(&self, character: &mut Character) {
+        match self.clone() {
+            CharacterChange::StressTraumaHealingArmor {
+                stress,
+                trauma,
+                healing,
+                armor,
+            } => {
+                character.stress = stress;
+                character.trauma = trauma;
+                character.healing = healing;
+                character.armor = armor;
+            }
+            CharacterChange::CoinStash { coin, stash } => {
+                character.coin = coin;
+                character.stash = stash;
+            }
+            CharacterChange::Xp(xp) => character.xp = xp,
+            CharacterChange::Dots(dots) => character.dots = dots,
+            CharacterChange::Load(load) => character.load = load,
+            CharacterChange::Items(items) => character.items = items,
+            CharacterChange::ClassItems(class_items) => character.class_items = class_items,
+        }
+    }
+}
+
+impl ValidationErrors {
+    pub fn push(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.0.push(FieldError {
+            field: field.into(),
+            message: message.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The first error for a field, if any.
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|e| e.field == field)
+            .map(|e| e.message.as_str())
+    }
+
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", error.field, error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for ValidationErrors {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut errors = ValidationErrors::default();
+
+        for line in s.lines().filter(|l| !l.is_empty()) {
+            let (field, message) = line
+                .split_once(": ")
+                .ok_or_else(|| format!("Invalid validation error: {line}"))?;
+            errors.push(field, message);
+        }
+
+        Ok(errors)
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl Load {
+    /// Item load allowed when carrying this load, with or without the Mule ability.
+    pub fn limit(&self, mule: bool) -> u8 {
+        match (self, mule) {
+            (Load::Light, false) => 3,
+            (Load::Medium, false) => 5,
+            (Load::Heavy, false) => 6,
+            (Load::Light, true) => 5,
+            (Load::Medium, true) => 7,
+            (Load::Heavy, true) => 8,
+        }
+    }
+}
+
+impl Items {
+    /// Load taken up by a single standard item.
+    pub fn item_load(item: Items) -> u8 {
+        STANDARD_ITEMS
+            .iter()
+            .find(|i| i.flag == item)
+            .map_or(0, |i| i.load)
+    }
+
+    /// Load taken up by the standard items carried.
+    pub fn load(&self) -> u8 {
+        self.iter().map(Items::item_load).sum()
+    }
+}
+
+impl Class {
+    /// The playbook's own items, with their load.
+    pub fn items(&self) -> &'static [ClassItem] {
+        match self {
+            Class::Cutter => &CLASS_ITEMS.cutter,
+            Class::Hound => &CLASS_ITEMS.hound,
+            Class::Leech => &CLASS_ITEMS.leech,
+            Class::Lurk => &CLASS_ITEMS.lurk,
+            Class::Slide => &CLASS_ITEMS.slide,
+            Class::Spider => &CLASS_ITEMS.spider,
+            Class::Whisper => &CLASS_ITEMS.whisper,
+        }
+    }
+
+    /// Load taken up by the named class items. Unknown names take none.
+    pub fn items_load(&self, names: &[String]) -> u8 {
+        names
+            .iter()
+            .filter_map(|name| self.items().iter().find(|i| i.name == *name))
+            .map(|i| i.load)
+            .sum()
+    }
+}
+
+impl Character {
+    pub const MAX_STRESS: u8 = 9;
+    pub const MAX_TRAUMA: u8 = 4;
+    pub const MAX_HEALING: u8 = 4;
+    pub const MAX_COIN: u8 = 4;
+    pub const MAX_STASH: u8 = 40;
+    pub const MAX_ACTION_RATING: u8 = 4;
+    pub const MAX_PLAYBOOK_XP: u8 = 8;
+    pub const MAX_ATTRIBUTE_XP: u8 = 6;
+
+    pub fn has_ability(&self, name: &str) -> bool {
+        self.abilities.iter().any(|a| a == name)
+    }
+
+    pub fn load_limit(&self, load: Load) -> u8 {
+        load.limit(self.has_ability("Mule"))
+    }
+
+    /// Load taken up by everything ticked, standard and class items alike.
+    pub fn used_load(&self) -> u8 {
+        self.items.load() + self.class.items_load(&self.class_items)
+    }
+
+    /// Checks that the chosen load leaves room for `used` load of items. Without a load
+    /// nothing can be ticked, though items can still be dropped.
+    fn check_load(&self, errors: &mut ValidationErrors, field: &str, used: u8) {
+        match self.load {
+            Some(load) => {
+                let limit = self.load_limit(load);
+                if used > limit {
+                    errors.push(field, format!("Items take {used} load, more than {limit}"));
+                }
+            }
+            None if used > self.used_load() => {
+                errors.push(field, "Choose a load before ticking items");
+            }
+            None => {}
+        }
+    }
+
+    /// The character's current values for the fields `change` writes.
+    pub fn current(&self, change: &CharacterChange) -> CharacterChange {
+        match change {
+            CharacterChange::StressTraumaHealingArmor { .. } => {
+                CharacterChange::StressTraumaHealingArmor {
+                    stress: self.stress,
+                    trauma: self.trauma,
+                    healing: self.healing,
+                    armor: self.armor,
+                }
+            }
+            CharacterChange::CoinStash { .. } => CharacterChange::CoinStash {
+                coin: self.coin,
+                stash: self.stash,
+            },
+            CharacterChange::Xp(_) => CharacterChange::Xp(self.xp.clone()),
+            CharacterChange::Dots(_) => CharacterChange::Dots(self.dots.clone()),
+            CharacterChange::Load(_) => CharacterChange::Load(self.load),
+            CharacterChange::Items(_) => CharacterChange::Items(self.items),
+            CharacterChange::ClassItems(_) => CharacterChange::ClassItems(self.class_items.clone()),
+        }
+    }
+
+    /// Checks a change against the rules and the character's current state.
+    pub fn validate_change(&self, change: &CharacterChange) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        match change {
+            CharacterChange::StressTraumaHealingArmor {
+                stress,
+                trauma,
+                healing,
+                armor,
+            } => {
+                if *stress > Self::MAX_STRESS {
+                    errors.push(
+                        "stress",
+                        format!("Stress cannot exceed {}", Self::MAX_STRESS),
+                    );
+                }
+
+                let old_trauma = self.trauma.count_bits();
+                let new_trauma = trauma.count_bits();
+
+                if new_trauma > Self::MAX_TRAUMA {
+                    errors.push(
+                        "trauma",
+                        format!(
+                            "A character cannot have more than {} trauma",
+                            Self::MAX_TRAUMA
+                        ),
+                    );
+                } else if new_trauma > old_trauma {
+                    if new_trauma - old_trauma > 1 {
+                        errors.push("trauma", "Only one trauma can be taken at a time");
+                    }
+                    if self.stress < Self::MAX_STRESS {
+                        errors.push("trauma", "Trauma is only taken when stress overflows");
+                    }
+                }
+
+                if *healing > Self::MAX_HEALING {
+                    errors.push(
+                        "healing",
+                        format!("Healing cannot exceed {}", Self::MAX_HEALING),
+                    );
+                }
+
+                if ArmorFlags::from_bits(armor.bits()).is_none() {
+                    errors.push("armor", "Unknown armor");
+                }
+            }
+            CharacterChange::CoinStash { coin, stash } => {
+                if *coin > Self::MAX_COIN {
+                    errors.push("coin", format!("Coin cannot exceed {}", Self::MAX_COIN));
+                }
+                if *stash > Self::MAX_STASH {
+                    errors.push("stash", format!("Stash cannot exceed {}", Self::MAX_STASH));
+                }
+            }
+            CharacterChange::Xp(xp) => {
+                if xp.playbook > Self::MAX_PLAYBOOK_XP {
+                    errors.push(
+                        "xp.playbook",
+                        format!("Playbook XP cannot exceed {}", Self::MAX_PLAYBOOK_XP),
+                    );
+                }
+                for (field, value) in [
+                    ("xp.insight", xp.insight),
+                    ("xp.prowess", xp.prowess),
+                    ("xp.resolve", xp.resolve),
+                ] {
+                    if value > Self::MAX_ATTRIBUTE_XP {
+                        errors.push(
+                            field,
+                            format!("Attribute XP cannot exceed {}", Self::MAX_ATTRIBUTE_XP),
+                        );
+                    }
+                }
+            }
+            CharacterChange::Dots(dots) => {
+                for action in Action::ALL {
+                    if dots.rating(action) > Self::MAX_ACTION_RATING {
+                        errors.push(
+                            format!("dots.{}", action.to_string().to_lowercase()),
+                            format!("{action} cannot exceed {}", Self::MAX_ACTION_RATING),
+                        );
+                    }
+                }
+            }
+            CharacterChange::Load(load) => match load {
+                Some(load) => {
+                    let limit = self.load_limit(*load);
+                    if self.used_load() > limit {
+                        errors.push(
+                            "load",
+                            format!("Carrying {} load, more than {limit}", self.used_load()),
+                        );
+                    }
+                }
+                None if self.used_load() > 0 => {
+                    errors.push("load", "Drop your items before clearing your load");
+                }
+                None => {}
+            },
+            CharacterChange::Items(items) => {
+                let used = items.load() + self.class.items_load(&self.class_items);
+                self.check_load(&mut errors, "items", used);
+            }
+            CharacterChange::ClassItems(class_items) => {
+                let used = self.items.load() + self.class.items_load(class_items);
+                self.check_load(&mut errors, "class_items", used);
+            }
+        }
+
+        errors.into_result()
+    }
+}
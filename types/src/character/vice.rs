@@ -0,0 +1,87 @@
+use crate::Character;
+
+pub type CharacterLogId = i32;
+
+/// A line in a character's log, recording things that happened to them off the sheet.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct CharacterLogEntry {
+    pub id: CharacterLogId,
+    pub character_id: crate::CharacterId,
+    pub entry: String,
+}
+
+/// What happens when indulging a vice clears more stress than the character had.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Overindulgence {
+    /// The GM makes an entanglement roll.
+    Trouble,
+    /// The crew takes +2 heat.
+    Brag,
+    /// The character vanishes for a few weeks.
+    Lost,
+    /// The character's purveyor cuts them off.
+    Tapped,
+}
+
+impl Overindulgence {
+    pub const ALL: [Overindulgence; 4] = [
+        Overindulgence::Trouble,
+        Overindulgence::Brag,
+        Overindulgence::Lost,
+        Overindulgence::Tapped,
+    ];
+
+    /// Heat the crew takes from a boastful character.
+    pub const BRAG_HEAT: u8 = 2;
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Overindulgence::Trouble => {
+                "You make trouble while indulging. The GM rolls an entanglement."
+            }
+            Overindulgence::Brag => "You boast about your exploits. The crew takes +2 heat.",
+            Overindulgence::Lost => {
+                "You vanish for a few weeks. Play a different character until this one returns."
+            }
+            Overindulgence::Tapped => {
+                "Your current purveyor cuts you off. Find a new source for your vice."
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Overindulgence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Overindulgence::Trouble => write!(f, "Attract Trouble"),
+            Overindulgence::Brag => write!(f, "Brag"),
+            Overindulgence::Lost => write!(f, "Lost"),
+            Overindulgence::Tapped => write!(f, "Tapped"),
+        }
+    }
+}
+
+impl Character {
+    /// Clears stress equal to the indulgence roll, returning how much was cleared. Rolling
+    /// higher than the current stress is an overindulgence, which must be resolved before
+    /// indulging again.
+    pub fn indulge_vice(&mut self, roll: u8) -> u8 {
+        let cleared = roll.min(self.stress);
+
+        if roll > self.stress {
+            self.pending_overindulgence = true;
+        }
+
+        self.stress -= cleared;
+        cleared
+    }
+
+    pub fn resolve_overindulgence(&mut self) -> Result<(), String> {
+        if !self.pending_overindulgence {
+            return Err(format!("{} has not overindulged", self.name));
+        }
+
+        self.pending_overindulgence = false;
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+pub type ClockId = i32;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Clock {
+    pub id: ClockId,
+    pub owner: ClockOwner,
+    pub name: String,
+    pub size: ClockSize,
+    pub filled: u8,
+}
+
+impl Clock {
+    pub fn segments(&self) -> u8 {
+        self.size.segments()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.filled >= self.segments()
+    }
+
+    /// Fills (or, for negative ticks, clears) segments, staying within the clock.
+    pub fn tick(&mut self, ticks: i8) {
+        self.filled = (self.filled as i16 + ticks as i16).clamp(0, self.segments() as i16) as u8;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum ClockOwner {
+    Crew(crate::CrewId),
+    Character(crate::CharacterId),
+    /// A faction's clock in a crew's faction game, kept by the GM.
+    Faction(crate::CrewId, crate::FactionId),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum ClockSize {
+    Four,
+    Six,
+    Eight,
+    Twelve,
+}
+
+impl ClockSize {
+    pub const ALL: [ClockSize; 4] = [
+        ClockSize::Four,
+        ClockSize::Six,
+        ClockSize::Eight,
+        ClockSize::Twelve,
+    ];
+
+    pub const fn segments(&self) -> u8 {
+        match self {
+            ClockSize::Four => 4,
+            ClockSize::Six => 6,
+            ClockSize::Eight => 8,
+            ClockSize::Twelve => 12,
+        }
+    }
+}
+
+impl TryFrom<u8> for ClockSize {
+    type Error = String;
+
+    fn try_from(segments: u8) -> Result<Self, Self::Error> {
+        Ok(match segments {
+            4 => ClockSize::Four,
+            6 => ClockSize::Six,
+            8 => ClockSize::Eight,
+            12 => ClockSize::Twelve,
+            _ => return Err(format!("Invalid clock size: {segments}")),
+        })
+    }
+}
+
+impl std::fmt::Display for ClockSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.segments())
+    }
+}
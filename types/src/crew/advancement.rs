@@ -0,0 +1,178 @@
+use super::{Crew, CrewUpgradeMark, Hold};
+use crate::{Character, TriggerFrequency};
+
+/// The GM's answers to the crew's end of session questions.
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct CrewXpAnswers {
+    /// The specialty's own XP trigger.
+    pub specialty: TriggerFrequency,
+    pub challenges: TriggerFrequency,
+    pub reputation: TriggerFrequency,
+    pub nature: TriggerFrequency,
+}
+
+impl CrewXpAnswers {
+    pub const CHALLENGES: &str = "You contended with challenges above your current station.";
+    pub const REPUTATION: &str = "You bolstered your crew's reputation or developed a new one.";
+    pub const NATURE: &str =
+        "You expressed the goals, drives, inner conflict, or essential nature of the crew.";
+
+    pub fn marks(&self) -> u8 {
+        self.specialty.marks()
+            + self.challenges.marks()
+            + self.reputation.marks()
+            + self.nature.marks()
+    }
+}
+
+/// A choice made when the crew's XP track is full.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum CrewAdvance {
+    /// A crew special ability, from the crew's specialty or another with Veteran.
+    Ability(String),
+    /// Upgrade boxes to tick, one per entry. Naming an upgrade twice ticks two of its boxes.
+    Upgrades(Vec<String>),
+}
+
+impl CrewAdvance {
+    pub const UPGRADE_BOXES: usize = 2;
+}
+
+impl std::fmt::Display for CrewAdvance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrewAdvance::Ability(name) => write!(f, "{name}"),
+            CrewAdvance::Upgrades(names) => write!(f, "{}", names.join(", ")),
+        }
+    }
+}
+
+// The vaults hold less than the higher tiers cost, so members put in coin from their stashes.
+// A full treasury and one full stash must still cover developing to the highest tier.
+const _: () = assert!(
+    Crew::MAX_TIER * Crew::COIN_PER_TIER
+        <= Crew::coin_capacity_with(Crew::MAX_VAULTS) + Character::MAX_STASH
+);
+
+impl Crew {
+    pub const MAX_XP: u8 = 8;
+    pub const COIN_PER_TIER: u8 = 8;
+
+    /// Marks XP, stopping once the track is full.
+    pub fn mark_xp(&mut self, amount: u8) {
+        self.xp = self.xp.saturating_add(amount).min(Self::MAX_XP);
+    }
+
+    pub fn can_advance(&self) -> bool {
+        self.xp >= Self::MAX_XP
+    }
+
+    /// Spends a full XP track on an advance, clearing the track. `abilities` and `upgrades`
+    /// are what the crew already holds; upgrades come back with the new boxes ticked.
+    pub fn advance(
+        &mut self,
+        advance: &CrewAdvance,
+        abilities: &[String],
+        upgrades: &[CrewUpgradeMark],
+    ) -> Result<Vec<CrewUpgradeMark>, String> {
+        if !self.can_advance() {
+            return Err("The crew XP track is not full".to_string());
+        }
+
+        let mut upgrades = upgrades.to_vec();
+
+        match advance {
+            CrewAdvance::Ability(name) => self.specialty.check_ability(name, abilities)?,
+            CrewAdvance::Upgrades(names) => {
+                if names.len() != CrewAdvance::UPGRADE_BOXES {
+                    return Err(format!(
+                        "Pick {} upgrade boxes to tick",
+                        CrewAdvance::UPGRADE_BOXES
+                    ));
+                }
+
+                for name in names {
+                    let Some(upgrade) = self.specialty.find_upgrade(name) else {
+                        return Err(format!("{} can't take {name}", self.specialty));
+                    };
+
+                    let index = match upgrades.iter().position(|u| u.name == *name) {
+                        Some(index) => index,
+                        None => {
+                            upgrades.push(CrewUpgradeMark {
+                                name: name.clone(),
+                                boxes: 0,
+                            });
+                            upgrades.len() - 1
+                        }
+                    };
+
+                    let mark = &mut upgrades[index];
+                    if mark.boxes >= upgrade.boxes {
+                        return Err(format!("{name} is already fully ticked"));
+                    }
+                    mark.boxes += 1;
+                }
+            }
+        }
+
+        self.xp = 0;
+
+        Ok(upgrades)
+    }
+
+    /// Rep needed to develop the crew. Each claimed turf lowers it by one.
+    pub fn rep_needed(turf: usize) -> u8 {
+        Self::MAX_REPUTATION.saturating_sub(turf.min(u8::MAX as usize) as u8)
+    }
+
+    /// The tier the crew will be at once developed. A weak hold firms up at the same tier.
+    pub fn next_tier(&self) -> u8 {
+        match self.hold {
+            Hold::Strong => self.tier + 1,
+            Hold::Weak => self.tier,
+        }
+    }
+
+    /// Coin needed to develop the crew, the new tier times eight.
+    pub fn development_cost(&self) -> u8 {
+        self.next_tier() * Self::COIN_PER_TIER
+    }
+
+    /// Develops the crew with a full rep track: a strong hold moves up a tier and becomes
+    /// weak, a weak hold becomes strong. Clears rep and pays for it from the crew's coin,
+    /// less the coin its members `contributed` from their stashes.
+    pub fn develop(&mut self, turf: usize, contributed: u8) -> Result<(), String> {
+        let needed = Self::rep_needed(turf);
+        if self.reputation < needed {
+            return Err(format!("The crew needs {needed} rep to develop"));
+        }
+
+        if self.next_tier() > Self::MAX_TIER {
+            return Err("The crew is already at the highest tier".to_string());
+        }
+
+        let cost = self.development_cost();
+        if contributed > cost {
+            return Err(format!("The crew only needs {cost} coin to develop"));
+        }
+
+        let owed = cost - contributed;
+        if self.coin < owed {
+            return Err(format!(
+                "The crew needs {cost} coin to develop, {} more than it and its members have put in",
+                owed - self.coin
+            ));
+        }
+
+        self.coin -= owed;
+        self.reputation = 0;
+        self.tier = self.next_tier();
+        self.hold = match self.hold {
+            Hold::Strong => Hold::Weak,
+            Hold::Weak => Hold::Strong,
+        };
+
+        Ok(())
+    }
+}
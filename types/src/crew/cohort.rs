@@ -0,0 +1,322 @@
+use super::{Crew, CrewUpgradeMark};
+
+pub type CohortId = i32;
+
+/// What a gang is good at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum GangType {
+    Adepts,
+    Rooks,
+    Rovers,
+    Skulks,
+    Thugs,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum CohortKind {
+    /// A group of a dozen or so, at the crew's scale.
+    Gang(Vec<GangType>),
+    /// A single specialist, named for their occupation.
+    Expert(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Edge {
+    Fearsome,
+    Independent,
+    Loyal,
+    Tenacious,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Flaw {
+    Principled,
+    Savage,
+    Unreliable,
+    Wild,
+}
+
+/// How badly hurt a cohort is. Each level makes them less effective, until they're lost.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum CohortHarm {
+    #[default]
+    Unharmed,
+    /// Reduced effect.
+    Weakened,
+    /// Reduced quality.
+    Impaired,
+    /// Can't act until healed.
+    Broken,
+    /// Gone, and must be replaced.
+    Dead,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Cohort {
+    pub id: CohortId,
+    pub crew_id: crate::CrewId,
+    pub name: String,
+    pub kind: CohortKind,
+    pub edges: Vec<Edge>,
+    pub flaws: Vec<Flaw>,
+    pub harm: CohortHarm,
+    /// Armor left to soak up a level of harm.
+    pub armor: bool,
+}
+
+/// A cohort as the crew describes it when taking them on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct NewCohort {
+    pub name: String,
+    pub kind: CohortKind,
+    pub edges: Vec<Edge>,
+    pub flaws: Vec<Flaw>,
+    pub armor: bool,
+}
+
+impl GangType {
+    pub const ALL: [GangType; 5] = [
+        GangType::Adepts,
+        GangType::Rooks,
+        GangType::Rovers,
+        GangType::Skulks,
+        GangType::Thugs,
+    ];
+
+    /// The crew upgrade that makes gangs of this type elite.
+    pub fn elite_upgrade(&self) -> String {
+        format!("Elite {self}")
+    }
+}
+
+impl Edge {
+    pub const ALL: [Edge; 4] = [
+        Edge::Fearsome,
+        Edge::Independent,
+        Edge::Loyal,
+        Edge::Tenacious,
+    ];
+}
+
+impl Flaw {
+    pub const ALL: [Flaw; 4] = [Flaw::Principled, Flaw::Savage, Flaw::Unreliable, Flaw::Wild];
+}
+
+impl CohortHarm {
+    pub const ALL: [CohortHarm; 5] = [
+        CohortHarm::Unharmed,
+        CohortHarm::Weakened,
+        CohortHarm::Impaired,
+        CohortHarm::Broken,
+        CohortHarm::Dead,
+    ];
+
+    pub fn level(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn from_level(level: u8) -> Self {
+        Self::ALL[(level as usize).min(Self::ALL.len() - 1)]
+    }
+}
+
+impl Cohort {
+    pub const MAX_EDGES: usize = 2;
+    pub const MAX_GANG_TYPES: usize = 2;
+
+    pub fn is_gang(&self) -> bool {
+        matches!(self.kind, CohortKind::Gang(_))
+    }
+
+    /// Gangs are as good as the crew's tier, experts one better. Elite upgrades for any of a
+    /// gang's types add one more, and an impaired cohort loses one.
+    pub fn quality(&self, crew: &Crew, upgrades: &[CrewUpgradeMark]) -> u8 {
+        let quality = match &self.kind {
+            CohortKind::Gang(types) => {
+                let elite = types.iter().any(|t| {
+                    let upgrade = t.elite_upgrade();
+                    upgrades.iter().any(|u| u.name == upgrade && u.boxes > 0)
+                });
+                crew.tier + elite as u8
+            }
+            CohortKind::Expert(_) => crew.tier + 1,
+        };
+
+        if self.harm >= CohortHarm::Impaired {
+            quality.saturating_sub(1)
+        } else {
+            quality
+        }
+    }
+
+    /// Takes `levels` of harm, spending armor on the first level if there's any left.
+    pub fn damage(&mut self, levels: u8) -> Result<(), String> {
+        if self.harm == CohortHarm::Dead {
+            return Err(format!("{} are already lost", self.name));
+        }
+
+        let mut levels = levels;
+        if levels > 0 && self.armor {
+            self.armor = false;
+            levels -= 1;
+        }
+
+        self.harm = CohortHarm::from_level(self.harm.level().saturating_add(levels));
+        Ok(())
+    }
+
+    /// Heals `levels` of harm. The dead can't be healed, only replaced.
+    pub fn heal(&mut self, levels: u8) -> Result<(), String> {
+        if self.harm == CohortHarm::Dead {
+            return Err(format!("{} are lost and must be replaced", self.name));
+        }
+
+        self.harm = CohortHarm::from_level(self.harm.level().saturating_sub(levels));
+        Ok(())
+    }
+}
+
+impl NewCohort {
+    /// A new cohort needs a name, a type, and as many flaws as edges.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("A cohort needs a name".to_string());
+        }
+
+        match &self.kind {
+            CohortKind::Gang(types) => {
+                if types.is_empty() || types.len() > Cohort::MAX_GANG_TYPES {
+                    return Err(format!(
+                        "A gang has between 1 and {} types",
+                        Cohort::MAX_GANG_TYPES
+                    ));
+                }
+                if types
+                    .iter()
+                    .enumerate()
+                    .any(|(i, t)| types[..i].contains(t))
+                {
+                    return Err("A gang can't have the same type twice".to_string());
+                }
+            }
+            CohortKind::Expert(kind) => {
+                if kind.trim().is_empty() {
+                    return Err("An expert needs an occupation".to_string());
+                }
+            }
+        }
+
+        if self.edges.len() > Cohort::MAX_EDGES {
+            return Err(format!("A cohort has at most {} edges", Cohort::MAX_EDGES));
+        }
+        if self.edges.len() != self.flaws.len() {
+            return Err("A cohort takes a flaw for each edge".to_string());
+        }
+        if self
+            .edges
+            .iter()
+            .enumerate()
+            .any(|(i, e)| self.edges[..i].contains(e))
+            || self
+                .flaws
+                .iter()
+                .enumerate()
+                .any(|(i, f)| self.flaws[..i].contains(f))
+        {
+            return Err("Edges and flaws can only be taken once".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for GangType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GangType::Adepts => write!(f, "Adepts"),
+            GangType::Rooks => write!(f, "Rooks"),
+            GangType::Rovers => write!(f, "Rovers"),
+            GangType::Skulks => write!(f, "Skulks"),
+            GangType::Thugs => write!(f, "Thugs"),
+        }
+    }
+}
+
+impl std::str::FromStr for GangType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GangType::ALL
+            .into_iter()
+            .find(|t| t.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Invalid gang type: {s}"))
+    }
+}
+
+impl std::fmt::Display for Edge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Edge::Fearsome => write!(f, "Fearsome"),
+            Edge::Independent => write!(f, "Independent"),
+            Edge::Loyal => write!(f, "Loyal"),
+            Edge::Tenacious => write!(f, "Tenacious"),
+        }
+    }
+}
+
+impl std::str::FromStr for Edge {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Edge::ALL
+            .into_iter()
+            .find(|e| e.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Invalid edge: {s}"))
+    }
+}
+
+impl std::fmt::Display for Flaw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Flaw::Principled => write!(f, "Principled"),
+            Flaw::Savage => write!(f, "Savage"),
+            Flaw::Unreliable => write!(f, "Unreliable"),
+            Flaw::Wild => write!(f, "Wild"),
+        }
+    }
+}
+
+impl std::str::FromStr for Flaw {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Flaw::ALL
+            .into_iter()
+            .find(|f| f.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Invalid flaw: {s}"))
+    }
+}
+
+impl std::fmt::Display for CohortHarm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CohortHarm::Unharmed => write!(f, "Unharmed"),
+            CohortHarm::Weakened => write!(f, "Weakened"),
+            CohortHarm::Impaired => write!(f, "Impaired"),
+            CohortHarm::Broken => write!(f, "Broken"),
+            CohortHarm::Dead => write!(f, "Dead"),
+        }
+    }
+}
@@ -1,3 +1,12 @@
+mod advancement;
+pub use advancement::*;
+
+mod cohort;
+pub use cohort::*;
+
+mod turf;
+pub use turf::*;
+
 #[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct CrewPreview {
     pub id: crate::CrewId,
@@ -13,6 +22,75 @@ pub struct Crew {
     pub name: String,
     pub specialty: CrewSpecialty,
     pub dm_id: crate::UserId,
+    pub reputation: u8,
+    pub heat: u8,
+    pub wanted_level: u8,
+    pub tier: u8,
+    pub hold: Hold,
+    pub coin: u8,
+    pub vaults: u8,
+    pub xp: u8,
+}
+
+impl Crew {
+    pub const MAX_REPUTATION: u8 = 12;
+    pub const MAX_HEAT: u8 = 9;
+    pub const MAX_WANTED_LEVEL: u8 = 4;
+    pub const MAX_TIER: u8 = 4;
+    pub const MAX_VAULTS: u8 = 2;
+
+    pub fn coin_capacity(&self) -> u8 {
+        Self::coin_capacity_with(self.vaults)
+    }
+
+    /// Crew coin starts at 4 and doubles with each vault.
+    pub const fn coin_capacity_with(vaults: u8) -> u8 {
+        match vaults {
+            0 => 4,
+            1 => 8,
+            _ => 16,
+        }
+    }
+}
+
+/// Boxes ticked on one of the crew's upgrades.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct CrewUpgradeMark {
+    pub name: String,
+    pub boxes: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "server", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "server", diesel(sql_type = diesel::sql_types::Text))]
+pub enum Hold {
+    Strong,
+    Weak,
+}
+
+impl Hold {
+    pub const ALL: [Hold; 2] = [Hold::Strong, Hold::Weak];
+}
+
+impl std::fmt::Display for Hold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hold::Strong => write!(f, "Strong"),
+            Hold::Weak => write!(f, "Weak"),
+        }
+    }
+}
+
+impl std::str::FromStr for Hold {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "strong" => Ok(Hold::Strong),
+            "weak" => Ok(Hold::Weak),
+            _ => Err(format!("Invalid hold: {s}")),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
@@ -39,6 +117,85 @@ impl CrewSpecialty {
     ];
 }
 
+impl CrewSpecialty {
+    pub const VETERAN: &str = "Veteran";
+    pub const MAX_VETERAN: usize = 3;
+
+    /// An upgrade open to this crew, from the common upgrades or the specialty's own.
+    pub fn find_upgrade(&self, name: &str) -> Option<&'static crate::specialty::CrewUpgrade> {
+        crate::specialty::UPGRADES
+            .iter()
+            .chain(self.upgrades())
+            .find(|u| u.name == name)
+    }
+
+    /// Checks an ability can be taken by a crew holding `abilities`. Each Veteran taken
+    /// opens one ability from another specialty, and Veteran can be taken three times.
+    pub fn check_ability(&self, name: &str, abilities: &[String]) -> Result<(), String> {
+        if name == Self::VETERAN {
+            if Self::veterans(abilities) >= Self::MAX_VETERAN {
+                return Err(format!(
+                    "The crew can only take {} {} times",
+                    Self::VETERAN,
+                    Self::MAX_VETERAN
+                ));
+            }
+            return Ok(());
+        }
+
+        if abilities.iter().any(|a| a == name) {
+            return Err(format!("The crew already has {name}"));
+        }
+
+        if self.is_own_ability(name) {
+            return Ok(());
+        }
+
+        let exists = CrewSpecialty::ALL
+            .iter()
+            .any(|s| s.abilities().iter().any(|a| a.name == name));
+        if !exists {
+            return Err(format!("Unknown crew ability: {name}"));
+        }
+
+        if self.outside_abilities(abilities) >= Self::veterans(abilities) {
+            return Err(format!(
+                "{name} needs another {} to take from another crew",
+                Self::VETERAN
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks an ability can be given up. A Veteran can't go while an ability from another
+    /// specialty still needs it.
+    pub fn check_removal(&self, name: &str, abilities: &[String]) -> Result<(), String> {
+        if name == Self::VETERAN && self.outside_abilities(abilities) >= Self::veterans(abilities) {
+            return Err(format!(
+                "Remove an ability from another crew before giving up {}",
+                Self::VETERAN
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// How many times the crew has taken Veteran.
+    pub fn veterans(abilities: &[String]) -> usize {
+        abilities.iter().filter(|a| *a == Self::VETERAN).count()
+    }
+
+    fn is_own_ability(&self, name: &str) -> bool {
+        self.abilities().iter().any(|a| a.name == name)
+    }
+
+    /// Abilities held from other specialties, one per Veteran.
+    fn outside_abilities(&self, abilities: &[String]) -> usize {
+        abilities.iter().filter(|a| !self.is_own_ability(a)).count()
+    }
+}
+
 impl std::fmt::Display for CrewSpecialty {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -97,4 +254,24 @@ mod server {
             Ok(s.parse()?)
         }
     }
+
+    impl ToSql<diesel::sql_types::Text, diesel::sqlite::Sqlite> for Hold {
+        fn to_sql<'a>(
+            &'a self,
+            out: &mut Output<'a, '_, diesel::sqlite::Sqlite>,
+        ) -> diesel::serialize::Result {
+            out.set_value(self.to_string());
+            Ok(diesel::serialize::IsNull::No)
+        }
+    }
+
+    impl FromSql<diesel::sql_types::Text, diesel::sqlite::Sqlite> for Hold {
+        fn from_sql(bytes: <Sqlite as Backend>::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            let s = <String as FromSql<diesel::sql_types::Text, diesel::sqlite::Sqlite>>::from_sql(
+                bytes,
+            )?;
+
+            Ok(s.parse()?)
+        }
+    }
 }
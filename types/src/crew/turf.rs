@@ -0,0 +1,67 @@
+use super::CrewSpecialty;
+use crate::claims::{Claim, LINKS};
+
+/// A cell on a claims map, counted row by row from the top left.
+pub type ClaimPosition = u8;
+
+impl CrewSpecialty {
+    pub const CLAIMS_COLUMNS: u8 = 5;
+    /// The lair sits at the centre of every claims map, and is always held.
+    pub const LAIR: ClaimPosition = 7;
+
+    pub fn claim(&self, position: ClaimPosition) -> Option<&'static Claim> {
+        self.claims().get(position as usize)
+    }
+
+    /// Positions joined to `position` on the claims map.
+    pub fn claim_neighbours(position: ClaimPosition) -> impl Iterator<Item = ClaimPosition> {
+        LINKS.iter().filter_map(move |&(a, b)| {
+            if a == position {
+                Some(b)
+            } else if b == position {
+                Some(a)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Checks a claim can be seized by a crew holding `held`. Claims must be next to one
+    /// already held, starting out from the lair.
+    pub fn check_seize(
+        &self,
+        held: &[ClaimPosition],
+        position: ClaimPosition,
+    ) -> Result<(), String> {
+        let Some(claim) = self.claim(position) else {
+            return Err(format!("There is no claim at {position}"));
+        };
+
+        if position == Self::LAIR || held.contains(&position) {
+            return Err(format!("The crew already holds {}", claim.name));
+        }
+
+        if !Self::claim_neighbours(position).any(|n| n == Self::LAIR || held.contains(&n)) {
+            return Err(format!(
+                "{} isn't next to a claim the crew holds",
+                claim.name
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// How many of the held claims are turf.
+    pub fn turf(&self, held: &[ClaimPosition]) -> usize {
+        held.iter()
+            .filter_map(|&p| self.claim(p))
+            .filter(|c| c.is_turf())
+            .count()
+    }
+}
+
+impl Claim {
+    pub fn is_turf(&self) -> bool {
+        self.name == "Turf"
+    }
+}
@@ -0,0 +1,31 @@
+use crate::districts::{DISTRICTS, District};
+
+/// A district's position in [`DISTRICTS`]. Positions shift when the catalogue changes, so
+/// districts are stored by name.
+pub type DistrictId = u8;
+
+impl District {
+    pub const MAX_TRAIT: u8 = 4;
+
+    pub fn find(id: DistrictId) -> Option<&'static District> {
+        DISTRICTS.get(id as usize)
+    }
+
+    /// Looks a district up by its stored name.
+    pub fn id_of(name: &str) -> Option<DistrictId> {
+        DISTRICTS
+            .iter()
+            .position(|d| d.name == name)
+            .map(|i| i as DistrictId)
+    }
+
+    /// The district's traits, named for display.
+    pub fn traits(&self) -> [(&'static str, u8); 4] {
+        [
+            ("Wealth", self.wealth),
+            ("Security & Safety", self.security),
+            ("Criminal Influence", self.criminal),
+            ("Occult Influence", self.occult),
+        ]
+    }
+}
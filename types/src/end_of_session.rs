@@ -0,0 +1,154 @@
+use crate::{Attribute, Character, XP};
+
+pub type EndOfSessionId = i32;
+
+/// An end of session started by the GM, open until they close it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EndOfSession {
+    pub id: EndOfSessionId,
+    pub crew_id: crate::CrewId,
+    /// Characters whose player has already answered.
+    pub answered: Vec<crate::CharacterId>,
+}
+
+/// How often an XP trigger came up during the session.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
+pub enum TriggerFrequency {
+    #[default]
+    Never,
+    Once,
+    Often,
+}
+
+impl TriggerFrequency {
+    pub const ALL: [TriggerFrequency; 3] = [
+        TriggerFrequency::Never,
+        TriggerFrequency::Once,
+        TriggerFrequency::Often,
+    ];
+
+    /// One mark if it came up, two if it came up multiple times.
+    pub fn marks(&self) -> u8 {
+        match self {
+            TriggerFrequency::Never => 0,
+            TriggerFrequency::Once => 1,
+            TriggerFrequency::Often => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for TriggerFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriggerFrequency::Never => write!(f, "No"),
+            TriggerFrequency::Once => write!(f, "Yes"),
+            TriggerFrequency::Often => write!(f, "Multiple times"),
+        }
+    }
+}
+
+/// The track end of session XP is marked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum XpTrack {
+    Playbook,
+    Attribute(Attribute),
+}
+
+impl XpTrack {
+    pub const ALL: [XpTrack; 4] = [
+        XpTrack::Playbook,
+        XpTrack::Attribute(Attribute::Insight),
+        XpTrack::Attribute(Attribute::Prowess),
+        XpTrack::Attribute(Attribute::Resolve),
+    ];
+
+    pub fn max(&self) -> u8 {
+        match self {
+            XpTrack::Playbook => Character::MAX_PLAYBOOK_XP,
+            XpTrack::Attribute(_) => Character::MAX_ATTRIBUTE_XP,
+        }
+    }
+}
+
+impl std::fmt::Display for XpTrack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XpTrack::Playbook => write!(f, "Playbook"),
+            XpTrack::Attribute(attribute) => write!(f, "{attribute}"),
+        }
+    }
+}
+
+impl std::str::FromStr for XpTrack {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        XpTrack::ALL
+            .into_iter()
+            .find(|t| t.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Invalid XP track: {s}"))
+    }
+}
+
+/// A player's answers to the end of session questions for one character.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct XpAnswers {
+    /// The playbook's own XP trigger.
+    pub playbook: TriggerFrequency,
+    pub beliefs: TriggerFrequency,
+    pub struggle: TriggerFrequency,
+    pub track: XpTrack,
+}
+
+impl XpAnswers {
+    pub const BELIEFS: &str = "You expressed your beliefs, drives, heritage, or background.";
+    pub const STRUGGLE: &str =
+        "You struggled with issues from your vice or traumas during the session.";
+
+    pub fn new(track: XpTrack) -> Self {
+        Self {
+            playbook: TriggerFrequency::Never,
+            beliefs: TriggerFrequency::Never,
+            struggle: TriggerFrequency::Never,
+            track,
+        }
+    }
+
+    pub fn marks(&self) -> u8 {
+        self.playbook.marks() + self.beliefs.marks() + self.struggle.marks()
+    }
+}
+
+impl XP {
+    pub fn track(&self, track: XpTrack) -> u8 {
+        match track {
+            XpTrack::Playbook => self.playbook,
+            XpTrack::Attribute(attribute) => self.attribute(attribute),
+        }
+    }
+
+    pub fn track_mut(&mut self, track: XpTrack) -> &mut u8 {
+        match track {
+            XpTrack::Playbook => &mut self.playbook,
+            XpTrack::Attribute(attribute) => self.attribute_mut(attribute),
+        }
+    }
+
+    /// Marks XP on a track, stopping once it is full.
+    pub fn mark(&mut self, track: XpTrack, amount: u8) {
+        let value = self.track_mut(track);
+        *value = value.saturating_add(amount).min(track.max());
+    }
+
+    /// The same XP with every track stopped at its maximum.
+    pub fn capped(&self) -> XP {
+        let mut xp = self.clone();
+        for track in XpTrack::ALL {
+            let value = xp.track_mut(track);
+            *value = (*value).min(track.max());
+        }
+        xp
+    }
+}
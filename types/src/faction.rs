@@ -0,0 +1,76 @@
+use crate::factions::{FACTIONS, Faction};
+
+/// A faction's position in [`FACTIONS`]. Positions shift when the catalogue changes, so
+/// factions are stored by name.
+pub type FactionId = u8;
+
+/// How a faction regards the crew, from war at -3 to allies at +3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct FactionStatus {
+    pub faction: FactionId,
+    pub status: i8,
+}
+
+impl FactionStatus {
+    pub const MIN: i8 = -3;
+    pub const MAX: i8 = 3;
+
+    pub fn faction(&self) -> Option<&'static Faction> {
+        Faction::find(self.faction)
+    }
+
+    /// At -3 the faction is at war with the crew.
+    pub fn is_war(&self) -> bool {
+        self.status <= Self::MIN
+    }
+
+    pub fn label(&self) -> &'static str {
+        Self::label_for(self.status)
+    }
+
+    /// Names a status on the -3 to +3 scale, shared by factions and NPCs.
+    pub fn label_for(status: i8) -> &'static str {
+        match status {
+            i8::MIN..=-3 => "War",
+            -2 => "Hostile",
+            -1 => "Interfering",
+            0 => "Neutral",
+            1 => "Helpful",
+            2 => "Friendly",
+            3..=i8::MAX => "Allies",
+        }
+    }
+}
+
+impl Faction {
+    pub fn find(id: FactionId) -> Option<&'static Faction> {
+        FACTIONS.get(id as usize)
+    }
+
+    /// Looks a faction up by its stored name.
+    pub fn id_of(name: &str) -> Option<FactionId> {
+        FACTIONS
+            .iter()
+            .position(|f| f.name == name)
+            .map(|i| i as FactionId)
+    }
+
+    /// Faction categories, in the order they appear in the catalogue.
+    pub fn categories() -> Vec<&'static str> {
+        FACTIONS.iter().fold(vec![], |mut acc, f| {
+            if !acc.contains(&f.category) {
+                acc.push(f.category);
+            }
+            acc
+        })
+    }
+
+    /// Factions in a category, alongside their ids.
+    pub fn in_category(category: &str) -> impl Iterator<Item = (FactionId, &'static Faction)> {
+        FACTIONS
+            .iter()
+            .enumerate()
+            .filter(move |(_, f)| f.category == category)
+            .map(|(i, f)| (i as FactionId, f))
+    }
+}
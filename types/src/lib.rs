@@ -9,17 +9,76 @@ pub use character::*;
 mod crew;
 pub use crew::*;
 
+mod clock;
+pub use clock::*;
+
+mod district;
+pub use district::*;
+
+mod end_of_session;
+pub use end_of_session::*;
+
+mod faction;
+pub use faction::*;
+
+mod npc;
+pub use npc::*;
+
+mod payoff;
+pub use payoff::*;
+
+pub mod roll;
+
+mod score;
+pub use score::*;
+
 data::blades!();
 
 pub type CharacterId = i32;
 pub type CrewId = i32;
 pub type UserId = String;
+pub type SessionId = i32;
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct User {
     pub username: String,
 }
 
+/// A device the user is logged in on.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Session {
+    pub id: SessionId,
+    /// The device name given at login, or the browser's user agent.
+    pub name: Option<String>,
+    /// The session making the request.
+    pub current: bool,
+}
+
+impl Class {
+    pub const ALL: [Class; 7] = [
+        Class::Cutter,
+        Class::Hound,
+        Class::Leech,
+        Class::Lurk,
+        Class::Slide,
+        Class::Spider,
+        Class::Whisper,
+    ];
+
+    pub fn contacts(&self) -> &'static [&'static str] {
+        use contacts::CONTACTS;
+        match self {
+            Class::Cutter => &CONTACTS.cutter,
+            Class::Hound => &CONTACTS.hound,
+            Class::Leech => &CONTACTS.leech,
+            Class::Lurk => &CONTACTS.lurk,
+            Class::Slide => &CONTACTS.slide,
+            Class::Spider => &CONTACTS.spider,
+            Class::Whisper => &CONTACTS.whisper,
+        }
+    }
+}
+
 impl TryFrom<&str> for Class {
     type Error = String;
     fn try_from(s: &str) -> Result<Self, String> {
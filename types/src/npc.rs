@@ -0,0 +1,75 @@
+use crate::{DistrictId, FactionId, FactionStatus, districts::District, factions::Faction};
+
+pub type NpcId = i32;
+
+/// Someone the crew knows, shared between the GM and every player in the crew. Character
+/// friends and rivals point at these.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Npc {
+    pub id: NpcId,
+    pub crew_id: crate::CrewId,
+    pub name: String,
+    pub description: String,
+    pub faction: Option<FactionId>,
+    pub district: Option<DistrictId>,
+    /// How they regard the crew, on the same scale as faction status.
+    pub status: i8,
+}
+
+impl Npc {
+    pub fn status_label(&self) -> &'static str {
+        FactionStatus::label_for(self.status)
+    }
+}
+
+/// A new registry entry, or changes to an existing one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize)]
+pub struct NpcDetails {
+    pub name: String,
+    pub description: String,
+    pub faction: Option<FactionId>,
+    pub district: Option<DistrictId>,
+    pub status: i8,
+}
+
+impl NpcDetails {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Name cannot be empty".to_string());
+        }
+
+        if let Some(faction) = self.faction
+            && Faction::find(faction).is_none()
+        {
+            return Err(format!("Unknown faction: {faction}"));
+        }
+
+        if let Some(district) = self.district
+            && District::find(district).is_none()
+        {
+            return Err(format!("Unknown district: {district}"));
+        }
+
+        if !(FactionStatus::MIN..=FactionStatus::MAX).contains(&self.status) {
+            return Err(format!(
+                "Status must be between {} and {}",
+                FactionStatus::MIN,
+                FactionStatus::MAX
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Npc> for NpcDetails {
+    fn from(npc: Npc) -> Self {
+        Self {
+            name: npc.name,
+            description: npc.description,
+            faction: npc.faction,
+            district: npc.district,
+            status: npc.status,
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{Character, Crew};
+
+/// How much attention the score drew.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Exposure {
+    /// Smooth and quiet, low exposure.
+    Quiet,
+    /// Contained, standard exposure.
+    Contained,
+    /// Loud and chaotic, high exposure.
+    Loud,
+    /// Wild and unrestrained, devastating exposure.
+    Wild,
+}
+
+/// What the GM awards once a score is over.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Payoff {
+    pub coin: u8,
+    /// Coin each character on the score takes, the rest goes to the crew.
+    pub share: u8,
+    pub rep: u8,
+    pub exposure: Exposure,
+    pub high_profile: bool,
+    pub hostile_turf: bool,
+    pub at_war: bool,
+    pub killing: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Entanglement {
+    GangTrouble,
+    TheUsualSuspects,
+    Rivals,
+    UnquietDead,
+    Cooperation,
+    Questioning,
+    Reprisals,
+    ShowOfForce,
+    Flipped,
+    Interrogation,
+    DemonicNotice,
+    Arrest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct EntanglementRoll {
+    pub dice: Vec<u8>,
+    pub zero_dice: bool,
+    /// The GM picks one of these.
+    pub options: Vec<Entanglement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct PayoffResult {
+    pub crew: Crew,
+    pub crew_coin: u8,
+    pub heat: u8,
+    pub entanglement: EntanglementRoll,
+}
+
+impl Exposure {
+    pub const ALL: [Exposure; 4] = [
+        Exposure::Quiet,
+        Exposure::Contained,
+        Exposure::Loud,
+        Exposure::Wild,
+    ];
+
+    pub fn heat(&self) -> u8 {
+        match self {
+            Exposure::Quiet => 0,
+            Exposure::Contained => 2,
+            Exposure::Loud => 4,
+            Exposure::Wild => 6,
+        }
+    }
+}
+
+impl std::fmt::Display for Exposure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Exposure::Quiet => write!(f, "Smooth & Quiet"),
+            Exposure::Contained => write!(f, "Contained"),
+            Exposure::Loud => write!(f, "Loud & Chaotic"),
+            Exposure::Wild => write!(f, "Wild"),
+        }
+    }
+}
+
+impl std::str::FromStr for Exposure {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Exposure::ALL
+            .into_iter()
+            .find(|e| e.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Invalid exposure: {s}"))
+    }
+}
+
+impl Payoff {
+    pub fn new() -> Self {
+        Self {
+            coin: 0,
+            share: 0,
+            rep: 0,
+            exposure: Exposure::Contained,
+            high_profile: false,
+            hostile_turf: false,
+            at_war: false,
+            killing: false,
+        }
+    }
+
+    /// Heat from exposure, plus 1 each for a high profile target, hostile turf and being at
+    /// war, and 2 for a killing.
+    pub fn heat(&self) -> u8 {
+        self.exposure.heat()
+            + u8::from(self.high_profile)
+            + u8::from(self.hostile_turf)
+            + u8::from(self.at_war)
+            + 2 * u8::from(self.killing)
+    }
+
+    /// Coin left for the crew once every character has taken their share.
+    pub fn crew_coin(&self, characters: usize) -> Result<u8, String> {
+        let shares = self.share as usize * characters;
+
+        if shares > self.coin as usize {
+            return Err(format!(
+                "{characters} shares of {} is more than the {} coin payoff",
+                self.share, self.coin
+            ));
+        }
+
+        Ok(self.coin - shares as u8)
+    }
+}
+
+impl Default for Payoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Entanglement {
+    /// The options for an entanglement roll's result at the crew's wanted level.
+    pub fn table(wanted_level: u8, result: u8) -> &'static [Entanglement] {
+        use Entanglement::*;
+
+        match (wanted_level, result) {
+            (0 | 1, 1..=3) => &[GangTrouble, TheUsualSuspects],
+            (0 | 1, 4 | 5) => &[Rivals, UnquietDead],
+            (0 | 1, _) => &[Cooperation],
+            (2 | 3, 1..=3) => &[GangTrouble, Questioning],
+            (2 | 3, 4 | 5) => &[Reprisals, UnquietDead],
+            (2 | 3, _) => &[ShowOfForce],
+            (_, 1..=3) => &[Flipped, Interrogation],
+            (_, 4 | 5) => &[DemonicNotice, ShowOfForce],
+            (_, _) => &[Arrest],
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Entanglement::GangTrouble => {
+                "Your gang causes trouble. Lose rep or face a problem with your cohorts."
+            }
+            Entanglement::TheUsualSuspects => {
+                "The Bluecoats grab someone in the crew's circle for questioning."
+            }
+            Entanglement::Rivals => "A neutral faction throws their weight around.",
+            Entanglement::UnquietDead => "A rogue spirit is drawn to you.",
+            Entanglement::Cooperation => "A +3 faction asks you for a favor.",
+            Entanglement::Questioning => "The Bluecoats take a PC in for questioning.",
+            Entanglement::Reprisals => "An enemy faction makes a move against you.",
+            Entanglement::ShowOfForce => {
+                "A faction with a negative status makes a play against your holdings."
+            }
+            Entanglement::Flipped => "One of your contacts, patrons or clients turns on you.",
+            Entanglement::Interrogation => "The Bluecoats round up a PC and beat them.",
+            Entanglement::DemonicNotice => "A demon approaches the crew with a dark offer.",
+            Entanglement::Arrest => "An inspector arrests a PC. Pay them off or face jail.",
+        }
+    }
+}
+
+impl std::fmt::Display for Entanglement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Entanglement::GangTrouble => write!(f, "Gang Trouble"),
+            Entanglement::TheUsualSuspects => write!(f, "The Usual Suspects"),
+            Entanglement::Rivals => write!(f, "Rivals"),
+            Entanglement::UnquietDead => write!(f, "Unquiet Dead"),
+            Entanglement::Cooperation => write!(f, "Cooperation"),
+            Entanglement::Questioning => write!(f, "Questioning"),
+            Entanglement::Reprisals => write!(f, "Reprisals"),
+            Entanglement::ShowOfForce => write!(f, "Show of Force"),
+            Entanglement::Flipped => write!(f, "Flipped"),
+            Entanglement::Interrogation => write!(f, "Interrogation"),
+            Entanglement::DemonicNotice => write!(f, "Demonic Notice"),
+            Entanglement::Arrest => write!(f, "Arrest"),
+        }
+    }
+}
+
+impl Crew {
+    /// Adds heat. Filling the heat track raises the wanted level and carries the rest over.
+    pub fn add_heat(&mut self, heat: u8) {
+        let mut total = self.heat + heat;
+
+        while total >= Self::MAX_HEAT {
+            if self.wanted_level >= Self::MAX_WANTED_LEVEL {
+                total = Self::MAX_HEAT;
+                break;
+            }
+
+            self.wanted_level += 1;
+            total -= Self::MAX_HEAT;
+        }
+
+        self.heat = total;
+    }
+
+    pub fn add_coin(&mut self, coin: u8) -> Result<(), String> {
+        if self.coin.saturating_add(coin) > self.coin_capacity() {
+            return Err(format!(
+                "The crew can only hold {} coin without more vaults",
+                self.coin_capacity()
+            ));
+        }
+
+        self.coin += coin;
+        Ok(())
+    }
+}
+
+impl Character {
+    /// Takes coin, putting what doesn't fit in their purse into their stash.
+    pub fn take_coin(&mut self, coin: u8) {
+        let total = self.coin.saturating_add(coin);
+
+        self.coin = total.min(Self::MAX_COIN);
+        self.stash = self
+            .stash
+            .saturating_add(total - self.coin)
+            .min(Self::MAX_STASH);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::{Action, Attribute, Character};
+
+pub type RollId = i32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Position {
+    Controlled,
+    Risky,
+    Desperate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Effect {
+    Zero,
+    Limited,
+    Standard,
+    Great,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Roll {
+    Action {
+        action: Action,
+        position: Position,
+        effect: Effect,
+    },
+    Resistance {
+        attribute: Attribute,
+    },
+    Fortune {
+        dice: u8,
+    },
+    Engagement {
+        dice: u8,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum RollKind {
+    Action,
+    Resistance,
+    Fortune,
+    Engagement,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Outcome {
+    Critical,
+    Success,
+    Partial,
+    Failure,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct RollResult {
+    pub roll: Roll,
+    pub dice: Vec<u8>,
+    /// The pool was empty, so two dice were rolled and the lowest taken.
+    pub zero_dice: bool,
+    pub outcome: Outcome,
+    /// Stress taken to resist, negative when a critical clears stress instead.
+    pub stress: Option<i8>,
+}
+
+/// A roll as recorded in a crew's roll log.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct RollLogEntry {
+    pub id: RollId,
+    /// `None` for crew rolls.
+    pub character_id: Option<crate::CharacterId>,
+    pub character_name: Option<String>,
+    pub kind: RollKind,
+    pub label: String,
+    pub dice: Vec<u8>,
+    pub zero_dice: bool,
+    pub outcome: Outcome,
+    pub stress: Option<i8>,
+}
+
+impl Position {
+    pub const ALL: [Position; 3] = [Position::Controlled, Position::Risky, Position::Desperate];
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Position::Controlled => write!(f, "Controlled"),
+            Position::Risky => write!(f, "Risky"),
+            Position::Desperate => write!(f, "Desperate"),
+        }
+    }
+}
+
+impl std::str::FromStr for Position {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "controlled" => Ok(Position::Controlled),
+            "risky" => Ok(Position::Risky),
+            "desperate" => Ok(Position::Desperate),
+            _ => Err(format!("Invalid position: {s}")),
+        }
+    }
+}
+
+impl Effect {
+    pub const ALL: [Effect; 4] = [
+        Effect::Zero,
+        Effect::Limited,
+        Effect::Standard,
+        Effect::Great,
+    ];
+}
+
+impl std::fmt::Display for Effect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Effect::Zero => write!(f, "Zero"),
+            Effect::Limited => write!(f, "Limited"),
+            Effect::Standard => write!(f, "Standard"),
+            Effect::Great => write!(f, "Great"),
+        }
+    }
+}
+
+impl std::str::FromStr for Effect {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "zero" => Ok(Effect::Zero),
+            "limited" => Ok(Effect::Limited),
+            "standard" => Ok(Effect::Standard),
+            "great" => Ok(Effect::Great),
+            _ => Err(format!("Invalid effect: {s}")),
+        }
+    }
+}
+
+impl Roll {
+    /// The most dice a single roll can use.
+    pub const MAX_DICE: u8 = 10;
+
+    /// The number of dice to roll before the 0-dice rule is applied, with level 2 harm
+    /// taking a die from the whole pool of action rolls.
+    pub fn dice_pool(&self, character: &Character, bonus: u8) -> u8 {
+        let base = match self {
+            Roll::Action { action, .. } => character.dots.rating(*action),
+            Roll::Resistance { attribute } => character.dots.attribute_rating(*attribute),
+            Roll::Fortune { dice } | Roll::Engagement { dice } => *dice,
+        };
+        let pool = base.saturating_add(bonus);
+
+        match self {
+            Roll::Action { .. } if character.harm.penalties().minus_die => pool.saturating_sub(1),
+            _ => pool,
+        }
+    }
+}
+
+impl Roll {
+    pub fn kind(&self) -> RollKind {
+        match self {
+            Roll::Action { .. } => RollKind::Action,
+            Roll::Resistance { .. } => RollKind::Resistance,
+            Roll::Fortune { .. } => RollKind::Fortune,
+            Roll::Engagement { .. } => RollKind::Engagement,
+        }
+    }
+}
+
+impl std::fmt::Display for Roll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Roll::Action {
+                action,
+                position,
+                effect,
+            } => write!(f, "{action} ({position}, {effect})"),
+            Roll::Resistance { attribute } => write!(f, "Resist with {attribute}"),
+            Roll::Fortune { .. } => write!(f, "Fortune"),
+            Roll::Engagement { .. } => write!(f, "Engagement"),
+        }
+    }
+}
+
+impl RollKind {
+    pub const ALL: [RollKind; 4] = [
+        RollKind::Action,
+        RollKind::Resistance,
+        RollKind::Fortune,
+        RollKind::Engagement,
+    ];
+}
+
+impl std::fmt::Display for RollKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RollKind::Action => write!(f, "Action"),
+            RollKind::Resistance => write!(f, "Resistance"),
+            RollKind::Fortune => write!(f, "Fortune"),
+            RollKind::Engagement => write!(f, "Engagement"),
+        }
+    }
+}
+
+impl std::str::FromStr for RollKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "action" => Ok(RollKind::Action),
+            "resistance" => Ok(RollKind::Resistance),
+            "fortune" => Ok(RollKind::Fortune),
+            "engagement" => Ok(RollKind::Engagement),
+            _ => Err(format!("Invalid roll kind: {s}")),
+        }
+    }
+}
+
+impl Outcome {
+    pub fn from_dice(dice: &[u8], zero_dice: bool) -> Self {
+        let result = if zero_dice {
+            dice.iter().min()
+        } else {
+            dice.iter().max()
+        };
+
+        match result {
+            Some(6) if !zero_dice && dice.iter().filter(|&&d| d == 6).count() >= 2 => {
+                Outcome::Critical
+            }
+            Some(6) => Outcome::Success,
+            Some(4 | 5) => Outcome::Partial,
+            _ => Outcome::Failure,
+        }
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Critical => write!(f, "Critical"),
+            Outcome::Success => write!(f, "Success"),
+            Outcome::Partial => write!(f, "Partial Success"),
+            Outcome::Failure => write!(f, "Failure"),
+        }
+    }
+}
+
+impl std::str::FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "critical" => Ok(Outcome::Critical),
+            "success" => Ok(Outcome::Success),
+            "partial success" | "partial" => Ok(Outcome::Partial),
+            "failure" => Ok(Outcome::Failure),
+            _ => Err(format!("Invalid outcome: {s}")),
+        }
+    }
+}
+
+impl RollResult {
+    pub fn new(roll: Roll, dice: Vec<u8>, zero_dice: bool) -> Self {
+        let outcome = Outcome::from_dice(&dice, zero_dice);
+
+        let stress = match roll {
+            Roll::Resistance { .. } if outcome == Outcome::Critical => Some(-1),
+            Roll::Resistance { .. } => {
+                let result = if zero_dice {
+                    dice.iter().min()
+                } else {
+                    dice.iter().max()
+                };
+                Some(6 - result.copied().unwrap_or(1) as i8)
+            }
+            _ => None,
+        };
+
+        Self {
+            roll,
+            dice,
+            zero_dice,
+            outcome,
+            stress,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::roll::{Outcome, Position};
+
+pub type ScoreId = i32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum PlanType {
+    Assault,
+    Deception,
+    Stealth,
+    Occult,
+    Social,
+    Transport,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum ScoreStatus {
+    /// Choosing the plan and who is going.
+    Planning,
+    /// The engagement roll has been made and the score is underway.
+    Active,
+    Completed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Score {
+    pub id: ScoreId,
+    pub crew_id: crate::CrewId,
+    pub target: String,
+    pub plan: PlanType,
+    /// The plan's detail, e.g. the point of attack for an assault.
+    pub detail: String,
+    pub characters: Vec<crate::CharacterId>,
+    pub status: ScoreStatus,
+    pub engagement: Option<Engagement>,
+    /// The GM has handed out payoff and heat for the completed score.
+    pub paid_out: bool,
+    /// Where the target is, if the crew has named a district.
+    pub district: Option<crate::DistrictId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Engagement {
+    pub dice: Vec<u8>,
+    pub outcome: Outcome,
+}
+
+/// The plan for a new score, or changes to one still being planned.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ScorePlan {
+    pub target: String,
+    pub plan: PlanType,
+    pub detail: String,
+    pub district: Option<crate::DistrictId>,
+    pub characters: Vec<crate::CharacterId>,
+}
+
+impl PlanType {
+    pub const ALL: [PlanType; 6] = [
+        PlanType::Assault,
+        PlanType::Deception,
+        PlanType::Stealth,
+        PlanType::Occult,
+        PlanType::Social,
+        PlanType::Transport,
+    ];
+
+    /// What the plan's detail describes.
+    pub fn detail(&self) -> &'static str {
+        match self {
+            PlanType::Assault => "Point of attack",
+            PlanType::Deception => "Method",
+            PlanType::Stealth => "Point of infiltration",
+            PlanType::Occult => "Arcane power",
+            PlanType::Social => "Social connection",
+            PlanType::Transport => "Route and means",
+        }
+    }
+}
+
+impl std::fmt::Display for PlanType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanType::Assault => write!(f, "Assault"),
+            PlanType::Deception => write!(f, "Deception"),
+            PlanType::Stealth => write!(f, "Stealth"),
+            PlanType::Occult => write!(f, "Occult"),
+            PlanType::Social => write!(f, "Social"),
+            PlanType::Transport => write!(f, "Transport"),
+        }
+    }
+}
+
+impl std::str::FromStr for PlanType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PlanType::ALL
+            .into_iter()
+            .find(|p| p.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Invalid plan type: {s}"))
+    }
+}
+
+impl std::fmt::Display for ScoreStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScoreStatus::Planning => write!(f, "Planning"),
+            ScoreStatus::Active => write!(f, "Active"),
+            ScoreStatus::Completed => write!(f, "Completed"),
+        }
+    }
+}
+
+impl std::str::FromStr for ScoreStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "planning" => Ok(ScoreStatus::Planning),
+            "active" => Ok(ScoreStatus::Active),
+            "completed" => Ok(ScoreStatus::Completed),
+            _ => Err(format!("Invalid score status: {s}")),
+        }
+    }
+}
+
+impl ScorePlan {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.target.trim().is_empty() {
+            return Err("Choose a target".to_string());
+        }
+
+        if self.detail.trim().is_empty() {
+            return Err(format!("{} is required", self.plan.detail()));
+        }
+
+        if let Some(district) = self.district
+            && crate::districts::District::find(district).is_none()
+        {
+            return Err(format!("Unknown district: {district}"));
+        }
+
+        if self.characters.is_empty() {
+            return Err("At least one character must go on the score".to_string());
+        }
+
+        if self
+            .characters
+            .iter()
+            .enumerate()
+            .any(|(i, c)| self.characters[..i].contains(c))
+        {
+            return Err("A character can only go on the score once".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl Outcome {
+    /// Where the crew starts once the engagement roll cuts to the action. A critical also
+    /// starts them past the first obstacle.
+    pub fn engagement_position(&self) -> Position {
+        match self {
+            Outcome::Critical | Outcome::Success => Position::Controlled,
+            Outcome::Partial => Position::Risky,
+            Outcome::Failure => Position::Desperate,
+        }
+    }
+}
+
+impl Engagement {
+    pub fn describe(&self) -> &'static str {
+        match self.outcome {
+            Outcome::Critical => "Exceptional result. You're already past the first obstacle.",
+            Outcome::Success => "Good result. You start in a controlled position.",
+            Outcome::Partial => "Mixed result. You start in a risky position.",
+            Outcome::Failure => "Bad result. You start in a desperate position.",
+        }
+    }
+}